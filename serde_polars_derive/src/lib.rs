@@ -0,0 +1,128 @@
+//! `#[derive(PolarsFrame)]` for [serde_polars](https://docs.rs/serde_polars).
+//!
+//! Use it through serde_polars' `derive` feature, which re-exports the macro next to
+//! the `PolarsFrame` trait it implements.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, GenericArgument, PathArguments, Type};
+
+/// Implement `serde_polars::PolarsFrame` for a record type.
+///
+/// The type must also implement `Serialize` and `Deserialize`. The implementation
+/// keeps one `Converter` and the traced Polars schema for the type in a static, so
+/// generic types are not supported.
+///
+/// Records must serialize as structs: enums are rejected unless they are
+/// `#[serde(untagged)]` or `#[serde(tag = "...")]`, while enums with data are fine as
+/// fields. `HashMap` and `BTreeMap` fields give a deprecation warning, as their
+/// columns depend on the records and `polars_schema()` cannot trace them. Other
+/// fields `polars_schema()` cannot trace, such as newtypes around chrono types, are
+/// only reported by its `ConversionError`.
+#[proc_macro_derive(PolarsFrame)]
+pub fn derive_polars_frame(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &input.generics,
+            "`PolarsFrame` cannot be derived for generic types, as each type keeps its schema in a static",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let warnings: Vec<TokenStream2> = match &input.data {
+        Data::Struct(data) => data.fields.iter().enumerate().filter_map(map_field_warning).collect(),
+        Data::Enum(_) if serializes_as_struct(&input) => Vec::new(),
+        Data::Enum(data) => {
+            return syn::Error::new_spanned(
+                data.enum_token,
+                "`PolarsFrame` records must serialize as structs; use enums with data as fields, \
+                 or mark the enum `#[serde(untagged)]` or `#[serde(tag = \"...\")]`",
+            )
+            .to_compile_error()
+            .into();
+        }
+        Data::Union(data) => {
+            return syn::Error::new_spanned(data.union_token, "`PolarsFrame` cannot be derived for unions")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    quote! {
+        impl ::serde_polars::PolarsFrame for #name {
+            fn frame_cache() -> &'static ::serde_polars::frame::FrameCache<Self> {
+                static CACHE: ::std::sync::OnceLock<::serde_polars::frame::FrameCache<#name>> =
+                    ::std::sync::OnceLock::new();
+                CACHE.get_or_init(::serde_polars::frame::FrameCache::new)
+            }
+        }
+
+        #(#warnings)*
+    }
+    .into()
+}
+
+/// Whether the enum's serde attributes make each variant serialize as a struct
+fn serializes_as_struct(input: &DeriveInput) -> bool {
+    let mut as_struct = false;
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("untagged") || meta.path.is_ident("tag") {
+                as_struct = true;
+            }
+            // Skip the value of `key = value` settings
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Lit>()?;
+            }
+            Ok(())
+        });
+    }
+    as_struct
+}
+
+/// A deprecation warning at a `HashMap` or `BTreeMap` field, optional or not
+fn map_field_warning((index, field): (usize, &syn::Field)) -> Option<TokenStream2> {
+    let map = map_type_name(&field.ty)?;
+    let name = field.ident.as_ref().map_or_else(|| index.to_string(), |ident| format!("`{}`", ident));
+    let note = format!(
+        "`polars_schema()` returns an error for this type: {} field {} has columns that depend on the records",
+        map, name
+    );
+    Some(quote_spanned! {field.ty.span()=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            struct PolarsSchemaUnavailable;
+            let _ = PolarsSchemaUnavailable;
+        };
+    })
+}
+
+/// `HashMap` or `BTreeMap` when `ty` is one of them, or an `Option` of one
+fn map_type_name(ty: &Type) -> Option<&'static str> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident == "Option" {
+        let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+            return None;
+        };
+        return match arguments.args.first()? {
+            GenericArgument::Type(inner) => map_type_name(inner),
+            _ => None,
+        };
+    }
+    if segment.ident == "HashMap" {
+        Some("`HashMap`")
+    } else if segment.ident == "BTreeMap" {
+        Some("`BTreeMap`")
+    } else {
+        None
+    }
+}
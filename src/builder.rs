@@ -0,0 +1,95 @@
+//! Incremental construction of DataFrames from records
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{to_dataframe_with, ConversionOptions, DataFrame, Result};
+
+/// Accumulates records and converts them to a DataFrame in one go.
+///
+/// Implements [`Extend`] and [`FromIterator`], so an iterator pipeline can be
+/// collected straight into a builder. Schema tracing and chrono detection run
+/// in [`build`](DataFrameBuilder::build), once all records are known:
+///
+/// ```ignore
+/// use serde_polars::DataFrameBuilder;
+///
+/// let df = lines
+///     .iter()
+///     .filter_map(|line| parse_event(line))
+///     .collect::<DataFrameBuilder<Event>>()
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct DataFrameBuilder<T> {
+    rows: Vec<T>,
+    options: ConversionOptions,
+}
+
+impl<T> DataFrameBuilder<T> {
+    /// Create an empty builder with the default conversion options
+    pub fn new() -> Self {
+        Self::with_options(ConversionOptions::default())
+    }
+
+    /// Create an empty builder that converts with custom [`ConversionOptions`]
+    pub fn with_options(options: ConversionOptions) -> Self {
+        Self {
+            rows: Vec::new(),
+            options,
+        }
+    }
+
+    /// Replace the conversion options used by [`build`](DataFrameBuilder::build)
+    pub fn options(mut self, options: ConversionOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Add a single record
+    pub fn push(&mut self, row: T) {
+        self.rows.push(row);
+    }
+
+    /// Number of records added so far
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Whether no records have been added
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+impl<T> DataFrameBuilder<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Convert the accumulated records to a DataFrame.
+    ///
+    /// Fails with `EmptyInput` when no records were added, like [`to_dataframe`](crate::to_dataframe).
+    pub fn build(&self) -> Result<DataFrame> {
+        to_dataframe_with(&self.rows, &self.options)
+    }
+}
+
+impl<T> Default for DataFrameBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Extend<T> for DataFrameBuilder<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.rows.extend(iter);
+    }
+}
+
+impl<T> FromIterator<T> for DataFrameBuilder<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut builder = Self::new();
+        builder.extend(iter);
+        builder
+    }
+}
@@ -0,0 +1,133 @@
+//! Repeated conversions of one record type, reusing the traced schema
+
+use std::marker::PhantomData;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    trace_record_schema, traced_records_to_dataframe, ConversionOptions, ConversionStats, DataFrame,
+    PolarsSerdeError, RecordSchema, Result,
+};
+
+/// Converts batches of records of one type to DataFrames, reusing work between calls.
+///
+/// [`to_dataframe_with`](crate::to_dataframe_with) traces the schema of the records,
+/// detects their chrono fields and sets up Arrow builders on every call, which is most
+/// of the cost of converting a few records. A `Converter` does this for its first batch
+/// and keeps the result for later ones, such as one conversion per request in a service:
+///
+/// ```ignore
+/// use serde_polars::Converter;
+///
+/// let converter = Converter::<Event>::new();
+/// for batch in batches {
+///     let df = converter.convert(&batch)?;
+/// }
+/// ```
+///
+/// Later batches take the column types of the first, so an `Option` field that is
+/// `None` throughout a batch keeps its type rather than becoming a `Null` column. A
+/// batch that does not fit the kept schema, such as one with values for a field that
+/// was only ever `None` before, is traced afresh, and its schema kept instead.
+///
+/// A `Converter` can be shared between threads. They all use the kept schema, and a
+/// thread that finds the builders in use by another sets up its own.
+pub struct Converter<T> {
+    options: ConversionOptions,
+    cache: Mutex<Option<Cache>>,
+    records: PhantomData<fn(&T)>,
+}
+
+/// A traced schema, with builders for it unless a conversion is using them
+struct Cache {
+    schema: Arc<RecordSchema>,
+    builder: Option<serde_arrow::ArrayBuilder>,
+}
+
+impl<T> Converter<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    /// Create a converter with the default conversion options
+    pub fn new() -> Self {
+        Self::with_options(ConversionOptions::default())
+    }
+
+    /// Create a converter that converts with custom [`ConversionOptions`]
+    pub fn with_options(options: ConversionOptions) -> Self {
+        Self {
+            options,
+            cache: Mutex::new(None),
+            records: PhantomData,
+        }
+    }
+
+    /// Convert a batch of records to a DataFrame.
+    ///
+    /// Gives the same results and errors as [`to_dataframe_with`](crate::to_dataframe_with)
+    /// with the converter's options, except for the column types kept from earlier batches.
+    pub fn convert(&self, rows: &[T]) -> Result<DataFrame> {
+        if rows.is_empty() {
+            return Err(PolarsSerdeError::EmptyInput);
+        }
+        let mut stats = ConversionStats::default();
+
+        let cached = self
+            .lock()
+            .as_mut()
+            .map(|cache| (cache.schema.clone(), cache.builder.take()));
+        if let Some((schema, builder)) = cached {
+            let mut builder = match builder {
+                Some(builder) => builder,
+                None => schema.array_builder()?,
+            };
+            // A failed conversion can leave records in the builder, so it is dropped
+            if let Ok(df) = traced_records_to_dataframe(rows, &schema, Some(&mut builder), &self.options, &mut stats) {
+                self.return_builder(&schema, builder);
+                return Ok(df);
+            }
+        }
+
+        // The first batch, or one that does not fit the kept schema
+        let schema = Arc::new(trace_record_schema(rows, &rows[0], &self.options, &mut stats)?);
+        let mut builder = schema.array_builder()?;
+        let df = traced_records_to_dataframe(rows, &schema, Some(&mut builder), &self.options, &mut stats)?;
+        *self.lock() = Some(Cache {
+            schema,
+            builder: Some(builder),
+        });
+        Ok(df)
+    }
+
+    /// Keep `builder` for the next conversion, unless the schema it was built for was replaced
+    fn return_builder(&self, schema: &Arc<RecordSchema>, builder: serde_arrow::ArrayBuilder) {
+        if let Some(cache) = self.lock().as_mut() {
+            if Arc::ptr_eq(&cache.schema, schema) && cache.builder.is_none() {
+                cache.builder = Some(builder);
+            }
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Cache>> {
+        // The cache is only ever replaced whole, so it is usable after a panic
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Default for Converter<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> std::fmt::Debug for Converter<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Converter")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,187 @@
+//! Conversion of `chrono::Duration` (`TimeDelta`) fields.
+//!
+//! chrono serializes a `TimeDelta` as a `(seconds, nanoseconds)` tuple, which
+//! serde_arrow stores as a struct column. The nanoseconds are always in
+//! `0..1_000_000_000`, so `-1.5s` is `(-2, 500_000_000)`. These columns are turned
+//! into nanosecond `Duration` columns when writing, and back into tuples when
+//! reading.
+
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, DurationNanosecondArray, Int32Array, Int64Array, StructArray};
+use arrow::compute;
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use serde::de::DeserializeOwned;
+
+use crate::{PolarsSerdeError, Result};
+
+const NANOS_PER_SECOND: i64 = 1_000_000_000;
+
+fn conversion_error(column: &str, message: impl std::fmt::Display) -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!("Failed to convert column '{}': {}", column, message),
+    }
+}
+
+/// Whether field `field` of `T` deserializes from the `(seconds, nanoseconds)` tuple of a `TimeDelta`
+pub(crate) fn field_accepts_duration<T: DeserializeOwned>(field: &str) -> bool {
+    crate::fixed_size_lists::field_tuple_len::<T>(field) == Some(2)
+}
+
+/// Whether `data_type` is the struct serde_arrow traces for a `TimeDelta`
+pub(crate) fn is_duration_tuple(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Struct(fields) => {
+            fields.len() == 2
+                && fields[0].name() == "0"
+                && fields[1].name() == "1"
+                && fields[0].data_type().is_integer()
+                && fields[1].data_type().is_integer()
+        }
+        _ => false,
+    }
+}
+
+/// Convert a struct column of `(seconds, nanoseconds)` tuples into a nanosecond `Duration` column
+pub(crate) fn tuples_to_durations(column: &ArrayRef, name: &str) -> Result<ArrayRef> {
+    if column.data_type() == &DataType::Null {
+        return Ok(arrow::array::new_null_array(&DataType::Duration(TimeUnit::Nanosecond), column.len()));
+    }
+    if !is_duration_tuple(column.data_type()) {
+        return Err(conversion_error(
+            name,
+            format!("expected (seconds, nanoseconds) tuples, found {}", column.data_type()),
+        ));
+    }
+
+    let tuples = column.as_struct();
+    let element = |i: usize| -> Result<Int64Array> {
+        compute::cast(tuples.column(i), &DataType::Int64)
+            .map(|element| element.as_primitive::<arrow::datatypes::Int64Type>().clone())
+            .map_err(|e| conversion_error(name, e))
+    };
+    let (seconds, nanos) = (element(0)?, element(1)?);
+
+    let durations = (0..tuples.len())
+        .map(|row| {
+            if tuples.is_null(row) {
+                return Ok(None);
+            }
+            seconds
+                .value(row)
+                .checked_mul(NANOS_PER_SECOND)
+                .and_then(|total| total.checked_add(nanos.value(row)))
+                .map(Some)
+                .ok_or_else(|| {
+                    conversion_error(
+                        name,
+                        format!(
+                            "duration of {}s in row {} is outside the nanosecond Duration range (about ±292 years)",
+                            seconds.value(row),
+                            row
+                        ),
+                    )
+                })
+        })
+        .collect::<Result<DurationNanosecondArray>>()?;
+    Ok(Arc::new(durations))
+}
+
+/// Convert a `Duration` column of any unit into the `(seconds, nanoseconds)` tuples a `TimeDelta` reads
+pub(crate) fn durations_to_tuples(column: &ArrayRef, name: &str) -> Result<ArrayRef> {
+    let DataType::Duration(unit) = column.data_type() else {
+        return Err(conversion_error(name, format!("expected a Duration column, found {}", column.data_type())));
+    };
+    let units_per_second = match unit {
+        TimeUnit::Second => 1,
+        TimeUnit::Millisecond => 1_000,
+        TimeUnit::Microsecond => 1_000_000,
+        TimeUnit::Nanosecond => NANOS_PER_SECOND,
+    };
+    let values = compute::cast(column, &DataType::Int64).map_err(|e| conversion_error(name, e))?;
+    let values = values.as_primitive::<arrow::datatypes::Int64Type>();
+
+    // Euclidean division keeps the nanoseconds positive, as `TimeDelta` requires
+    let seconds: Int64Array = values.iter().map(|value| value.map(|v| v.div_euclid(units_per_second))).collect();
+    let nanos: Int32Array = values
+        .iter()
+        .map(|value| value.map(|v| (v.rem_euclid(units_per_second) * (NANOS_PER_SECOND / units_per_second)) as i32))
+        .collect();
+
+    let fields = Fields::from(vec![Field::new("0", DataType::Int64, true), Field::new("1", DataType::Int32, true)]);
+    let tuples = StructArray::try_new(fields, vec![Arc::new(seconds), Arc::new(nanos)], column.nulls().cloned())
+        .map_err(|e| conversion_error(name, e))?;
+    Ok(Arc::new(tuples))
+}
+
+/// Convert the named `Duration` columns into `TimeDelta` tuples
+pub(crate) fn duration_columns_to_tuples(batch: RecordBatch, columns: &[String]) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if columns.contains(field.name()) {
+            let tuples = durations_to_tuples(column, field.name())?;
+            new_fields.push(Arc::new(Field::new(field.name(), tuples.data_type().clone(), field.is_nullable())));
+            new_columns.push(tuples);
+        } else {
+            new_columns.push(column.clone());
+            new_fields.push(field.clone());
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_durations_keep_positive_nanoseconds() {
+        // -1.5s, -1ns and 2 days 0.25s
+        let nanos = vec![-1_500_000_000, -1, 2 * 86_400 * NANOS_PER_SECOND + 250_000_000];
+        let column: ArrayRef = Arc::new(DurationNanosecondArray::from(nanos.clone()));
+
+        let tuples = durations_to_tuples(&column, "elapsed").unwrap();
+        let tuples = tuples.as_struct();
+        assert_eq!(tuples.column(0).as_primitive::<arrow::datatypes::Int64Type>().values(), &[-2, -1, 172_800]);
+        assert_eq!(
+            tuples.column(1).as_primitive::<arrow::datatypes::Int32Type>().values(),
+            &[500_000_000, 999_999_999, 250_000_000]
+        );
+
+        let back = tuples_to_durations(&(Arc::new(tuples.clone()) as ArrayRef), "elapsed").unwrap();
+        assert_eq!(back.as_primitive::<arrow::datatypes::DurationNanosecondType>().values(), nanos.as_slice());
+    }
+
+    #[test]
+    fn test_millisecond_durations_become_tuples() {
+        let column: ArrayRef = Arc::new(arrow::array::DurationMillisecondArray::from(vec![Some(-250), None]));
+
+        let tuples = durations_to_tuples(&column, "elapsed").unwrap();
+        let tuples = tuples.as_struct();
+        assert_eq!(tuples.column(0).as_primitive::<arrow::datatypes::Int64Type>().value(0), -1);
+        assert_eq!(tuples.column(1).as_primitive::<arrow::datatypes::Int32Type>().value(0), 750_000_000);
+        assert!(tuples.is_null(1));
+    }
+
+    #[test]
+    fn test_out_of_range_duration_is_rejected() {
+        let column: ArrayRef = Arc::new(StructArray::from(vec![
+            (
+                Arc::new(Field::new("0", DataType::Int64, false)),
+                Arc::new(Int64Array::from(vec![i64::MAX / 1_000])) as ArrayRef,
+            ),
+            (Arc::new(Field::new("1", DataType::Int32, false)), Arc::new(Int32Array::from(vec![0])) as ArrayRef),
+        ]));
+
+        let error = tuples_to_durations(&column, "elapsed").unwrap_err().to_string();
+        assert!(error.contains("elapsed") && error.contains("row 0"), "{}", error);
+    }
+}
@@ -0,0 +1,419 @@
+//! Columns of enums whose variants carry data, such as `Result<T, E>`.
+//!
+//! serde_arrow stores these enums as dense unions, which Polars cannot hold. They
+//! are written as struct columns instead: a `variant` string field naming each
+//! row's variant, followed by one nullable field per variant holding its data.
+//! `Result<T, E>` thus becomes `Struct(variant, Ok, Err)`.
+//!
+//! Fieldless enums are plain string columns; this module also explains which of
+//! their values failed to read. Columns of enums implementing [`EnumDiscriminant`]
+//! can store discriminants instead, as `Int32` columns, and `Boolean` columns can
+//! be read into two-variant enums.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, Int32Array, StringArray, StructArray, UInt32Array, UnionArray};
+use arrow::buffer::ScalarBuffer;
+use arrow::compute;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields, UnionFields};
+use arrow::record_batch::RecordBatch;
+use serde::de::{DeserializeOwned, IntoDeserializer};
+use serde::Serialize;
+
+use crate::{PolarsSerdeError, ProbeError, Result};
+
+/// Name of the struct field holding the variant of each row
+pub(crate) const VARIANT_FIELD: &str = "variant";
+
+fn conversion_error(column: &str, message: impl std::fmt::Display) -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!("Failed to convert enum column '{}': {}", column, message),
+    }
+}
+
+/// Whether any column of the batch is a union
+pub(crate) fn has_union_column(batch: &RecordBatch) -> bool {
+    batch
+        .schema()
+        .fields()
+        .iter()
+        .any(|field| matches!(field.data_type(), DataType::Union(_, _)))
+}
+
+/// Replace union columns by struct columns tagged with the variant of each row
+pub(crate) fn union_columns_to_structs(batch: RecordBatch) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match field.data_type() {
+            DataType::Union(variants, _) => {
+                let tagged = union_to_struct(column, field.name(), variants)?;
+                new_fields.push(Arc::new(Field::new(field.name(), tagged.data_type().clone(), false)));
+                new_columns.push(tagged);
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    rebuild(new_fields, new_columns)
+}
+
+fn union_to_struct(column: &ArrayRef, name: &str, variants: &UnionFields) -> Result<ArrayRef> {
+    let union = column
+        .as_any()
+        .downcast_ref::<UnionArray>()
+        .ok_or_else(|| conversion_error(name, "expected a union array"))?;
+    let type_ids = union.type_ids();
+    let names: HashMap<i8, &str> = variants.iter().map(|(id, field)| (id, field.name().as_str())).collect();
+
+    let tags: StringArray = type_ids.iter().map(|id| names.get(id).copied()).collect();
+    let mut fields: Vec<FieldRef> = vec![Arc::new(Field::new(VARIANT_FIELD, DataType::Utf8, false))];
+    let mut arrays: Vec<ArrayRef> = vec![Arc::new(tags)];
+
+    for (id, variant) in variants.iter() {
+        // Each row takes the variant's value where it holds this variant, and null elsewhere
+        let indices: UInt32Array = (0..union.len())
+            .map(|row| (type_ids[row] == id).then(|| union.value_offset(row) as u32))
+            .collect();
+        let values = compute::take(union.child(id).as_ref(), &indices, None)
+            .map_err(|e| conversion_error(name, e))?;
+        fields.push(Arc::new(Field::new(variant.name(), variant.data_type().clone(), true)));
+        arrays.push(values);
+    }
+
+    let tagged = StructArray::try_new(Fields::from(fields), arrays, None).map_err(|e| conversion_error(name, e))?;
+    Ok(Arc::new(tagged))
+}
+
+/// The variants of the enum field `field` of `T` deserializes from, if it is an enum
+pub(crate) fn field_variants<T: DeserializeOwned>(field: &str) -> Option<&'static [&'static str]> {
+    let variants = Cell::new(None);
+    let probe = serde::de::value::MapDeserializer::<_, ProbeError>::new(std::iter::once((
+        field,
+        EnumVariantsProbe(&variants),
+    )));
+    let _ = T::deserialize(serde::de::value::MapAccessDeserializer::new(probe));
+    variants.get()
+}
+
+/// A fieldless enum that can be stored as an integer column of its discriminants.
+///
+/// By default fieldless enums are stored as the names of their variants. Enums
+/// implementing this trait can be stored as `Int32` columns instead, for columns
+/// registered with [`ConversionOptions::enum_as_discriminant`](crate::ConversionOptions::enum_as_discriminant).
+/// For a `#[repr(i32)]` enum with explicit discriminants, the implementation is a cast:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Serialize, Deserialize)]
+/// #[repr(i32)]
+/// enum Priority { Low = 10, High = 20 }
+///
+/// impl EnumDiscriminant for Priority {
+///     fn discriminant(&self) -> i32 {
+///         *self as i32
+///     }
+/// }
+/// ```
+pub trait EnumDiscriminant: Serialize + DeserializeOwned {
+    /// The integer stored for this variant; it must differ between variants
+    fn discriminant(&self) -> i32;
+}
+
+/// Each unit variant of `E` with its discriminant, in declaration order
+pub(crate) fn discriminants<E: EnumDiscriminant>() -> Vec<(&'static str, i32)> {
+    let variants = Cell::new(None);
+    let _ = E::deserialize(EnumVariantsProbe(&variants));
+    variants
+        .get()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|&variant| {
+            let value = E::deserialize(IntoDeserializer::<serde::de::value::Error>::into_deserializer(variant)).ok()?;
+            Some((variant, value.discriminant()))
+        })
+        .collect()
+}
+
+/// Store the named variant columns as the discriminants of their variants
+pub(crate) fn variants_to_discriminants(
+    batch: RecordBatch,
+    tables: &HashMap<String, Vec<(&'static str, i32)>>,
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match tables.get(field.name()) {
+            Some(table) if is_string_or_dictionary_type(field.data_type()) => {
+                let names = compute::cast(column, &DataType::Utf8).map_err(|e| conversion_error(field.name(), e))?;
+                let numbers = names
+                    .as_string::<i32>()
+                    .iter()
+                    .map(|name| {
+                        name.map(|name| match table.iter().find(|(variant, _)| *variant == name) {
+                            Some((_, number)) => Ok(*number),
+                            None => Err(PolarsSerdeError::UnknownVariant {
+                                column: field.name().clone(),
+                                value: name.to_string(),
+                                expected: table.iter().map(|(variant, _)| variant.to_string()).collect(),
+                            }),
+                        })
+                        .transpose()
+                    })
+                    .collect::<Result<Int32Array>>()?;
+                fields.push(Arc::new(Field::new(field.name(), DataType::Int32, field.is_nullable())));
+                columns.push(Arc::new(numbers) as ArrayRef);
+            }
+            _ => {
+                fields.push(field.clone());
+                columns.push(column.clone());
+            }
+        }
+    }
+
+    rebuild(fields, columns)
+}
+
+/// Turn the named `Boolean` columns into the names of their `(true, false)` variants
+pub(crate) fn booleans_to_variants(batch: RecordBatch, columns: &HashMap<String, (String, String)>) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut arrays = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match columns.get(field.name()) {
+            Some((on, off)) if field.data_type() == &DataType::Boolean => {
+                let names: StringArray = column
+                    .as_boolean()
+                    .iter()
+                    .map(|value| value.map(|value| if value { on.as_str() } else { off.as_str() }))
+                    .collect();
+                fields.push(Arc::new(Field::new(field.name(), DataType::Utf8, field.is_nullable())));
+                arrays.push(Arc::new(names) as ArrayRef);
+            }
+            _ => {
+                fields.push(field.clone());
+                arrays.push(column.clone());
+            }
+        }
+    }
+
+    rebuild(fields, arrays)
+}
+
+/// Whether the column holds strings, plainly or dictionary encoded as enums are traced
+fn is_string_or_dictionary_type(data_type: &DataType) -> bool {
+    match data_type {
+        DataType::Dictionary(_, value_type) => crate::is_string_type(value_type),
+        data_type => crate::is_string_type(data_type),
+    }
+}
+
+/// Turn the named integer columns of discriminants back into variant names
+pub(crate) fn discriminants_to_variants(
+    batch: RecordBatch,
+    tables: &HashMap<String, Vec<(&'static str, i32)>>,
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut fields = Vec::with_capacity(batch.num_columns());
+    let mut columns = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match tables.get(field.name()) {
+            Some(table) if field.data_type().is_integer() => {
+                let numbers = compute::cast(column, &DataType::Int64).map_err(|e| conversion_error(field.name(), e))?;
+                let names = numbers
+                    .as_primitive::<arrow::datatypes::Int64Type>()
+                    .iter()
+                    .map(|number| {
+                        number
+                            .map(|number| match table.iter().find(|(_, value)| i64::from(*value) == number) {
+                                Some((variant, _)) => Ok(*variant),
+                                None => Err(PolarsSerdeError::UnknownVariant {
+                                    column: field.name().clone(),
+                                    value: number.to_string(),
+                                    expected: table.iter().map(|(_, value)| value.to_string()).collect(),
+                                }),
+                            })
+                            .transpose()
+                    })
+                    .collect::<Result<StringArray>>()?;
+                fields.push(Arc::new(Field::new(field.name(), DataType::Utf8, field.is_nullable())));
+                columns.push(Arc::new(names) as ArrayRef);
+            }
+            _ => {
+                fields.push(field.clone());
+                columns.push(column.clone());
+            }
+        }
+    }
+
+    rebuild(fields, columns)
+}
+
+/// Value that records the variants of the enum it is deserialized as
+struct EnumVariantsProbe<'a>(&'a Cell<Option<&'static [&'static str]>>);
+
+impl<'de> serde::de::IntoDeserializer<'de, ProbeError> for EnumVariantsProbe<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for EnumVariantsProbe<'_> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(ProbeError::Other)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_enum<V>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.set(Some(variants));
+        Err(ProbeError::Other)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// The first string value that is not a variant of the enum field its column is read into.
+///
+/// Used to explain an "unknown variant" failure; `None` if no column is to blame.
+pub(crate) fn find_unknown_variant<T: DeserializeOwned>(batch: &RecordBatch) -> Option<PolarsSerdeError> {
+    let schema = batch.schema();
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if !crate::is_string_type(field.data_type()) {
+            continue;
+        }
+        let Some(variants) = field_variants::<T>(field.name()) else {
+            continue;
+        };
+        let values = compute::cast(column, &DataType::Utf8).ok()?;
+        let unknown = values
+            .as_string::<i32>()
+            .iter()
+            .flatten()
+            .find(|value| !variants.contains(value));
+        if let Some(value) = unknown {
+            return Some(PolarsSerdeError::UnknownVariant {
+                column: field.name().clone(),
+                value: value.to_string(),
+                expected: variants.iter().map(|variant| variant.to_string()).collect(),
+            });
+        }
+    }
+    None
+}
+
+/// Turn the named tagged struct columns back into unions of the given variants
+pub(crate) fn struct_columns_to_unions(
+    batch: RecordBatch,
+    enum_columns: &HashMap<String, &'static [&'static str]>,
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match enum_columns.get(field.name()) {
+            Some(variants) if matches!(field.data_type(), DataType::Struct(_)) => {
+                let union = struct_to_union(column, field.name(), variants)?;
+                new_fields.push(Arc::new(Field::new(field.name(), union.data_type().clone(), false)));
+                new_columns.push(union);
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    rebuild(new_fields, new_columns)
+}
+
+fn struct_to_union(column: &ArrayRef, name: &str, variants: &[&str]) -> Result<ArrayRef> {
+    let tagged = column.as_struct();
+    if tagged.null_count() > 0 {
+        return Err(conversion_error(name, "enum columns cannot hold nulls"));
+    }
+    let tags = tagged
+        .column_by_name(VARIANT_FIELD)
+        .ok_or_else(|| conversion_error(name, format!("missing the '{}' field", VARIANT_FIELD)))?;
+    let tags = compute::cast(tags, &DataType::Utf8).map_err(|e| conversion_error(name, e))?;
+    let tags = tags.as_string::<i32>();
+
+    // Type ids follow the declaration order of the variants
+    let mut type_ids = Vec::with_capacity(tagged.len());
+    let mut offsets = Vec::with_capacity(tagged.len());
+    let mut rows: Vec<Vec<u32>> = vec![Vec::new(); variants.len()];
+    for row in 0..tagged.len() {
+        let tag = tags.is_valid(row).then(|| tags.value(row));
+        let id = tag
+            .and_then(|tag| variants.iter().position(|variant| *variant == tag))
+            .ok_or_else(|| conversion_error(name, format!("unknown variant {:?} in row {}", tag, row)))?;
+        type_ids.push(id as i8);
+        offsets.push(rows[id].len() as i32);
+        rows[id].push(row as u32);
+    }
+
+    let mut fields = Vec::with_capacity(variants.len());
+    let mut children = Vec::with_capacity(variants.len());
+    for (variant, rows) in variants.iter().zip(rows) {
+        // Variants absent from the frame (and unit variants) carry no data
+        let child = match tagged.column_by_name(variant) {
+            Some(values) => compute::take(values.as_ref(), &UInt32Array::from(rows), None)
+                .map_err(|e| conversion_error(name, e))?,
+            None => arrow::array::new_null_array(&DataType::Null, rows.len()),
+        };
+        fields.push(Field::new(*variant, child.data_type().clone(), true));
+        children.push(child);
+    }
+
+    let union_fields = UnionFields::new(0..variants.len() as i8, fields);
+    let union = UnionArray::try_new(
+        union_fields,
+        ScalarBuffer::from(type_ids),
+        Some(ScalarBuffer::from(offsets)),
+        children,
+    )
+    .map_err(|e| conversion_error(name, e))?;
+    Ok(Arc::new(union))
+}
+
+fn rebuild(fields: Vec<FieldRef>, columns: Vec<ArrayRef>) -> Result<RecordBatch> {
+    let schema = Arc::new(arrow::datatypes::Schema::new(fields));
+    RecordBatch::try_new(schema, columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
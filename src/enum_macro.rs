@@ -0,0 +1,79 @@
+//! The [`serde_polars_enum!`](crate::serde_polars_enum) macro.
+
+/// Implement `Serialize` and `Deserialize` for a fieldless enum, storing it as the
+/// names of its variants.
+///
+/// This lets application enums that do not derive serde traits be used directly
+/// as fields of records, without a parallel struct holding the enum as a `String`.
+/// The impls match what `#[derive(Serialize, Deserialize)]` generates for a
+/// fieldless enum, so columns are `String` columns of the variant names and an
+/// unknown name fails with [`PolarsSerdeError::UnknownVariant`](crate::PolarsSerdeError::UnknownVariant).
+///
+/// List every variant of the enum; a missing one is a compile error.
+///
+/// ```ignore
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Status { Active, Inactive, Pending, Failed }
+///
+/// serde_polars::serde_polars_enum!(Status { Active, Inactive, Pending, Failed });
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Account {
+///     id: i64,
+///     status: Status,  // `String` column: "Active", "Inactive", ...
+/// }
+/// ```
+#[macro_export]
+macro_rules! serde_polars_enum {
+    ($enum:ty { $($variant:ident),+ $(,)? }) => {
+        impl $crate::__private::serde::Serialize for $enum {
+            fn serialize<S>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                const VARIANTS: &[&str] = &[$(stringify!($variant)),+];
+                let variant = match self {
+                    $(<$enum>::$variant => stringify!($variant),)+
+                };
+                let index = VARIANTS.iter().position(|name| *name == variant).unwrap_or_default();
+                serializer.serialize_unit_variant(stringify!($enum), index as u32, variant)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $enum {
+            fn deserialize<D>(deserializer: D) -> ::std::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                use $crate::__private::serde::de;
+
+                const VARIANTS: &[&str] = &[$(stringify!($variant)),+];
+
+                struct VariantVisitor;
+
+                impl<'de> de::Visitor<'de> for VariantVisitor {
+                    type Value = $enum;
+
+                    fn expecting(&self, formatter: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                        formatter.write_str(concat!("a variant of ", stringify!($enum)))
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> ::std::result::Result<$enum, E> {
+                        match value {
+                            $(stringify!($variant) => Ok(<$enum>::$variant),)+
+                            _ => Err(E::unknown_variant(value, VARIANTS)),
+                        }
+                    }
+
+                    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> ::std::result::Result<$enum, A::Error> {
+                        let (name, variant): (::std::string::String, _) = data.variant()?;
+                        de::VariantAccess::unit_variant(variant)?;
+                        self.visit_str(&name)
+                    }
+                }
+
+                deserializer.deserialize_enum(stringify!($enum), VARIANTS, VariantVisitor)
+            }
+        }
+    };
+}
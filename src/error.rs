@@ -36,9 +36,15 @@ use polars_crate_0_49 as polars;
 use polars_crate_0_50 as polars;
 
 /// Comprehensive error type for all polars_serde operations
+///
+/// New variants may be added in minor releases, so matches on this type
+/// need a wildcard (`_ =>`) arm. The error is `Send + Sync`, so it can be
+/// returned from spawned threads and async tasks.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum PolarsSerdeError {
     /// Polars-specific errors
+    #[cfg(feature = "dataframe")]
     #[error("Polars error: {0}")]
     PolarsError(#[from] polars::error::PolarsError),
 
@@ -50,11 +56,18 @@ pub enum PolarsSerdeError {
     #[error("Arrow error: {0}")]
     ArrowError(#[from] arrow::error::ArrowError),
 
+    /// Parquet reading and writing errors
+    #[cfg(feature = "parquet")]
+    #[error("Parquet error: {0}")]
+    ParquetError(#[from] parquet::errors::ParquetError),
+
     /// DataFrame interchange errors
+    #[cfg(feature = "dataframe")]
     #[error("Interchange error: {0}")]
     InterchangeError(#[from] df_interchange::InterchangeError),
 
-    /// Invalid row count errors
+    /// A conversion produced a different number of rows than it was given, or a
+    /// DataFrame is larger than `ConversionOptions::max_rows` allows
     #[error("Invalid row count: expected {expected}, got {actual}")]
     InvalidRowCount { expected: usize, actual: usize },
 
@@ -62,7 +75,43 @@ pub enum PolarsSerdeError {
     #[error("Cannot create DataFrame from empty input")]
     EmptyInput,
 
+    /// A column required by the target type is not present in the DataFrame
+    #[error("Missing column: {column}")]
+    MissingColumn { column: String },
+
+    /// A column's dtype cannot be converted into the target field's type
+    #[error("Schema mismatch for column '{column}': expected {expected}, found {actual}")]
+    SchemaMismatch {
+        column: String,
+        expected: String,
+        actual: String,
+    },
+
+    /// A string column holds a value that is not a variant of the target enum field
+    #[error("Unknown variant '{value}' in column '{column}': expected one of {}", .expected.join(", "))]
+    UnknownVariant {
+        column: String,
+        value: String,
+        expected: Vec<String>,
+    },
+
+    /// A record failed to serialize; `index` is its position in the input
+    #[error("Failed to convert row {index}: {source}")]
+    RowError {
+        index: usize,
+        #[source]
+        source: Box<PolarsSerdeError>,
+    },
+
     /// Generic conversion errors
     #[error("Conversion error: {message}")]
     ConversionError { message: String },
 }
+
+/// For `Read`/`Write`-centric code that returns `std::io::Result`. The error is
+/// kept as the source, with kind `InvalidData`.
+impl From<PolarsSerdeError> for std::io::Error {
+    fn from(error: PolarsSerdeError) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+    }
+}
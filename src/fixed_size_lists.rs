@@ -0,0 +1,196 @@
+//! Columns of fixed-size arrays such as `[f64; 3]`.
+//!
+//! serde serializes arrays as tuples, which serde_arrow traces as structs with
+//! fields `"0"`, `"1"`, ... Arrays are written as `FixedSizeList` columns instead,
+//! which Polars holds as its `Array` dtype and which keeps the arity. serde_arrow
+//! cannot read tuples back from fixed-size lists, so such columns become structs
+//! of the tuple's elements again before deserialization.
+
+use std::cell::Cell;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{Array, ArrayRef, AsArray, StructArray, UInt32Array};
+use arrow::compute;
+use arrow::datatypes::{DataType, Field, FieldRef, Fields};
+use arrow::record_batch::RecordBatch;
+use serde::de::DeserializeOwned;
+
+use crate::{PolarsSerdeError, ProbeError, Result};
+
+/// Name of the item field of written fixed-size lists
+const ITEM_FIELD: &str = "element";
+
+fn conversion_error(column: &str, message: impl std::fmt::Display) -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!("Failed to convert array column '{}': {}", column, message),
+    }
+}
+
+/// The length of the array type named `type_name`, as in `[f64; 3]` or `Option<[f64; 3]>`
+pub(crate) fn array_len_of_type_name(type_name: &str) -> Option<usize> {
+    let type_name = type_name
+        .strip_prefix("core::option::Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(type_name);
+    let (_, len) = type_name.strip_prefix('[')?.strip_suffix(']')?.rsplit_once("; ")?;
+    len.parse().ok().filter(|len| *len > 0)
+}
+
+/// Retype the traced struct fields of array fields as fixed-size lists.
+///
+/// `arrays` maps field names to array lengths. Fields whose elements were traced
+/// with different types stay structs.
+pub(crate) fn struct_fields_to_lists(fields: Vec<FieldRef>, arrays: &HashMap<String, usize>) -> Vec<FieldRef> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let (Some(len), DataType::Struct(children)) = (arrays.get(field.name()), field.data_type()) else {
+                return field;
+            };
+            if children.len() != *len {
+                return field;
+            }
+            // Elements traced from samples may be `Null` where every sample was `None`
+            let mut item_types = children
+                .iter()
+                .map(|child| child.data_type())
+                .filter(|data_type| **data_type != DataType::Null);
+            let item_type = item_types.next().unwrap_or(&DataType::Null).clone();
+            if item_types.any(|data_type| *data_type != item_type) {
+                return field;
+            }
+            let nullable = children.iter().any(|child| child.is_nullable());
+            let item = Arc::new(Field::new(ITEM_FIELD, item_type, nullable));
+            Arc::new(field.as_ref().clone().with_data_type(DataType::FixedSizeList(item, *len as i32)))
+        })
+        .collect()
+}
+
+/// The length of the tuple the field `field` of `T` deserializes from, if it is a tuple.
+///
+/// Arrays and `time` types ask for a tuple of a fixed length; the length is
+/// recorded without reading any data.
+pub(crate) fn field_tuple_len<T: DeserializeOwned>(field: &str) -> Option<usize> {
+    let len = Cell::new(None);
+    let probe = serde::de::value::MapDeserializer::<_, ProbeError>::new(std::iter::once((
+        field,
+        TupleLenProbe(&len),
+    )));
+    let _ = T::deserialize(serde::de::value::MapAccessDeserializer::new(probe));
+    len.get()
+}
+
+/// Value that records the length of the tuple it is deserialized as
+struct TupleLenProbe<'a>(&'a Cell<Option<usize>>);
+
+impl<'de> serde::de::IntoDeserializer<'de, ProbeError> for TupleLenProbe<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for TupleLenProbe<'_> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(ProbeError::Other)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_tuple<V>(self, len: usize, _visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.set(Some(len));
+        Err(ProbeError::Other)
+    }
+
+    fn is_human_readable(&self) -> bool {
+        false
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Turn the named fixed-size list columns into structs with one field per element
+pub(crate) fn lists_to_structs(batch: RecordBatch, columns: &[String]) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match field.data_type() {
+            DataType::FixedSizeList(_, _) if columns.contains(field.name()) => {
+                let elements = list_to_struct(column, field.name())?;
+                new_fields.push(Arc::new(Field::new(
+                    field.name(),
+                    elements.data_type().clone(),
+                    field.is_nullable(),
+                )));
+                new_columns.push(elements);
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+fn list_to_struct(column: &ArrayRef, name: &str) -> Result<ArrayRef> {
+    let list = column.as_fixed_size_list();
+    let len = list.value_length() as usize;
+    let first = list.offset();
+
+    let mut fields: Vec<FieldRef> = Vec::with_capacity(len);
+    let mut arrays: Vec<ArrayRef> = Vec::with_capacity(len);
+    for element in 0..len {
+        // Element `i` of row `r` sits at `r * len + i` of the flat values
+        let indices: UInt32Array = (first..first + list.len())
+            .map(|row| (row * len + element) as u32)
+            .collect();
+        let values = compute::take(list.values().as_ref(), &indices, None).map_err(|e| conversion_error(name, e))?;
+        fields.push(Arc::new(Field::new(element.to_string(), values.data_type().clone(), true)));
+        arrays.push(values);
+    }
+
+    let elements = StructArray::try_new(Fields::from(fields), arrays, list.nulls().cloned())
+        .map_err(|e| conversion_error(name, e))?;
+    Ok(Arc::new(elements))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_array_len_of_type_name() {
+        assert_eq!(array_len_of_type_name("[f64; 3]"), Some(3));
+        assert_eq!(array_len_of_type_name("core::option::Option<[u8; 16]>"), Some(16));
+        assert_eq!(array_len_of_type_name("[[f32; 2]; 4]"), Some(4));
+        assert_eq!(array_len_of_type_name("[f64; 0]"), None);
+        assert_eq!(array_len_of_type_name("(f64, f64)"), None);
+        assert_eq!(array_len_of_type_name("alloc::vec::Vec<f64>"), None);
+    }
+}
@@ -0,0 +1,352 @@
+//! The [`PolarsFrame`] trait, implemented with `#[derive(PolarsFrame)]`
+
+use std::sync::OnceLock;
+
+use serde::de::{self, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::de::value::Error as ExampleError;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    chrono_kind_of_type_name, version_compat, Converter, DataFrame, PolarsSerdeError, Result, Schema,
+};
+
+/// Conversions of one record type, with its traced schema kept for later calls.
+///
+/// Implement it with `#[derive(PolarsFrame)]` (the `derive` feature) on a type that
+/// also derives `Serialize` and `Deserialize`:
+///
+/// ```ignore
+/// use serde_polars::{PolarsFrame, ToDataFrame};
+///
+/// #[derive(Serialize, Deserialize, PolarsFrame)]
+/// struct Trade {
+///     id: i64,
+///     traded_on: NaiveDate,
+/// }
+///
+/// let schema = Trade::polars_schema()?;  // `id: Int64, traded_on: Date`
+/// let df = trades.to_dataframe()?;
+/// let trades = Trade::from_dataframe(df)?;
+/// ```
+///
+/// Conversions go through one [`Converter`] per type, so they reuse its traced
+/// schema and builders.
+pub trait PolarsFrame: Serialize + for<'de> Deserialize<'de> + Sized + 'static {
+    /// The converter and schema kept for this type, in a static of the derived impl
+    #[doc(hidden)]
+    fn frame_cache() -> &'static FrameCache<Self>;
+
+    /// The Polars schema that [`to_dataframe`](Self::to_dataframe) gives this type's records.
+    ///
+    /// It is traced from an example record made up from the type, so no records are
+    /// needed, and kept after the first call. Types with columns that depend on the
+    /// records, such as the keys of a `BTreeMap` field, give a `ConversionError`, as do
+    /// fields whose `Deserialize` impl accepts no made-up value, such as a newtype
+    /// holding a `NaiveDate`.
+    fn polars_schema() -> Result<&'static Schema> {
+        let cache = Self::frame_cache();
+        if let Some(schema) = cache.schema.get() {
+            return Ok(schema);
+        }
+        let example = example_record::<Self>()?;
+        let df = cache.converter.convert(std::slice::from_ref(&example))?;
+        Ok(cache.schema.get_or_init(|| version_compat::frame_schema(&df)))
+    }
+
+    /// Convert records of this type to a DataFrame, as [`to_dataframe`](crate::to_dataframe) does
+    fn to_dataframe(rows: &[Self]) -> Result<DataFrame> {
+        Self::frame_cache().converter.convert(rows)
+    }
+
+    /// Convert a DataFrame back to records of this type, as [`from_dataframe`](crate::from_dataframe) does
+    fn from_dataframe(df: DataFrame) -> Result<Vec<Self>> {
+        crate::from_dataframe(df)
+    }
+}
+
+/// `records.to_dataframe()` for slices and vectors of [`PolarsFrame`] records
+pub trait ToDataFrame {
+    /// Convert the records to a DataFrame, as [`PolarsFrame::to_dataframe`] does
+    fn to_dataframe(&self) -> Result<DataFrame>;
+}
+
+impl<T: PolarsFrame> ToDataFrame for [T] {
+    fn to_dataframe(&self) -> Result<DataFrame> {
+        T::to_dataframe(self)
+    }
+}
+
+/// What `#[derive(PolarsFrame)]` keeps for a type; not part of the public API
+#[doc(hidden)]
+pub struct FrameCache<T> {
+    converter: Converter<T>,
+    schema: OnceLock<Schema>,
+}
+
+impl<T> FrameCache<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    pub fn new() -> Self {
+        Self {
+            converter: Converter::new(),
+            schema: OnceLock::new(),
+        }
+    }
+}
+
+impl<T> Default for FrameCache<T>
+where
+    T: Serialize + for<'de> Deserialize<'de>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A record of type `T` made up from its `Deserialize` impl, to trace the type's schema.
+///
+/// Numbers are zero, strings empty, `Option`s `Some`, sequences hold one item and
+/// enums take their first variant. Maps fail, as their keys give columns. Date and time fields, recognised by their type
+/// names like the fields of records being converted, get the Unix epoch.
+fn example_record<T: for<'de> Deserialize<'de>>() -> Result<T> {
+    T::deserialize(ExampleValue {
+        type_name: std::any::type_name::<T>(),
+    })
+    .map_err(|error| PolarsSerdeError::ConversionError {
+        message: format!(
+            "cannot make an example record of type `{}` to trace its schema: {}",
+            std::any::type_name::<T>(),
+            error
+        ),
+    })
+}
+
+/// Deserializer behind [`example_record`], for a value of the named type
+#[derive(Clone, Copy)]
+struct ExampleValue {
+    type_name: &'static str,
+}
+
+impl ExampleValue {
+    fn of<T: ?Sized>() -> Self {
+        Self {
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// The example text for a value deserialized from a string
+    fn text(self) -> &'static str {
+        match chrono_kind_of_type_name(self.type_name) {
+            Some("NaiveDate") => "1970-01-01",
+            Some("NaiveDateTime") => "1970-01-01T00:00:00",
+            Some("DateTimeUtc" | "DateTimeFixedOffset" | "DateTimeLocal") => "1970-01-01T00:00:00Z",
+            _ => "",
+        }
+    }
+}
+
+macro_rules! example_scalar {
+    ($($method:ident => $visit:ident($($value:expr)?),)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+                visitor.$visit($($value)?)
+            }
+        )+
+    };
+}
+
+impl<'de> de::Deserializer<'de> for ExampleValue {
+    type Error = ExampleError;
+
+    example_scalar! {
+        deserialize_bool => visit_bool(false),
+        deserialize_i8 => visit_i8(0),
+        deserialize_i16 => visit_i16(0),
+        deserialize_i32 => visit_i32(0),
+        deserialize_i64 => visit_i64(0),
+        deserialize_i128 => visit_i128(0),
+        deserialize_u8 => visit_u8(0),
+        deserialize_u16 => visit_u16(0),
+        deserialize_u32 => visit_u32(0),
+        deserialize_u64 => visit_u64(0),
+        deserialize_u128 => visit_u128(0),
+        deserialize_f32 => visit_f32(0.0),
+        deserialize_f64 => visit_f64(0.0),
+        deserialize_char => visit_char('a'),
+        deserialize_bytes => visit_bytes(&[]),
+        deserialize_byte_buf => visit_bytes(&[]),
+        deserialize_unit => visit_unit(),
+        deserialize_ignored_any => visit_unit(),
+    }
+
+    // Self-describing types, such as untagged enums, are asked for nothing in particular
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_str(self.text())
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_str(self.text())
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_str("")
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    // One item, so the type of the items is traced
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_seq(ExampleItems { remaining: 1 })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, len: usize, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_seq(ExampleItems { remaining: len })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_seq(ExampleItems { remaining: len })
+    }
+
+    // Map keys become columns, which only records can tell
+    fn deserialize_map<V: Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        Err(de::Error::custom(format_args!(
+            "the keys of `{}` become columns, which depend on the records",
+            self.type_name
+        )))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_map(ExampleFields { fields, next: 0 })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        match variants.first() {
+            Some(variant) => visitor.visit_enum(ExampleVariant { variant }),
+            None => Err(de::Error::custom(format_args!("enum `{}` has no variants", name))),
+        }
+    }
+}
+
+/// The items of an example sequence or tuple
+struct ExampleItems {
+    remaining: usize,
+}
+
+impl<'de> de::SeqAccess<'de> for ExampleItems {
+    type Error = ExampleError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> std::result::Result<Option<S::Value>, ExampleError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(ExampleValue::of::<S::Value>()).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.remaining)
+    }
+}
+
+/// The fields of an example struct, each named in turn
+struct ExampleFields {
+    fields: &'static [&'static str],
+    next: usize,
+}
+
+impl<'de> de::MapAccess<'de> for ExampleFields {
+    type Error = ExampleError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> std::result::Result<Option<K::Value>, ExampleError> {
+        let Some(field) = self.fields.get(self.next) else {
+            return Ok(None);
+        };
+        self.next += 1;
+        seed.deserialize(field.into_deserializer()).map(Some)
+    }
+
+    // The seed's type names the field's type, which picks the example for a date or time
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> std::result::Result<V::Value, ExampleError> {
+        seed.deserialize(ExampleValue::of::<V::Value>())
+    }
+}
+
+/// The first variant of an example enum
+struct ExampleVariant {
+    variant: &'static str,
+}
+
+impl<'de> de::EnumAccess<'de> for ExampleVariant {
+    type Error = ExampleError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> std::result::Result<(S::Value, Self), ExampleError> {
+        let variant = seed.deserialize(self.variant.into_deserializer())?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> de::VariantAccess<'de> for ExampleVariant {
+    type Error = ExampleError;
+
+    fn unit_variant(self) -> std::result::Result<(), ExampleError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> std::result::Result<S::Value, ExampleError> {
+        seed.deserialize(ExampleValue::of::<S::Value>())
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_seq(ExampleItems { remaining: len })
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, ExampleError> {
+        visitor.visit_map(ExampleFields { fields, next: 0 })
+    }
+}
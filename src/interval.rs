@@ -0,0 +1,59 @@
+//! Calendar intervals made of months, days and nanoseconds
+
+use arrow::datatypes::IntervalMonthDayNano;
+use serde::{Deserialize, Serialize};
+
+/// A calendar interval such as "3 months" or "1 month and 2 days".
+///
+/// Unlike a `Duration`, the length of a calendar interval depends on the date it
+/// is applied to, so months, days and nanoseconds are kept apart. This mirrors
+/// Arrow's `Interval(MonthDayNano)` type and converts to and from
+/// [`IntervalMonthDayNano`]. Polars has no interval dtype, so in a DataFrame a
+/// `CalendarInterval` field is a `Struct` column with `months`, `days` and
+/// `nanos` fields.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CalendarInterval {
+    /// Number of months
+    pub months: i32,
+    /// Number of days
+    pub days: i32,
+    /// Number of nanoseconds
+    pub nanos: i64,
+}
+
+impl CalendarInterval {
+    /// Create an interval from its months, days and nanoseconds
+    pub fn new(months: i32, days: i32, nanos: i64) -> Self {
+        Self { months, days, nanos }
+    }
+
+    /// An interval of `months` months
+    pub fn months(months: i32) -> Self {
+        Self::new(months, 0, 0)
+    }
+
+    /// An interval of `days` days
+    pub fn days(days: i32) -> Self {
+        Self::new(0, days, 0)
+    }
+}
+
+impl From<IntervalMonthDayNano> for CalendarInterval {
+    fn from(interval: IntervalMonthDayNano) -> Self {
+        Self::new(interval.months, interval.days, interval.nanoseconds)
+    }
+}
+
+impl From<CalendarInterval> for IntervalMonthDayNano {
+    fn from(interval: CalendarInterval) -> Self {
+        IntervalMonthDayNano::new(interval.months, interval.days, interval.nanos)
+    }
+}
+
+impl TryFrom<chrono::Months> for CalendarInterval {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(months: chrono::Months) -> std::result::Result<Self, Self::Error> {
+        Ok(Self::months(i32::try_from(months.as_u32())?))
+    }
+}
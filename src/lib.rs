@@ -23,6 +23,8 @@
 //! - `NaiveDate` → `Date` column (stored as i32 days since Unix epoch)
 //! - `NaiveDateTime` → `Timestamp` column (stored as i64 nanoseconds since Unix epoch) 
 //! - `DateTime<Utc>` → `Timestamp` column (stored as i64 nanoseconds since Unix epoch)
+//! - `DateTime<FixedOffset>`, `DateTime<Local>` → `Timestamp` column tagged with the offset
+//! - `chrono::Duration` → `Duration` column (stored as i64 nanoseconds)
 //!
 //! **All chrono types work seamlessly with efficient numeric storage!**
 //!
@@ -115,21 +117,84 @@ use polars_crate_0_49 as polars;
 #[cfg(feature = "polars_0_50")]
 use polars_crate_0_50 as polars;
 
+#[cfg(all(
+    feature = "dataframe",
+    not(any(
+        feature = "polars_0_40",
+        feature = "polars_0_41",
+        feature = "polars_0_42",
+        feature = "polars_0_43",
+        feature = "polars_0_44",
+        feature = "polars_0_45",
+        feature = "polars_0_46",
+        feature = "polars_0_47",
+        feature = "polars_0_48",
+        feature = "polars_0_49",
+        feature = "polars_0_50"
+    ))
+))]
+compile_error!("The `dataframe` feature needs a Polars version feature such as `polars_0_50`");
+
+#[cfg(feature = "dataframe")]
 use polars::prelude::*;
 
 use arrow::compute;
 use arrow::datatypes::{DataType, Field, FieldRef, TimeUnit};
 use arrow::record_batch::RecordBatch;
-use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc, Duration};
+use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use serde_arrow::schema::{SchemaLike, TracingOptions};
 use serde_arrow::{from_record_batch, to_record_batch};
 use std::sync::Arc;
+use std::time::Instant;
 
+#[cfg(feature = "dataframe")]
+pub mod builder;
+#[cfg(feature = "dataframe")]
+pub mod converter;
+mod durations;
+mod enum_columns;
+mod enum_macro;
 pub mod error;
+mod fixed_size_lists;
+#[cfg(feature = "dataframe")]
+pub mod frame;
+pub mod interval;
+pub mod options;
+pub mod stats;
+#[cfg(all(feature = "testing", feature = "dataframe"))]
+pub mod testing;
+#[cfg(feature = "time")]
+mod time_types;
+#[cfg(feature = "dataframe")]
 pub mod version_compat;
+/// The `arrow` crate this crate is built against.
+///
+/// Record batches passed to functions such as [`normalize_temporal_columns`] must come
+/// from this exact version; importing it from here avoids a mismatch with a
+/// separately resolved `arrow` dependency.
+pub use arrow;
+#[cfg(feature = "dataframe")]
+pub use builder::DataFrameBuilder;
+#[cfg(feature = "dataframe")]
+pub use converter::Converter;
+pub use enum_columns::EnumDiscriminant;
 pub use error::PolarsSerdeError;
+#[cfg(feature = "dataframe")]
+pub use frame::{PolarsFrame, ToDataFrame};
+/// `#[derive(PolarsFrame)]`, implementing [`PolarsFrame`] for a record type
+#[cfg(all(feature = "derive", feature = "dataframe"))]
+pub use serde_polars_derive::PolarsFrame;
+pub use interval::CalendarInterval;
+pub use options::{ConversionOptions, OnParseError, ParquetCompression, ParquetEncoding, ScalarValue, SchemaSource};
+pub use stats::ConversionStats;
+
+/// Items used by this crate's macros; not part of the public API
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+}
 
 /// Result type used throughout this crate
 pub type Result<T> = std::result::Result<T, PolarsSerdeError>;
@@ -139,21 +204,70 @@ use std::collections::HashMap;
 
 
 
-/// Type detector that identifies chrono types at compile time
+/// Type detector that identifies chrono types at compile time.
+///
+/// Only the top-level fields of a record are inspected, by their type names.
+/// Field values are not serialized, so large strings and lists cost nothing;
+/// a field is only looked into to see through newtype wrappers and for date and
+/// time fields of nested structs.
 struct TypeDetector {
     field_types: HashMap<String, String>,
-    current_field: Option<String>,
+    /// Date and time fields of nested structs, as paths from the record's field
+    nested_chrono_fields: Vec<(Vec<&'static str>, &'static str)>,
+    /// Paths to nested values that are `None` in the record, which may hide more of them
+    hidden_nested_fields: Vec<Vec<&'static str>>,
+    btree_map_fields: Vec<String>,
+    optional_string_fields: Vec<String>,
+    zero_sized_fields: Vec<String>,
+    array_fields: HashMap<String, usize>,
+    /// Whether the record serializes as a map, as `#[serde(flatten)]` fields make it
+    record_is_map: bool,
 }
 
 impl TypeDetector {
     fn new() -> Self {
         Self {
             field_types: HashMap::new(),
-            current_field: None,
+            nested_chrono_fields: Vec::new(),
+            hidden_nested_fields: Vec::new(),
+            btree_map_fields: Vec::new(),
+            optional_string_fields: Vec::new(),
+            zero_sized_fields: Vec::new(),
+            array_fields: HashMap::new(),
+            record_is_map: false,
         }
     }
 }
 
+/// The chrono type named `type_name`, if any, as recorded by [`TypeDetector`]
+fn chrono_kind_of_type_name(type_name: &str) -> Option<&'static str> {
+    let type_name = type_name
+        .strip_prefix("core::option::Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(type_name);
+    match type_name {
+        "chrono::naive::date::NaiveDate" => Some("NaiveDate"),
+        "chrono::naive::datetime::NaiveDateTime" => Some("NaiveDateTime"),
+        "chrono::datetime::DateTime<chrono::offset::utc::Utc>" => Some("DateTimeUtc"),
+        "chrono::datetime::DateTime<chrono::offset::fixed::FixedOffset>" => Some("DateTimeFixedOffset"),
+        "chrono::datetime::DateTime<chrono::offset::local::Local>" => Some("DateTimeLocal"),
+        "chrono::time_delta::TimeDelta" => Some("Duration"),
+        "chrono::weekday::Weekday" => Some("Weekday"),
+        "chrono::month::Month" => Some("Month"),
+        _ => None,
+    }
+}
+
+/// The chrono type of the items of a list field such as `Vec<NaiveDate>`, if it is a date or time
+fn list_chrono_kind_of_type_name(type_name: &str) -> Option<&'static str> {
+    let type_name = type_name
+        .strip_prefix("core::option::Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(type_name);
+    let item = type_name.strip_prefix("alloc::vec::Vec<")?.strip_suffix('>')?;
+    chrono_kind_of_type_name(item).filter(|kind| matches!(*kind, "NaiveDate" | "NaiveDateTime" | "DateTimeUtc"))
+}
+
 impl serde::ser::Serializer for &mut TypeDetector {
     type Ok = ();
     type Error = serde_arrow::Error;
@@ -188,35 +302,8 @@ impl serde::ser::Serializer for &mut TypeDetector {
     fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
     fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
     
-    fn serialize_newtype_struct<T: ?Sized>(self, name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error>
+    fn serialize_newtype_struct<T: ?Sized>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error>
     where T: serde::Serialize {
-        // ONLY detect actual chrono types, not arbitrary newtype structs
-        if let Some(field_name) = &self.current_field {
-            match name {
-                "NaiveDate" | "NaiveDateTime" | "DateTime" => {
-                    // Determine the specific chrono type
-                    let chrono_type = match name {
-                        "NaiveDate" => "NaiveDate",
-                        "NaiveDateTime" => "NaiveDateTime", 
-                        "DateTime" => {
-                            // For DateTime, we need to check if it's UTC or not
-                            // We'll use type name inspection as a fallback
-                            let type_name = std::any::type_name::<T>();
-                            if type_name.contains("Utc") || type_name.contains("UTC") {
-                                "DateTimeUtc"
-                            } else {
-                                "NaiveDateTime" // Treat as naive if we can't determine timezone
-                            }
-                        },
-                        _ => unreachable!(),
-                    };
-                    self.field_types.insert(field_name.clone(), chrono_type.to_string());
-                },
-                _ => {
-                    // Not a chrono type, ignore
-                }
-            }
-        }
         value.serialize(self)
     }
     
@@ -226,28 +313,31 @@ impl serde::ser::Serializer for &mut TypeDetector {
         Ok(()) 
     }
     
-    fn serialize_newtype_variant<T: ?Sized>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error>
+    fn serialize_newtype_variant<T: ?Sized>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error>
     where T: serde::Serialize {
-        value.serialize(self)
+        Ok(())
     }
     fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Ok(self) }
     fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Ok(self) }
     fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Ok(self) }
     fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Ok(self) }
-    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Ok(self) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> {
+        self.record_is_map = true;
+        Ok(self)
+    }
     fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Ok(self) }
     fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Ok(self) }
 }
 
-// Implement the compound serialization traits
+// Records that are not structs have no fields to detect, so their elements are ignored
 macro_rules! impl_serialize_compound {
     ($trait:ident, $method:ident) => {
         impl serde::ser::$trait for &mut TypeDetector {
             type Ok = ();
             type Error = serde_arrow::Error;
-            fn $method<T: ?Sized>(&mut self, value: &T) -> std::result::Result<(), Self::Error> 
+            fn $method<T: ?Sized>(&mut self, _value: &T) -> std::result::Result<(), Self::Error> 
             where T: serde::Serialize { 
-                value.serialize(&mut **self)
+                Ok(())
             }
             fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
         }
@@ -262,13 +352,13 @@ impl_serialize_compound!(SerializeTupleVariant, serialize_field);
 impl serde::ser::SerializeMap for &mut TypeDetector {
     type Ok = ();
     type Error = serde_arrow::Error;
-    fn serialize_key<T: ?Sized>(&mut self, key: &T) -> std::result::Result<(), Self::Error> 
+    fn serialize_key<T: ?Sized>(&mut self, _key: &T) -> std::result::Result<(), Self::Error> 
     where T: serde::Serialize { 
-        key.serialize(&mut **self)
+        Ok(())
     }
-    fn serialize_value<T: ?Sized>(&mut self, value: &T) -> std::result::Result<(), Self::Error> 
+    fn serialize_value<T: ?Sized>(&mut self, _value: &T) -> std::result::Result<(), Self::Error> 
     where T: serde::Serialize { 
-        value.serialize(&mut **self)
+        Ok(())
     }
     fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
 }
@@ -279,27 +369,39 @@ impl serde::ser::SerializeStruct for &mut TypeDetector {
     
     fn serialize_field<T: ?Sized>(&mut self, key: &'static str, value: &T) -> std::result::Result<(), Self::Error> 
     where T: serde::Serialize {
-        self.current_field = Some(key.to_string());
-        
         // Check the type name to detect chrono types
         let type_name = std::any::type_name::<T>();
-        
-        if type_name == "chrono::naive::date::NaiveDate" {
-            self.field_types.insert(key.to_string(), "NaiveDate".to_string());
-        } else if type_name == "chrono::naive::datetime::NaiveDateTime" {
-            self.field_types.insert(key.to_string(), "NaiveDateTime".to_string());
-        } else if type_name.starts_with("chrono::datetime::DateTime<chrono::offset::utc::Utc>") {
-            self.field_types.insert(key.to_string(), "DateTimeUtc".to_string());
-        } else if type_name.starts_with("core::option::Option<chrono::naive::date::NaiveDate>") {
-            self.field_types.insert(key.to_string(), "NaiveDate".to_string());
-        } else if type_name.starts_with("core::option::Option<chrono::naive::datetime::NaiveDateTime>") {
-            self.field_types.insert(key.to_string(), "NaiveDateTime".to_string());
-        } else if type_name.starts_with("core::option::Option<chrono::datetime::DateTime<chrono::offset::utc::Utc>>") {
-            self.field_types.insert(key.to_string(), "DateTimeUtc".to_string());
+
+        #[cfg(feature = "time")]
+        if let Some(kind) = time_types::kind_of_type_name(type_name) {
+            self.field_types.insert(key.to_string(), kind.to_string());
         }
         
-        value.serialize(&mut **self)?;
-        self.current_field = None;
+        if let Some(kind) = chrono_kind_of_type_name(type_name) {
+            self.field_types.insert(key.to_string(), kind.to_string());
+        } else if let Some(kind) = list_chrono_kind_of_type_name(type_name) {
+            // Lists of dates are converted item by item, like date fields
+            self.field_types.insert(key.to_string(), kind.to_string());
+        } else if type_name.starts_with("alloc::collections::btree::map::BTreeMap<")
+            || type_name.starts_with("core::option::Option<alloc::collections::btree::map::BTreeMap<")
+        {
+            self.btree_map_fields.push(key.to_string());
+        } else if type_name == "core::option::Option<alloc::string::String>"
+            || type_name == "core::option::Option<alloc::boxed::Box<str>>"
+        {
+            self.optional_string_fields.push(key.to_string());
+        } else if let Some(len) = fixed_size_lists::array_len_of_type_name(type_name) {
+            self.array_fields.insert(key.to_string(), len);
+        } else if std::mem::size_of_val(value) == 0 {
+            // `PhantomData`, `()` and unit structs hold no data
+            self.zero_sized_fields.push(key.to_string());
+        } else if let Some(kind) = wrapped_chrono_kind(value) {
+            self.field_types.insert(key.to_string(), kind.to_string());
+        } else {
+            let probe = nested_chrono_fields(key, value);
+            self.nested_chrono_fields.extend(probe.found);
+            self.hidden_nested_fields.extend(probe.hidden);
+        }
         Ok(())
     }
     
@@ -311,510 +413,4331 @@ impl serde::ser::SerializeStructVariant for &mut TypeDetector {
     type Error = serde_arrow::Error;
     fn serialize_field<T: ?Sized>(&mut self, key: &'static str, value: &T) -> std::result::Result<(), Self::Error> 
     where T: serde::Serialize {
-        self.current_field = Some(key.to_string());
-        value.serialize(&mut **self)?;
-        self.current_field = None;
+        if let Some(kind) = wrapped_chrono_kind(value) {
+            self.field_types.insert(key.to_string(), kind.to_string());
+        }
         Ok(())
     }
     fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
 }
 
-
-/// Detect chrono types by analyzing type information at compile time
-pub fn detect_chrono_types<T: Serialize>(sample: &T) -> std::result::Result<HashMap<String, String>, serde_arrow::Error> {
-    let mut detector = TypeDetector::new();
-    sample.serialize(&mut detector).map_err(|_| serde_arrow::Error::custom("Type detection failed".to_string()))?;
-    Ok(detector.field_types)
+/// The chrono type held by a newtype wrapper such as `struct EventDate(NaiveDate)`, if any.
+///
+/// Such wrappers hold their column's value directly, so the wrapped type decides
+/// the column type. Anything other than a newtype or `Some` stops the search
+/// without serializing its contents.
+fn wrapped_chrono_kind<T: Serialize + ?Sized>(value: &T) -> Option<&'static str> {
+    value.serialize(WrappedTypeProbe).ok().flatten()
 }
 
+/// Serializer behind [`wrapped_chrono_kind`]
+struct WrappedTypeProbe;
 
-/// Convert string arrays containing dates to Date32 arrays (i32 days since Unix epoch)
-fn convert_string_dates_to_date32(column: &arrow::array::ArrayRef) -> Result<arrow::array::ArrayRef> {
-    use arrow::array::{StringArray, LargeStringArray, Date32Builder, NullArray};
-    use arrow::array::Array;
-    
-    let mut builder = Date32Builder::new();
-    
-    // Handle null arrays (when all values are None)
-    if let Some(null_array) = column.as_any().downcast_ref::<NullArray>() {
-        // All values are null, create a date array with all nulls
-        for _ in 0..null_array.len() {
-            builder.append_null();
-        }
-        return Ok(Arc::new(builder.finish()));
+impl serde::ser::Serializer for WrappedTypeProbe {
+    type Ok = Option<&'static str>;
+    // A plain error, as `serde_arrow::Error` captures a backtrace
+    type Error = serde::de::value::Error;
+
+    type SerializeSeq = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeTuple = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeMap = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeStruct = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+    type SerializeStructVariant = serde::ser::Impossible<Self::Ok, serde::de::value::Error>;
+
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
     }
-    
-    // Handle both Utf8 and LargeUtf8 string arrays
-    if let Some(string_array) = column.as_any().downcast_ref::<StringArray>() {
-        for i in 0..string_array.len() {
-            if string_array.is_null(i) {
-                builder.append_null();
-            } else {
-                let date_str = string_array.value(i);
-                // Parse chrono's default date format
-                if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-                    let days = date.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
-                    builder.append_value(days);
-                } else {
-                    return Err(PolarsSerdeError::ConversionError {
-                        message: format!("Failed to parse date string: {}", date_str),
-                    });
-                }
-            }
-        }
-    } else if let Some(large_string_array) = column.as_any().downcast_ref::<LargeStringArray>() {
-        for i in 0..large_string_array.len() {
-            if large_string_array.is_null(i) {
-                builder.append_null();
-            } else {
-                let date_str = large_string_array.value(i);
-                // Parse chrono's default date format
-                if let Ok(date) = NaiveDate::parse_from_str(date_str, "%Y-%m-%d") {
-                    let days = date.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
-                    builder.append_value(days);
-                } else {
-                    return Err(PolarsSerdeError::ConversionError {
-                        message: format!("Failed to parse date string: {}", date_str),
-                    });
-                }
-            }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(None) }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        match chrono_kind_of_type_name(std::any::type_name::<T>()) {
+            Some(kind) => Ok(Some(kind)),
+            None => value.serialize(self),
         }
-    } else {
-        return Err(PolarsSerdeError::ConversionError {
-            message: "Expected string array (Utf8 or LargeUtf8) for date conversion".to_string(),
-        });
     }
-    
-    Ok(Arc::new(builder.finish()))
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    // Compound values are never newtype wrappers, and are left unserialized
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(serde::ser::Error::custom("not a newtype")) }
 }
 
-/// Convert string arrays containing datetimes to Timestamp arrays (i64 nanoseconds since Unix epoch)
-fn convert_string_datetimes_to_timestamp(
-    column: &arrow::array::ArrayRef, 
-    timezone: Option<Arc<str>>
-) -> Result<arrow::array::ArrayRef> {
-    use arrow::array::{StringArray, LargeStringArray, TimestampNanosecondBuilder, NullArray};
-    use arrow::array::Array;
-    
-    let mut builder = TimestampNanosecondBuilder::new();
-    
-    // Handle null arrays (when all values are None)
-    if let Some(null_array) = column.as_any().downcast_ref::<NullArray>() {
-        // All values are null, create a timestamp array with all nulls
-        for _ in 0..null_array.len() {
-            builder.append_null();
-        }
-        return Ok(Arc::new(builder.finish().with_timezone_opt(timezone)));
-    }
-    
-    // Handle both Utf8 and LargeUtf8 string arrays
-    if let Some(string_array) = column.as_any().downcast_ref::<StringArray>() {
-        for i in 0..string_array.len() {
-            if string_array.is_null(i) {
-                builder.append_null();
-            } else {
-                let datetime_str = string_array.value(i);
-                let nanos = parse_datetime_string(datetime_str, timezone.is_some())?;
-                builder.append_value(nanos);
+/// The date and time fields inside a struct-valued field `key`, as paths starting at `key`.
+///
+/// Nested structs are followed through newtypes and `Some`, with the field names
+/// serde gives them, so `rename_all` on an inner struct is respected. Lists, maps
+/// and enums are not looked into, and a `None` hides the struct it would hold; its
+/// path is kept in `hidden`, to be looked for in other records.
+fn nested_chrono_fields<T: Serialize + ?Sized>(key: &'static str, value: &T) -> NestedChronoProbe {
+    let mut probe = NestedChronoProbe {
+        path: vec![key],
+        found: Vec::new(),
+        hidden: Vec::new(),
+    };
+    let _ = value.serialize(&mut probe);
+    probe
+}
+
+/// Serializer behind [`nested_chrono_fields`]
+struct NestedChronoProbe {
+    path: Vec<&'static str>,
+    found: Vec<(Vec<&'static str>, &'static str)>,
+    hidden: Vec<Vec<&'static str>>,
+}
+
+impl NestedChronoProbe {
+    fn record<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) {
+        let kind = chrono_kind_of_type_name(std::any::type_name::<T>()).or_else(|| wrapped_chrono_kind(value));
+        match kind {
+            Some(kind @ ("NaiveDate" | "NaiveDateTime" | "DateTimeUtc")) => {
+                let mut path = self.path.clone();
+                path.push(key);
+                self.found.push((path, kind));
             }
-        }
-    } else if let Some(large_string_array) = column.as_any().downcast_ref::<LargeStringArray>() {
-        for i in 0..large_string_array.len() {
-            if large_string_array.is_null(i) {
-                builder.append_null();
-            } else {
-                let datetime_str = large_string_array.value(i);
-                let nanos = parse_datetime_string(datetime_str, timezone.is_some())?;
-                builder.append_value(nanos);
+            Some(_) => {}
+            None => {
+                self.path.push(key);
+                let _ = value.serialize(&mut *self);
+                self.path.pop();
             }
         }
-    } else {
-        return Err(PolarsSerdeError::ConversionError {
-            message: "Expected string array (Utf8 or LargeUtf8) for datetime conversion".to_string(),
-        });
     }
-    
-    Ok(Arc::new(builder.finish().with_timezone_opt(timezone)))
 }
 
-/// Helper function to parse datetime strings
-fn parse_datetime_string(datetime_str: &str, is_utc: bool) -> Result<i64> {
-    if is_utc {
-        // Parse DateTime<Utc> format (RFC3339)
-        if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
-            Ok(dt.timestamp_nanos_opt().unwrap_or(0))
-        } else {
-            Err(PolarsSerdeError::ConversionError {
-                message: format!("Failed to parse UTC datetime string: {}", datetime_str),
-            })
-        }
-    } else {
-        // Parse NaiveDateTime format  
-        if let Ok(dt) = NaiveDateTime::parse_from_str(datetime_str, "%Y-%m-%dT%H:%M:%S%.f") {
-            Ok(dt.and_utc().timestamp_nanos_opt().unwrap_or(0))
-        } else {
-            Err(PolarsSerdeError::ConversionError {
-                message: format!("Failed to parse datetime string: {}", datetime_str),
-            })
-        }
+impl serde::ser::Serializer for &mut NestedChronoProbe {
+    type Ok = ();
+    // A plain error, as `serde_arrow::Error` captures a backtrace
+    type Error = serde::de::value::Error;
+
+    type SerializeSeq = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTuple = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeMap = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> {
+        self.hidden.push(self.path.clone());
+        Ok(())
+    }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
     }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        Err(serde::ser::Error::custom("not a struct"))
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Ok(self) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(serde::ser::Error::custom("not a struct")) }
 }
 
-/// Convert chrono types to proper Arrow date/datetime types
-fn convert_chrono_columns(
-    batch: RecordBatch,
-    chrono_types: &HashMap<String, String>
-) -> Result<RecordBatch> {
-    
-    let mut new_columns = Vec::new();
-    let mut new_fields = Vec::new();
-    let schema = batch.schema();
+impl serde::ser::SerializeStruct for &mut NestedChronoProbe {
+    type Ok = ();
+    type Error = serde::de::value::Error;
 
-    for (i, column) in batch.columns().iter().enumerate() {
-        let field = schema.field(i);
-        let field_name = field.name();
-        
-        if let Some(chrono_type) = chrono_types.get(field_name) {
-            match chrono_type.as_str() {
-                "NaiveDate" => {
-                    // Convert string dates to Date32 (i32 days since Unix epoch)
-                    let date_array = convert_string_dates_to_date32(column)?;
-                    new_columns.push(date_array);
-                    new_fields.push(Arc::new(Field::new(
-                        field_name,
-                        DataType::Date32,
-                        field.is_nullable(),
-                    )));
-                },
-                "NaiveDateTime" => {
-                    // Convert string datetimes to Timestamp (i64 nanoseconds)
-                    let ts_array = convert_string_datetimes_to_timestamp(column, None)?;
-                    new_columns.push(ts_array);
-                    new_fields.push(Arc::new(Field::new(
-                        field_name,
-                        DataType::Timestamp(TimeUnit::Nanosecond, None),
-                        field.is_nullable(),
-                    )));
-                },
-                "DateTimeUtc" => {
-                    // Convert string UTC datetimes to Timestamp with UTC timezone
-                    let ts_array = convert_string_datetimes_to_timestamp(column, Some("UTC".into()))?;
-                    new_columns.push(ts_array);
-                    new_fields.push(Arc::new(Field::new(
-                        field_name,
-                        DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
-                        field.is_nullable(),
-                    )));
-                },
-                _ => {
-                    // Unknown chrono type, keep as-is
-                    new_columns.push(column.clone());
-                    new_fields.push(Arc::new(field.clone()));
-                }
-            }
-        } else {
-            // No chrono type detected, keep as-is
-            new_columns.push(column.clone());
-            new_fields.push(Arc::new(field.clone()));
-        }
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, key: &'static str, value: &T) -> std::result::Result<(), Self::Error> {
+        self.record(key, value);
+        Ok(())
     }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
 
-    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
-    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
-        message: format!("Failed to create converted record batch: {}", e),
-    })
+/// The nested date and time fields under `hidden` paths, which are `None` in the first
+/// record, from the first of `rows` that has a value for each path
+fn hidden_nested_chrono_fields<S: Serialize + ?Sized>(
+    rows: &S,
+    hidden: Vec<Vec<&'static str>>,
+) -> Vec<(Vec<&'static str>, &'static str)> {
+    let mut search = HiddenChronoSearch { hidden, found: Vec::new() };
+    // The search stops by failing once every path has been seen with a value
+    let _ = rows.serialize(&mut search);
+    search.found
 }
 
-/// Convert Date32 arrays back to string arrays for chrono deserialization  
-fn convert_date32_to_string(column: &arrow::array::ArrayRef) -> Result<arrow::array::ArrayRef> {
-    use arrow::array::{Date32Array, StringBuilder};
-    use arrow::array::Array;
-    
-    let date_array = column.as_any().downcast_ref::<Date32Array>()
-        .ok_or_else(|| PolarsSerdeError::ConversionError {
-            message: "Expected Date32 array for string conversion".to_string(),
-        })?;
-    
-    let mut builder = StringBuilder::new();
-    
-    for i in 0..date_array.len() {
-        if date_array.is_null(i) {
-            builder.append_null();
-        } else {
-            let days = date_array.value(i);
-            let date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + Duration::days(days as i64);
-            builder.append_value(date.format("%Y-%m-%d").to_string());
+/// Serializer behind [`hidden_nested_chrono_fields`], probing the fields of each record
+/// of a sequence that still have hidden paths
+struct HiddenChronoSearch {
+    hidden: Vec<Vec<&'static str>>,
+    found: Vec<(Vec<&'static str>, &'static str)>,
+}
+
+impl HiddenChronoSearch {
+    fn probe<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) {
+        if !self.hidden.iter().any(|path| path[0] == key) {
+            return;
         }
+        let probe = nested_chrono_fields(key, value);
+        let (under_key, others): (Vec<_>, Vec<_>) = std::mem::take(&mut self.hidden)
+            .into_iter()
+            .partition(|path| path[0] == key);
+
+        // Fields are new where they lie under a path hidden until now
+        for (path, kind) in probe.found {
+            let revealed = under_key.iter().any(|hidden| path.starts_with(hidden));
+            if revealed && !self.found.iter().any(|(found, _)| found == &path) {
+                self.found.push((path, kind));
+            }
+        }
+        // A path stays hidden while a `None` still covers it, and `None`s below it hide more
+        let mut hidden = others;
+        hidden.extend(
+            under_key
+                .iter()
+                .filter(|path| probe.hidden.iter().any(|none| path.starts_with(none)))
+                .cloned(),
+        );
+        hidden.extend(probe.hidden.into_iter().filter(|none| {
+            under_key.iter().any(|path| none.len() > path.len() && none.starts_with(path))
+        }));
+        self.hidden = hidden;
     }
-    
-    Ok(Arc::new(builder.finish()))
 }
 
-/// Convert Timestamp arrays back to string arrays for chrono deserialization
-fn convert_timestamp_to_string(
-    column: &arrow::array::ArrayRef,
-    timezone: Option<Arc<str>>
-) -> Result<arrow::array::ArrayRef> {
-    use arrow::array::{TimestampNanosecondArray, StringBuilder};
-    use arrow::array::Array;
-    
-    let ts_array = column.as_any().downcast_ref::<TimestampNanosecondArray>()
-        .ok_or_else(|| PolarsSerdeError::ConversionError {
-            message: "Expected Timestamp array for string conversion".to_string(),
-        })?;
-    
-    let mut builder = StringBuilder::new();
-    
-    for i in 0..ts_array.len() {
-        if ts_array.is_null(i) {
-            builder.append_null();
-        } else {
-            let nanos = ts_array.value(i);
-            
-            if timezone.is_some() {
-                // Convert to UTC DateTime string (RFC3339 format)
-                let dt = DateTime::from_timestamp_nanos(nanos);
-                builder.append_value(dt.to_rfc3339());
+impl serde::ser::Serializer for &mut HiddenChronoSearch {
+    type Ok = ();
+    // A plain error, as `serde_arrow::Error` captures a backtrace
+    type Error = serde::de::value::Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeMap = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Ok(self) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Ok(self) }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    // Only a sequence of struct records is looked into
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(not_a_struct()) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(not_a_struct()) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(not_a_struct()) }
+}
+
+impl serde::ser::SerializeSeq for &mut HiddenChronoSearch {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> std::result::Result<(), Self::Error> {
+        value.serialize(&mut **self)?;
+        match self.hidden.is_empty() {
+            true => Err(serde::ser::Error::custom("every path found")),
+            false => Ok(()),
+        }
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+impl serde::ser::SerializeStruct for &mut HiddenChronoSearch {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, key: &'static str, value: &T) -> std::result::Result<(), Self::Error> {
+        self.probe(key, value);
+        Ok(())
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+/// Give the nested date and time fields found by [`nested_chrono_fields`] temporal types.
+///
+/// serde_arrow parses chrono's strings into `Date32` and `Timestamp` children
+/// directly, so unlike top-level fields, these need no conversion afterwards.
+/// Children traced as anything but strings are left alone.
+fn retype_nested_chrono_fields(
+    fields: Vec<FieldRef>,
+    nested: &[(Vec<&'static str>, &'static str)],
+) -> Vec<FieldRef> {
+    fields
+        .into_iter()
+        .map(|field| {
+            let name = field.name().clone();
+            nested
+                .iter()
+                .filter(|(path, _)| path[0] == name)
+                .fold(field, |field, (path, kind)| retype_struct_child(field, &path[1..], kind))
+        })
+        .collect()
+}
+
+/// `field` with its descendant at `path` retyped to hold `kind` values
+fn retype_struct_child(field: FieldRef, path: &[&str], kind: &str) -> FieldRef {
+    let data_type = match (path.split_first(), field.data_type()) {
+        (None, DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View) => match kind {
+            "NaiveDate" => DataType::Date32,
+            "NaiveDateTime" => DataType::Timestamp(TimeUnit::Nanosecond, None),
+            _ => DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())),
+        },
+        (Some((name, rest)), DataType::Struct(children)) => DataType::Struct(
+            children
+                .iter()
+                .map(|child| match child.name() == name {
+                    true => retype_struct_child(child.clone(), rest, kind),
+                    false => child.clone(),
+                })
+                .collect(),
+        ),
+        _ => return field,
+    };
+    Arc::new(field.as_ref().clone().with_data_type(data_type))
+}
+
+/// Serializer that collects the record fields skipped by `skip_serializing_if`.
+///
+/// Only the top-level fields of each record are inspected; field values are
+/// never serialized, so a pass over all records stays cheap.
+#[derive(Default)]
+struct SkippedFieldCollector {
+    skipped: Vec<&'static str>,
+}
+
+impl serde::ser::Serializer for &mut SkippedFieldCollector {
+    type Ok = ();
+    type Error = serde_arrow::Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = serde::ser::Impossible<(), serde_arrow::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<(), serde_arrow::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), serde_arrow::Error>;
+    type SerializeMap = serde::ser::Impossible<(), serde_arrow::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = serde::ser::Impossible<(), serde_arrow::Error>;
+
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        Ok(())
+    }
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Ok(self) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Ok(self) }
+
+    // Records that are not plain structs have no fields to skip
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(serde_arrow::Error::custom("not a struct".to_string())) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(serde_arrow::Error::custom("not a struct".to_string())) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(serde_arrow::Error::custom("not a struct".to_string())) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(serde_arrow::Error::custom("not a struct".to_string())) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(serde_arrow::Error::custom("not a struct".to_string())) }
+}
+
+impl serde::ser::SerializeSeq for &mut SkippedFieldCollector {
+    type Ok = ();
+    type Error = serde_arrow::Error;
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> std::result::Result<(), Self::Error> {
+        value.serialize(&mut **self)
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+impl serde::ser::SerializeStruct for &mut SkippedFieldCollector {
+    type Ok = ();
+    type Error = serde_arrow::Error;
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, _key: &'static str, _value: &T) -> std::result::Result<(), Self::Error> {
+        Ok(())
+    }
+    fn skip_field(&mut self, key: &'static str) -> std::result::Result<(), Self::Error> {
+        if !self.skipped.contains(&key) {
+            self.skipped.push(key);
+        }
+        Ok(())
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+/// Fields that `skip_serializing_if` left out of at least one of `rows`
+fn skipped_record_fields<S: Serialize + ?Sized>(rows: &S) -> Vec<&'static str> {
+    let mut collector = SkippedFieldCollector::default();
+    match rows.serialize(&mut collector) {
+        Ok(()) => collector.skipped,
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Give every field that some records skip a nullable column, so the skipped
+/// rows are filled with nulls.
+///
+/// Fields that no record serialized at all are missing from a schema traced
+/// from the records; they are added as `Null` columns in the struct's field order.
+fn fill_skipped_fields<T>(fields: Vec<FieldRef>, skipped: &[&'static str]) -> Vec<FieldRef>
+where
+    T: for<'de> serde::Deserialize<'de>,
+{
+    let mut fields: Vec<FieldRef> = fields
+        .into_iter()
+        .map(|field| {
+            if skipped.contains(&field.name().as_str()) && !field.is_nullable() {
+                Arc::new(field.as_ref().clone().with_nullable(true))
             } else {
-                // Convert to NaiveDateTime string
-                let dt = DateTime::from_timestamp_nanos(nanos).naive_utc();
-                builder.append_value(dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string());
+                field
             }
+        })
+        .collect();
+
+    let absent: Vec<&str> = skipped
+        .iter()
+        .copied()
+        .filter(|name| !fields.iter().any(|field| field.name() == name))
+        .collect();
+    if absent.is_empty() {
+        return fields;
+    }
+
+    for name in absent {
+        fields.push(Arc::new(Field::new(name, DataType::Null, true)));
+    }
+    // Keep the struct's field order where it is known
+    let order = struct_field_names::<T>().unwrap_or_default();
+    fields.sort_by_key(|field| order.iter().position(|name| name == field.name()).unwrap_or(order.len()));
+    fields
+}
+
+/// A record whose fields cannot share columns with the records before it
+struct DivergentRecord {
+    index: usize,
+    field: String,
+    expected: DataType,
+    found: DataType,
+}
+
+/// Explain a failed sample trace of `rows` by the first record whose shape diverges
+/// from the records before it, such as an untagged enum variant that gives a field
+/// another type. Without such a record, `error` is returned as it is.
+fn divergent_records_error<S: Serialize + ?Sized>(
+    rows: &S,
+    tracing_options: &TracingOptions,
+    error: serde_arrow::Error,
+) -> PolarsSerdeError {
+    let mut finder = DivergenceFinder {
+        tracing_options,
+        index: 0,
+        seen: Vec::new(),
+        found: None,
+    };
+    // The finder stops at the first divergent record by failing
+    let _ = rows.serialize(&mut finder);
+    match finder.found {
+        Some(divergent) => PolarsSerdeError::ConversionError {
+            message: format!(
+                "record {} does not have the shape of the records before it: field `{}` is {:?} there, \
+                 but {:?} before",
+                divergent.index, divergent.field, divergent.found, divergent.expected
+            ),
+        },
+        None => error.into(),
+    }
+}
+
+/// Whether values of `a` and `b` cannot share a column; `Null` and missing struct
+/// fields fit anything
+fn data_types_conflict(a: &DataType, b: &DataType) -> bool {
+    match (a, b) {
+        (DataType::Null, _) | (_, DataType::Null) => false,
+        (DataType::Struct(a), DataType::Struct(b)) => a.iter().any(|a| {
+            b.iter()
+                .find(|b| b.name() == a.name())
+                .is_some_and(|b| data_types_conflict(a.data_type(), b.data_type()))
+        }),
+        (DataType::List(a), DataType::List(b)) | (DataType::LargeList(a), DataType::LargeList(b)) => {
+            data_types_conflict(a.data_type(), b.data_type())
         }
+        (a, b) => a != b,
     }
-    
-    Ok(Arc::new(builder.finish()))
 }
 
-/// Convert Date32/Timestamp columns back to string for serde_arrow compatibility
-fn convert_from_chrono_columns(
-    batch: RecordBatch,
-) -> Result<RecordBatch> {
-    
-    // eprintln!("DEBUG: convert_from_chrono_columns - Input batch has {} rows, {} columns", 
-    //          batch.num_rows(), batch.num_columns());
-    
-    let mut new_columns = Vec::new();
-    let mut new_fields = Vec::new();
-    let schema = batch.schema();
+/// Serializer behind [`divergent_records_error`], tracing each record of a sequence on its own
+struct DivergenceFinder<'a> {
+    tracing_options: &'a TracingOptions,
+    index: usize,
+    /// The type of every field seen so far, by name
+    seen: Vec<FieldRef>,
+    found: Option<DivergentRecord>,
+}
 
-    for (i, column) in batch.columns().iter().enumerate() {
-        let field = schema.field(i);
-        let field_name = field.name();
-        
-        // eprintln!("DEBUG: Processing column '{}' with type {:?}, {} rows", 
-        //          field_name, field.data_type(), column.len());
-        
-        // DO NOT automatically convert Date/Timestamp columns to strings!
-        // Let serde_arrow handle the conversion based on what the target struct expects.
-        // If the struct expects a chrono type, serde_arrow will handle Date→chrono conversion.
-        // If the struct expects an integer, serde_arrow will handle Date→integer conversion.
-        
-        // Keep all types as-is and let serde_arrow handle the conversion
-        new_columns.push(column.clone());
-        new_fields.push(Arc::new(field.clone()));
+/// A single record, serialized as a sequence of one for tracing
+struct SingleRecord<'a, T: ?Sized>(&'a T);
+
+impl<T: Serialize + ?Sized> Serialize for SingleRecord<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+        let mut seq = serializer.serialize_seq(Some(1))?;
+        seq.serialize_element(self.0)?;
+        seq.end()
     }
+}
 
-    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
-    let result = RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
-        message: format!("Failed to create converted record batch: {}", e),
-    })?;
-    
-    // eprintln!("DEBUG: convert_from_chrono_columns - Output batch has {} rows, {} columns", 
-    //          result.num_rows(), result.num_columns());
-    
-    Ok(result)
+impl DivergenceFinder<'_> {
+    fn check_record<T: Serialize + ?Sized>(&mut self, record: &T) -> std::result::Result<(), serde::de::value::Error> {
+        // A record that cannot be traced even on its own has no shape to compare
+        let Ok(fields) = Vec::<FieldRef>::from_samples(SingleRecord(record), self.tracing_options.clone()) else {
+            self.index += 1;
+            return Ok(());
+        };
+        for field in fields {
+            match self.seen.iter_mut().find(|seen| seen.name() == field.name()) {
+                Some(seen) if data_types_conflict(seen.data_type(), field.data_type()) => {
+                    self.found = Some(DivergentRecord {
+                        index: self.index,
+                        field: field.name().clone(),
+                        expected: seen.data_type().clone(),
+                        found: field.data_type().clone(),
+                    });
+                    return Err(serde::ser::Error::custom("divergent record"));
+                }
+                Some(seen) if seen.data_type() == &DataType::Null => *seen = field,
+                Some(_) => {}
+                None => self.seen.push(field),
+            }
+        }
+        self.index += 1;
+        Ok(())
+    }
+}
+
+impl serde::ser::Serializer for &mut DivergenceFinder<'_> {
+    type Ok = ();
+    // A plain error, as `serde_arrow::Error` captures a backtrace
+    type Error = serde::de::value::Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeMap = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeStruct = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeStructVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Ok(self) }
+
+    // Only a sequence of records is looked into
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, _value: &T) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Err(not_a_sequence()) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(not_a_sequence()) }
+}
+
+impl serde::ser::SerializeSeq for &mut DivergenceFinder<'_> {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> std::result::Result<(), Self::Error> {
+        self.check_record(value)
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+fn not_a_sequence() -> serde::de::value::Error {
+    serde::ser::Error::custom("not a sequence of records")
+}
+
+/// A record that lacks a field of the records before it, or has one they lack
+struct ShapeMismatch {
+    index: usize,
+    field: &'static str,
+    missing: bool,
+}
+
+/// Check that every record of `rows` has the top-level fields of the first one.
+///
+/// Fields skipped with `skip_serializing_if` count as present, as they still get a
+/// column. Records that do not serialize as structs are not checked.
+fn check_record_shapes<S: Serialize + ?Sized>(rows: &S) -> Result<()> {
+    let mut checker = ShapeChecker::default();
+    // The checker stops at the first record of another shape by failing
+    let _ = rows.serialize(&mut checker);
+    match checker.found {
+        Some(mismatch) => Err(PolarsSerdeError::ConversionError {
+            message: format!(
+                "record {} does not have the shape of the records before it: field `{}` is {}",
+                mismatch.index,
+                mismatch.field,
+                if mismatch.missing { "missing there" } else { "not in the records before it" }
+            ),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Serializer behind [`check_record_shapes`], collecting the field names of each
+/// record of a sequence without serializing their values
+#[derive(Default)]
+struct ShapeChecker {
+    index: usize,
+    /// The fields of the first record, serialized or skipped
+    expected: Option<Vec<&'static str>>,
+    fields: Vec<&'static str>,
+    found: Option<ShapeMismatch>,
+}
+
+impl ShapeChecker {
+    fn check_record<T: Serialize + ?Sized>(&mut self, record: &T) -> std::result::Result<(), serde::de::value::Error> {
+        self.fields.clear();
+        record.serialize(&mut *self)?;
+        let Some(expected) = &self.expected else {
+            self.expected = Some(std::mem::take(&mut self.fields));
+            self.index += 1;
+            return Ok(());
+        };
+        let missing = expected.iter().find(|field| !self.fields.contains(field)).map(|field| (*field, true));
+        let extra = || self.fields.iter().find(|field| !expected.contains(field)).map(|field| (*field, false));
+        if let Some((field, missing)) = missing.or_else(extra) {
+            self.found = Some(ShapeMismatch { index: self.index, field, missing });
+            return Err(serde::ser::Error::custom("record of another shape"));
+        }
+        self.index += 1;
+        Ok(())
+    }
 }
 
-/// Helper function to convert dictionary arrays to string arrays to avoid categorical issues
+impl serde::ser::Serializer for &mut ShapeChecker {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+
+    type SerializeSeq = Self;
+    type SerializeTuple = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleStruct = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeTupleVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeMap = serde::ser::Impossible<(), serde::de::value::Error>;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = serde::ser::Impossible<(), serde::de::value::Error>;
+
+    fn serialize_seq(self, _len: Option<usize>) -> std::result::Result<Self::SerializeSeq, Self::Error> { Ok(self) }
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeStruct, Self::Error> { Ok(self) }
+    fn serialize_some<T: ?Sized + serde::Serialize>(self, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_struct<T: ?Sized + serde::Serialize>(self, _name: &'static str, value: &T) -> std::result::Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    // Records that are not plain structs have no fields to compare
+    fn serialize_bool(self, _v: bool) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i8(self, _v: i8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i16(self, _v: i16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i32(self, _v: i32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_i64(self, _v: i64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u8(self, _v: u8) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u16(self, _v: u16) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u32(self, _v: u32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_u64(self, _v: u64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_f32(self, _v: f32) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_f64(self, _v: f64) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_char(self, _v: char) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_str(self, _v: &str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_bytes(self, _v: &[u8]) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_none(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit(self) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit_struct(self, _name: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_unit_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_newtype_variant<T: ?Sized + serde::Serialize>(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _value: &T) -> std::result::Result<Self::Ok, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple(self, _len: usize) -> std::result::Result<Self::SerializeTuple, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple_struct(self, _name: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleStruct, Self::Error> { Err(not_a_struct()) }
+    fn serialize_tuple_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeTupleVariant, Self::Error> { Err(not_a_struct()) }
+    fn serialize_map(self, _len: Option<usize>) -> std::result::Result<Self::SerializeMap, Self::Error> { Err(not_a_struct()) }
+    fn serialize_struct_variant(self, _name: &'static str, _variant_index: u32, _variant: &'static str, _len: usize) -> std::result::Result<Self::SerializeStructVariant, Self::Error> { Err(not_a_struct()) }
+}
+
+impl serde::ser::SerializeSeq for &mut ShapeChecker {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    fn serialize_element<T: ?Sized + serde::Serialize>(&mut self, value: &T) -> std::result::Result<(), Self::Error> {
+        self.check_record(value)
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+impl serde::ser::SerializeStruct for &mut ShapeChecker {
+    type Ok = ();
+    type Error = serde::de::value::Error;
+    fn serialize_field<T: ?Sized + serde::Serialize>(&mut self, key: &'static str, _value: &T) -> std::result::Result<(), Self::Error> {
+        self.fields.push(key);
+        Ok(())
+    }
+    fn skip_field(&mut self, key: &'static str) -> std::result::Result<(), Self::Error> {
+        self.fields.push(key);
+        Ok(())
+    }
+    fn end(self) -> std::result::Result<Self::Ok, Self::Error> { Ok(()) }
+}
+
+fn not_a_struct() -> serde::de::value::Error {
+    serde::ser::Error::custom("not a struct")
+}
+
+/// Detect chrono types by analyzing type information at compile time
+pub fn detect_chrono_types<T: Serialize>(sample: &T) -> std::result::Result<HashMap<String, String>, serde_arrow::Error> {
+    Ok(detect_field_types(sample)?.field_types)
+}
+
+/// Run the type detector over a sample record
+fn detect_field_types<T: Serialize>(sample: &T) -> std::result::Result<TypeDetector, serde_arrow::Error> {
+    let mut detector = TypeDetector::new();
+    sample.serialize(&mut detector).map_err(|_| serde_arrow::Error::custom("Type detection failed".to_string()))?;
+    Ok(detector)
+}
+
+/// Trace `BTreeMap` fields as structs with one child field per key, in sorted key order.
+///
+/// Polars has no equivalent of the Arrow `Map` type, so the keys observed across all
+/// rows become the struct's fields. Every row is expected to use the same key set;
+/// keys missing from some rows only work when the map's values are optional.
+fn trace_btree_maps_as_structs<S: Serialize + ?Sized>(
+    fields: Vec<FieldRef>,
+    rows: &S,
+    map_fields: &[String],
+    tracing_options: TracingOptions,
+) -> Result<Vec<FieldRef>> {
+    let struct_fields = Vec::<FieldRef>::from_samples(rows, tracing_options.map_as_struct(true))?;
+
+    Ok(fields
+        .into_iter()
+        .map(|field| {
+            if !map_fields.iter().any(|name| name == field.name()) {
+                return field;
+            }
+            match struct_fields.iter().find(|traced| traced.name() == field.name()) {
+                Some(traced) => match traced.data_type() {
+                    DataType::Struct(children) => {
+                        let mut children: Vec<FieldRef> = children.iter().cloned().collect();
+                        children.sort_by(|a, b| a.name().cmp(b.name()));
+                        Arc::new(Field::new(
+                            field.name(),
+                            DataType::Struct(children.into()),
+                            traced.is_nullable(),
+                        ))
+                    }
+                    _ => traced.clone(),
+                },
+                None => field,
+            }
+        })
+        .collect())
+}
+
+/// Restore the string type of `Option<String>` and `Option<Box<str>>` fields that sample
+/// tracing saw only as `None`.
+///
+/// Sample-based tracing has nothing to infer a type from in an all-null column and
+/// falls back to `Null`, which would make the column's dtype depend on the data.
+fn retype_null_string_fields(
+    fields: Vec<FieldRef>,
+    string_fields: &[String],
+    tracing_options: &TracingOptions,
+) -> Vec<FieldRef> {
+    let string_type = if tracing_options.string_as_large_utf8 {
+        DataType::LargeUtf8
+    } else {
+        DataType::Utf8
+    };
+
+    fields
+        .into_iter()
+        .map(|field| {
+            if field.data_type() == &DataType::Null
+                && string_fields.iter().any(|name| name == field.name())
+            {
+                Arc::new(Field::new(field.name(), string_type.clone(), true))
+            } else {
+                field
+            }
+        })
+        .collect()
+}
+
+/// Date formats tried, in order, when parsing date strings.
+///
+/// The first entry is chrono's own `NaiveDate` serialization format; the others
+/// accept ISO week dates (`2023-W24-4`) and ordinal dates (`2023-166`). Ordinal
+/// dates need exactly three digits for the day, as chrono would otherwise read a
+/// truncated date such as `2023-06` as the sixth day of the year.
+pub const DEFAULT_DATE_FORMATS: &[&str] = &["%Y-%m-%d", "%G-W%V-%u", "%Y-%j"];
+
+/// Parse a date string using the first matching format in `DEFAULT_DATE_FORMATS`
+fn parse_date_string(date_str: &str) -> Option<NaiveDate> {
+    parse_iso_date_fast(date_str).or_else(|| {
+        DEFAULT_DATE_FORMATS
+            .iter()
+            .filter(|format| **format != "%Y-%j" || has_three_digit_ordinal(date_str))
+            .find_map(|format| NaiveDate::parse_from_str(date_str, format).ok())
+    })
+}
+
+/// Whether the part of `date_str` after its last dash is exactly three digits
+fn has_three_digit_ordinal(date_str: &str) -> bool {
+    date_str
+        .rsplit_once('-')
+        .is_some_and(|(_, day)| day.len() == 3 && day.bytes().all(|byte| byte.is_ascii_digit()))
+}
+
+/// Parse the fixed `YYYY-MM-DD` layout by slicing bytes.
+///
+/// chrono's format interpretation dominates conversion time on date-heavy data,
+/// and `YYYY-MM-DD` is what `NaiveDate` serializes to. Anything that does not
+/// match the exact layout returns `None` so the chrono formats can decide.
+fn parse_iso_date_fast(date_str: &str) -> Option<NaiveDate> {
+    let bytes = date_str.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return None;
+    }
+
+    let number = |digits: &[u8]| {
+        digits.iter().try_fold(0u32, |acc, &b| {
+            b.is_ascii_digit().then(|| acc * 10 + u32::from(b - b'0'))
+        })
+    };
+
+    let year = number(&bytes[0..4])?;
+    let month = number(&bytes[5..7])?;
+    let day = number(&bytes[8..10])?;
+    NaiveDate::from_ymd_opt(year as i32, month, day)
+}
+
+/// Convert string arrays containing dates to Date32 arrays (i32 days since Unix epoch)
+fn convert_string_dates_to_date32(
+    column: &arrow::array::ArrayRef,
+    on_error: OnParseError,
+) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{StringArray, LargeStringArray, Date32Builder, NullArray};
+    use arrow::array::Array;
+    
+    let mut builder = Date32Builder::new();
+    
+    // Handle null arrays (when all values are None)
+    if let Some(null_array) = column.as_any().downcast_ref::<NullArray>() {
+        // All values are null, create a date array with all nulls
+        for _ in 0..null_array.len() {
+            builder.append_null();
+        }
+        return Ok(Arc::new(builder.finish()));
+    }
+    
+    // Handle both Utf8 and LargeUtf8 string arrays
+    if let Some(string_array) = column.as_any().downcast_ref::<StringArray>() {
+        for i in 0..string_array.len() {
+            if string_array.is_null(i) {
+                builder.append_null();
+            } else {
+                let date_str = string_array.value(i);
+                if let Some(date) = parse_date_string(date_str) {
+                    let days = date.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
+                    builder.append_value(days);
+                } else if on_error == OnParseError::Null {
+                    builder.append_null();
+                } else {
+                    return Err(PolarsSerdeError::ConversionError {
+                        message: format!("Failed to parse date string: {}", date_str),
+                    });
+                }
+            }
+        }
+    } else if let Some(large_string_array) = column.as_any().downcast_ref::<LargeStringArray>() {
+        for i in 0..large_string_array.len() {
+            if large_string_array.is_null(i) {
+                builder.append_null();
+            } else {
+                let date_str = large_string_array.value(i);
+                if let Some(date) = parse_date_string(date_str) {
+                    let days = date.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
+                    builder.append_value(days);
+                } else if on_error == OnParseError::Null {
+                    builder.append_null();
+                } else {
+                    return Err(PolarsSerdeError::ConversionError {
+                        message: format!("Failed to parse date string: {}", date_str),
+                    });
+                }
+            }
+        }
+    } else {
+        return Err(PolarsSerdeError::ConversionError {
+            message: "Expected string array (Utf8 or LargeUtf8) for date conversion".to_string(),
+        });
+    }
+    
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Convert string arrays containing datetimes to Timestamp arrays (i64 nanoseconds since Unix epoch)
+fn convert_string_datetimes_to_timestamp(
+    column: &arrow::array::ArrayRef, 
+    timezone: Option<Arc<str>>,
+    on_error: OnParseError,
+) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{StringArray, LargeStringArray, TimestampNanosecondBuilder, NullArray};
+    use arrow::array::Array;
+    
+    let mut builder = TimestampNanosecondBuilder::new();
+    
+    // Handle null arrays (when all values are None)
+    if let Some(null_array) = column.as_any().downcast_ref::<NullArray>() {
+        // All values are null, create a timestamp array with all nulls
+        for _ in 0..null_array.len() {
+            builder.append_null();
+        }
+        return Ok(Arc::new(builder.finish().with_timezone_opt(timezone)));
+    }
+    
+    // Handle both Utf8 and LargeUtf8 string arrays
+    if let Some(string_array) = column.as_any().downcast_ref::<StringArray>() {
+        for i in 0..string_array.len() {
+            if string_array.is_null(i) {
+                builder.append_null();
+            } else {
+                let datetime_str = string_array.value(i);
+                match parse_datetime_string(datetime_str, timezone.is_some()) {
+                    Ok(nanos) => builder.append_value(nanos),
+                    Err(_) if on_error == OnParseError::Null => builder.append_null(),
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+    } else if let Some(large_string_array) = column.as_any().downcast_ref::<LargeStringArray>() {
+        for i in 0..large_string_array.len() {
+            if large_string_array.is_null(i) {
+                builder.append_null();
+            } else {
+                let datetime_str = large_string_array.value(i);
+                match parse_datetime_string(datetime_str, timezone.is_some()) {
+                    Ok(nanos) => builder.append_value(nanos),
+                    Err(_) if on_error == OnParseError::Null => builder.append_null(),
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+    } else {
+        return Err(PolarsSerdeError::ConversionError {
+            message: "Expected string array (Utf8 or LargeUtf8) for datetime conversion".to_string(),
+        });
+    }
+    
+    Ok(Arc::new(builder.finish().with_timezone_opt(timezone)))
+}
+
+/// Helper function to parse datetime strings
+///
+/// Timestamps are stored as i64 nanoseconds, which only covers roughly
+/// 1677-09-21 through 2262-04-11. Values outside that range are reported as a
+/// `ConversionError` instead of being silently stored as the epoch.
+fn parse_datetime_string(datetime_str: &str, is_utc: bool) -> Result<i64> {
+    if is_utc {
+        // Parse DateTime<Utc> format (RFC3339)
+        if let Ok(dt) = DateTime::parse_from_rfc3339(datetime_str) {
+            dt.timestamp_nanos_opt().ok_or_else(|| timestamp_out_of_range(datetime_str))
+        } else {
+            Err(PolarsSerdeError::ConversionError {
+                message: format!("Failed to parse UTC datetime string: {}", datetime_str),
+            })
+        }
+    } else {
+        // Parse NaiveDateTime format  
+        if let Ok(dt) = NaiveDateTime::parse_from_str(datetime_str, "%Y-%m-%dT%H:%M:%S%.f") {
+            dt.and_utc().timestamp_nanos_opt().ok_or_else(|| timestamp_out_of_range(datetime_str))
+        } else {
+            Err(PolarsSerdeError::ConversionError {
+                message: format!("Failed to parse datetime string: {}", datetime_str),
+            })
+        }
+    }
+}
+
+/// Error for datetimes that do not fit in an i64 nanosecond timestamp
+fn timestamp_out_of_range(datetime_str: &str) -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!(
+            "Datetime {} is outside the nanosecond timestamp range (1677-09-21 to 2262-04-11)",
+            datetime_str
+        ),
+    }
+}
+
+/// Convert chrono types to proper Arrow date/datetime types
+fn convert_chrono_columns(
+    batch: RecordBatch,
+    chrono_types: &HashMap<String, String>,
+    options: &ConversionOptions,
+) -> Result<RecordBatch> {
+    
+    let mut new_columns = Vec::new();
+    let mut new_fields = Vec::new();
+    let schema = batch.schema();
+
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = schema.field(i);
+        let field_name = field.name();
+        
+        if let Some(chrono_type) = chrono_types.get(field_name) {
+            match (chrono_type.as_str(), field.data_type()) {
+                // Customized tracing can make serde_arrow produce the temporal type directly
+                ("NaiveDate", DataType::Date32)
+                | (
+                    "NaiveDateTime" | "DateTimeUtc" | "DateTimeFixedOffset" | "DateTimeLocal",
+                    DataType::Timestamp(_, _),
+                )
+                | ("Weekday" | "Month", DataType::UInt8)
+                | ("Duration", DataType::Duration(_)) => {
+                    new_columns.push(column.clone());
+                    new_fields.push(Arc::new(field.clone()));
+                    continue;
+                }
+                // `TimeDelta` serializes as a `(seconds, nanoseconds)` tuple
+                ("Duration", DataType::Struct(_) | DataType::Null) => {
+                    new_columns.push(durations::tuples_to_durations(column, field_name)?);
+                    new_fields.push(Arc::new(Field::new(
+                        field_name,
+                        DataType::Duration(TimeUnit::Nanosecond),
+                        field.is_nullable(),
+                    )));
+                    continue;
+                }
+                // `time` types serialize as tuples, which serde_arrow stores as structs
+                #[cfg(feature = "time")]
+                (
+                    time_types::DATE | time_types::PRIMITIVE_DATE_TIME | time_types::OFFSET_DATE_TIME,
+                    DataType::Struct(_),
+                ) => {
+                    let timezone: Arc<str> = options
+                        .column_timezones
+                        .get(field_name)
+                        .map(|tz| tz.as_str())
+                        .unwrap_or("UTC")
+                        .into();
+                    let (temporal, data_type) =
+                        time_types::tuples_to_temporal(column, field_name, chrono_type, timezone)?;
+                    new_columns.push(temporal);
+                    new_fields.push(Arc::new(Field::new(field_name, data_type, field.is_nullable())));
+                    continue;
+                }
+                (
+                    "NaiveDate" | "NaiveDateTime" | "DateTimeUtc",
+                    DataType::List(_) | DataType::LargeList(_),
+                ) => {
+                    let (list, data_type) = convert_chrono_list(column, field_name, chrono_type, options)?;
+                    new_columns.push(list);
+                    new_fields.push(Arc::new(Field::new(field_name, data_type, field.is_nullable())));
+                    continue;
+                }
+                (_, DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View | DataType::Null) => {}
+                // `keep_string_dictionary` dictionary-encodes chrono strings as well
+                (_, DataType::Dictionary(_, value_type)) if is_string_type(value_type) => {}
+                (_, other) => {
+                    return Err(PolarsSerdeError::ConversionError {
+                        message: format!(
+                            "Column '{}' holds {} values as {}; expected a string column",
+                            field_name, chrono_type, other
+                        ),
+                    });
+                }
+            }
+
+            // String views (as Polars exports strings) and dictionaries are parsed from a plain string array
+            let column = &if matches!(field.data_type(), DataType::Utf8View | DataType::Dictionary(_, _)) {
+                compute::cast(column, &DataType::Utf8).map_err(|e| PolarsSerdeError::ConversionError {
+                    message: format!("Failed to read strings of column '{}': {}", field_name, e),
+                })?
+            } else {
+                column.clone()
+            };
+
+            match chrono_type.as_str() {
+                "NaiveDate" | "NaiveDateTime" | "DateTimeUtc" | "DateTimeFixedOffset" | "DateTimeLocal" => {
+                    let (temporal, data_type) = convert_chrono_strings(column, field_name, chrono_type, options)?;
+                    new_columns.push(temporal);
+                    new_fields.push(Arc::new(Field::new(field_name, data_type, field.is_nullable())));
+                },
+                "Weekday" | "Month" => {
+                    new_columns.push(convert_calendar_names_to_numbers(column, chrono_type)?);
+                    new_fields.push(Arc::new(Field::new(
+                        field_name,
+                        DataType::UInt8,
+                        field.is_nullable(),
+                    )));
+                },
+                _ => {
+                    // Unknown chrono type, keep as-is
+                    new_columns.push(column.clone());
+                    new_fields.push(Arc::new(field.clone()));
+                }
+            }
+        } else {
+            // No chrono type detected, keep as-is
+            new_columns.push(column.clone());
+            new_fields.push(Arc::new(field.clone()));
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Parse the strings of a column holding `chrono_type` values into its temporal type
+fn convert_chrono_strings(
+    column: &arrow::array::ArrayRef,
+    field_name: &str,
+    chrono_type: &str,
+    options: &ConversionOptions,
+) -> Result<(arrow::array::ArrayRef, DataType)> {
+    match chrono_type {
+        // Dates become Date32 (i32 days since Unix epoch)
+        "NaiveDate" => Ok((
+            convert_string_dates_to_date32(column, options.on_temporal_parse_error)?,
+            DataType::Date32,
+        )),
+        // Datetimes become Timestamp (i64 nanoseconds)
+        "NaiveDateTime" => Ok((
+            convert_string_datetimes_to_timestamp(column, None, options.on_temporal_parse_error)?,
+            DataType::Timestamp(TimeUnit::Nanosecond, None),
+        )),
+        _ => {
+            // Timezone-aware datetimes are tagged with their own zone unless the
+            // options request another zone for this column
+            let timezone: Arc<str> = match options.column_timezones.get(field_name) {
+                Some(tz) => tz.as_str().into(),
+                None => default_timezone(column, chrono_type).into(),
+            };
+            let ts_array = convert_string_datetimes_to_timestamp(
+                column,
+                Some(timezone.clone()),
+                options.on_temporal_parse_error,
+            )?;
+            Ok((ts_array, DataType::Timestamp(TimeUnit::Nanosecond, Some(timezone))))
+        }
+    }
+}
+
+/// The zone a column of timezone-aware `chrono_type` datetimes is tagged with.
+///
+/// `DateTime<FixedOffset>` columns take the offset of their first value, such as
+/// `+05:30`, and `DateTime<Local>` columns the system's offset at conversion time.
+/// Either way the stored values are UTC instants, so values at other offsets keep
+/// their instant but are read back at the column's offset.
+fn default_timezone(column: &arrow::array::ArrayRef, chrono_type: &str) -> String {
+    use arrow::array::AsArray;
+
+    match chrono_type {
+        "DateTimeFixedOffset" => {
+            let first = match column.data_type() {
+                DataType::Utf8 => column.as_string::<i32>().iter().flatten().next(),
+                DataType::LargeUtf8 => column.as_string::<i64>().iter().flatten().next(),
+                _ => None,
+            };
+            first
+                .and_then(|value| DateTime::parse_from_rfc3339(value).ok())
+                .map_or_else(|| "+00:00".to_string(), |value| value.offset().to_string())
+        }
+        "DateTimeLocal" => chrono::Local::now().offset().to_string(),
+        _ => "UTC".to_string(),
+    }
+}
+
+/// Parse the string items of a list column holding `chrono_type` values.
+///
+/// The list keeps its offsets and nulls, and only its items change type. Lists
+/// whose items already have a temporal type are returned as they are.
+fn convert_chrono_list(
+    column: &arrow::array::ArrayRef,
+    field_name: &str,
+    chrono_type: &str,
+    options: &ConversionOptions,
+) -> Result<(arrow::array::ArrayRef, DataType)> {
+    use arrow::array::{Array, AsArray, LargeListArray, ListArray};
+
+    let item = match column.data_type() {
+        DataType::List(item) | DataType::LargeList(item) => item,
+        other => unreachable!("list columns were selected by type, found {}", other),
+    };
+    let values = match item.data_type() {
+        DataType::Utf8 | DataType::LargeUtf8 | DataType::Null => match column.data_type() {
+            DataType::List(_) => column.as_list::<i32>().values().clone(),
+            _ => column.as_list::<i64>().values().clone(),
+        },
+        DataType::Dictionary(_, value_type) if is_string_type(value_type) => {
+            let values = match column.data_type() {
+                DataType::List(_) => column.as_list::<i32>().values(),
+                _ => column.as_list::<i64>().values(),
+            };
+            compute::cast(values, &DataType::Utf8).map_err(|e| PolarsSerdeError::ConversionError {
+                message: format!("Failed to read strings of list column '{}': {}", field_name, e),
+            })?
+        }
+        _ => return Ok((column.clone(), column.data_type().clone())),
+    };
+
+    let (values, item_type) = convert_chrono_strings(&values, field_name, chrono_type, options)?;
+    // Unparseable items become nulls with `OnParseError::Null`
+    let item = Arc::new(Field::new(item.name(), item_type, item.is_nullable() || values.null_count() > 0));
+    let failed = |e: arrow::error::ArrowError| PolarsSerdeError::ConversionError {
+        message: format!("Failed to convert list column '{}': {}", field_name, e),
+    };
+    Ok(match column.data_type() {
+        DataType::List(_) => {
+            let list = column.as_list::<i32>();
+            let list = ListArray::try_new(item.clone(), list.offsets().clone(), values, list.nulls().cloned())
+                .map_err(failed)?;
+            (Arc::new(list), DataType::List(item))
+        }
+        _ => {
+            let list = column.as_list::<i64>();
+            let list = LargeListArray::try_new(item.clone(), list.offsets().clone(), values, list.nulls().cloned())
+                .map_err(failed)?;
+            (Arc::new(list), DataType::LargeList(item))
+        }
+    })
+}
+
+/// Convert Date32 arrays back to string arrays for chrono deserialization  
+#[cfg(any(test, all(feature = "json", feature = "dataframe")))]
+fn convert_date32_to_string(column: &arrow::array::ArrayRef) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{Date32Array, StringBuilder};
+    use arrow::array::Array;
+    
+    let date_array = column.as_any().downcast_ref::<Date32Array>()
+        .ok_or_else(|| PolarsSerdeError::ConversionError {
+            message: "Expected Date32 array for string conversion".to_string(),
+        })?;
+    
+    let mut builder = StringBuilder::new();
+    
+    for i in 0..date_array.len() {
+        if date_array.is_null(i) {
+            builder.append_null();
+        } else {
+            let days = date_array.value(i);
+            let date = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + chrono::Duration::days(days as i64);
+            builder.append_value(date.format("%Y-%m-%d").to_string());
+        }
+    }
+    
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Convert Timestamp arrays to strings for deserialization.
+///
+/// Without a `format`, tz-aware timestamps become RFC 3339 strings and tz-naive
+/// ones `%Y-%m-%dT%H:%M:%S%.f` strings, as chrono parses them. A `format` uses
+/// chrono's strftime syntax. Values of a column tagged with a fixed offset such as
+/// `+05:30` are given at that offset, and other tz-aware values in UTC.
+fn convert_timestamp_to_string(
+    column: &arrow::array::ArrayRef,
+    timezone: Option<Arc<str>>,
+    format: Option<&str>,
+) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{TimestampNanosecondArray, StringBuilder};
+    use arrow::array::Array;
+    use std::fmt::Write;
+
+    let nanos_type = DataType::Timestamp(TimeUnit::Nanosecond, timezone.clone());
+    let column = compute::cast(column, &nanos_type)?;
+    let offset = timezone.as_deref().and_then(|tz| tz.parse::<chrono::FixedOffset>().ok());
+    let ts_array = column.as_any().downcast_ref::<TimestampNanosecondArray>()
+        .ok_or_else(|| PolarsSerdeError::ConversionError {
+            message: "Expected Timestamp array for string conversion".to_string(),
+        })?;
+    
+    let mut builder = StringBuilder::new();
+    let mut formatted = String::new();
+    
+    for i in 0..ts_array.len() {
+        if ts_array.is_null(i) {
+            builder.append_null();
+        } else {
+            let nanos = ts_array.value(i);
+            let dt = DateTime::from_timestamp_nanos(nanos);
+            let at_offset = offset.map(|offset| dt.with_timezone(&offset));
+
+            match (format, &timezone) {
+                (Some(format), _) => {
+                    formatted.clear();
+                    let written = if let Some(at_offset) = at_offset {
+                        write!(formatted, "{}", at_offset.format(format))
+                    } else if timezone.is_some() {
+                        write!(formatted, "{}", dt.format(format))
+                    } else {
+                        write!(formatted, "{}", dt.naive_utc().format(format))
+                    };
+                    written.map_err(|_| PolarsSerdeError::ConversionError {
+                        message: format!("Invalid timestamp output format '{}'", format),
+                    })?;
+                    builder.append_value(&formatted);
+                }
+                // Convert to DateTime string (RFC3339 format), at the column's offset if it has one
+                (None, Some(_)) => match at_offset {
+                    Some(at_offset) => builder.append_value(at_offset.to_rfc3339()),
+                    None => builder.append_value(dt.to_rfc3339()),
+                },
+                // Convert to NaiveDateTime string
+                (None, None) => builder.append_value(dt.naive_utc().format("%Y-%m-%dT%H:%M:%S%.f").to_string()),
+            }
+        }
+    }
+    
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Replace the named timestamp columns with their values formatted as strings
+fn format_timestamp_columns(batch: RecordBatch, columns: &[String], format: &str) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match field.data_type() {
+            DataType::Timestamp(_, tz) if columns.contains(field.name()) => {
+                new_columns.push(convert_timestamp_to_string(column, tz.clone(), Some(format))?);
+                new_fields.push(Arc::new(Field::new(field.name(), DataType::Utf8, field.is_nullable())));
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Convert `Weekday` or `Month` names to numbers: weekdays count from Monday as 0,
+/// months from January as 1
+fn convert_calendar_names_to_numbers(
+    column: &arrow::array::ArrayRef,
+    chrono_type: &str,
+) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{StringArray, UInt8Array};
+
+    let strings = compute::cast(column, &DataType::Utf8)?;
+    let strings = strings
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .expect("cast to Utf8 produces a StringArray");
+
+    let numbers = strings
+        .iter()
+        .map(|name| {
+            name.map(|name| {
+                let number = match chrono_type {
+                    "Weekday" => name
+                        .parse::<chrono::Weekday>()
+                        .ok()
+                        .map(|day| day.num_days_from_monday() as u8),
+                    _ => name
+                        .parse::<chrono::Month>()
+                        .ok()
+                        .map(|month| month.number_from_month() as u8),
+                };
+                number.ok_or_else(|| PolarsSerdeError::ConversionError {
+                    message: format!("Failed to parse {} name: {}", chrono_type, name),
+                })
+            })
+            .transpose()
+        })
+        .collect::<Result<UInt8Array>>()?;
+
+    Ok(Arc::new(numbers))
+}
+
+/// Convert `Weekday` or `Month` numbers back to the names chrono deserializes
+fn convert_calendar_numbers_to_names(
+    column: &arrow::array::ArrayRef,
+    chrono_type: &str,
+) -> Result<arrow::array::ArrayRef> {
+    use arrow::array::{StringArray, UInt8Array};
+
+    let numbers = column
+        .as_any()
+        .downcast_ref::<UInt8Array>()
+        .ok_or_else(|| PolarsSerdeError::ConversionError {
+            message: format!("Expected UInt8 array for {} conversion", chrono_type),
+        })?;
+
+    let names = numbers
+        .iter()
+        .map(|number| {
+            number
+                .map(|number| {
+                    let name = match chrono_type {
+                        "Weekday" => chrono::Weekday::try_from(number).ok().map(|day| day.to_string()),
+                        _ => chrono::Month::try_from(number).ok().map(|month| month.name().to_string()),
+                    };
+                    name.ok_or_else(|| PolarsSerdeError::ConversionError {
+                        message: format!("{} is not a valid {} number", number, chrono_type),
+                    })
+                })
+                .transpose()
+        })
+        .collect::<Result<StringArray>>()?;
+
+    Ok(Arc::new(names))
+}
+
+/// Convert Date32/Timestamp columns back to string for serde_arrow compatibility
+fn convert_from_chrono_columns(
+    batch: RecordBatch,
+    utc_columns: &[String],
+    naive_columns: &[String],
+    offset_columns: &[String],
+    calendar_columns: &HashMap<String, String>,
+) -> Result<RecordBatch> {
+    
+    // eprintln!("DEBUG: convert_from_chrono_columns - Input batch has {} rows, {} columns", 
+    //          batch.num_rows(), batch.num_columns());
+    
+    let mut new_columns = Vec::new();
+    let mut new_fields = Vec::new();
+    let schema = batch.schema();
+
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = schema.field(i);
+        let field_name = field.name();
+        
+        // eprintln!("DEBUG: Processing column '{}' with type {:?}, {} rows", 
+        //          field_name, field.data_type(), column.len());
+        
+        // DO NOT automatically convert Date/Timestamp columns to strings!
+        // Let serde_arrow handle the conversion based on what the target struct expects.
+        // If the struct expects a chrono type, serde_arrow will handle Date→chrono conversion.
+        // If the struct expects an integer, serde_arrow will handle Date→integer conversion.
+        
+        // Keep all types as-is and let serde_arrow handle the conversion.
+        // The one exception is timezone metadata: serde_arrow only accepts UTC or
+        // tz-naive timestamps, and the stored instants are UTC regardless of the
+        // zone a column is tagged with, so other zones are relabelled as UTC.
+        match field.data_type() {
+            // Timezone-aware timestamps read into `NaiveDateTime` fields give the UTC
+            // wall-clock time, as naive timestamps carry no offset
+            DataType::Timestamp(unit, Some(_)) if naive_columns.iter().any(|name| name == field_name) => {
+                let naive_type = DataType::Timestamp(*unit, None);
+                let relabelled = relabel_column(column, naive_type.clone()).map_err(|e| {
+                    PolarsSerdeError::ConversionError {
+                        message: format!("Failed to drop timezone of column '{}': {}", field_name, e),
+                    }
+                })?;
+                new_columns.push(relabelled);
+                new_fields.push(Arc::new(Field::new(field_name, naive_type, field.is_nullable())));
+            }
+            // Timestamps tagged with a fixed offset are read as RFC 3339 strings at that
+            // offset, which `DateTime<FixedOffset>` fields keep and other fields convert
+            DataType::Timestamp(_, Some(tz)) if offset_columns.iter().any(|name| name == field_name) => {
+                new_columns.push(convert_timestamp_to_string(column, Some(tz.clone()), None)?);
+                new_fields.push(Arc::new(Field::new(field_name, DataType::Utf8, field.is_nullable())));
+            }
+            DataType::Timestamp(unit, Some(tz)) if !tz.eq_ignore_ascii_case("UTC") => {
+                let utc_type = DataType::Timestamp(*unit, Some("UTC".into()));
+                let relabelled = compute::cast(column, &utc_type).map_err(|e| {
+                    PolarsSerdeError::ConversionError {
+                        message: format!("Failed to relabel timezone of column '{}': {}", field_name, e),
+                    }
+                })?;
+                new_columns.push(relabelled);
+                new_fields.push(Arc::new(Field::new(field_name, utc_type, field.is_nullable())));
+            }
+            // tz-naive timestamps read into `DateTime<Utc>` fields are taken to be UTC
+            DataType::Timestamp(unit, None) if utc_columns.iter().any(|name| name == field_name) => {
+                // Only the type changes; the stored values already are the UTC instants
+                let utc_type = DataType::Timestamp(*unit, Some("UTC".into()));
+                let relabelled = relabel_column(column, utc_type.clone()).map_err(|e| {
+                    PolarsSerdeError::ConversionError {
+                        message: format!("Failed to mark column '{}' as UTC: {}", field_name, e),
+                    }
+                })?;
+                new_columns.push(relabelled);
+                new_fields.push(Arc::new(Field::new(field_name, utc_type, field.is_nullable())));
+            }
+            // `Weekday` and `Month` are stored as numbers but deserialize from names
+            DataType::UInt8 if calendar_columns.contains_key(field_name) => {
+                new_columns.push(convert_calendar_numbers_to_names(column, &calendar_columns[field_name])?);
+                new_fields.push(Arc::new(Field::new(field_name, DataType::Utf8, field.is_nullable())));
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(Arc::new(field.clone()));
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    let result = RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })?;
+    
+    // eprintln!("DEBUG: convert_from_chrono_columns - Output batch has {} rows, {} columns", 
+    //          result.num_rows(), result.num_columns());
+    
+    Ok(result)
+}
+
+/// Replace date, time, timestamp and duration columns with their integer representation,
+/// except for the columns named in `skip`
+fn temporal_columns_to_integers(batch: RecordBatch, skip: &[String]) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let integer_type = match field.data_type() {
+            _ if skip.contains(field.name()) => None,
+            DataType::Date32 | DataType::Time32(_) => Some(DataType::Int32),
+            DataType::Date64 | DataType::Time64(_) | DataType::Timestamp(_, _) | DataType::Duration(_) => {
+                Some(DataType::Int64)
+            }
+            _ => None,
+        };
+
+        match integer_type {
+            Some(integer_type) => {
+                let integers = compute::cast(column, &integer_type).map_err(|e| PolarsSerdeError::ConversionError {
+                    message: format!("Failed to read column '{}' as integers: {}", field.name(), e),
+                })?;
+                new_columns.push(integers);
+                new_fields.push(Arc::new(Field::new(field.name(), integer_type, field.is_nullable())));
+            }
+            None => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Change the type of a column without touching its values
+fn relabel_column(
+    column: &arrow::array::ArrayRef,
+    data_type: DataType,
+) -> std::result::Result<arrow::array::ArrayRef, arrow::error::ArrowError> {
+    column
+        .to_data()
+        .into_builder()
+        .data_type(data_type)
+        .build()
+        .map(arrow::array::make_array)
+}
+
+/// Whether values of this type are strings (`Utf8`, `LargeUtf8` or `Utf8View`)
+fn is_string_type(data_type: &DataType) -> bool {
+    matches!(data_type, DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View)
+}
+
+/// Helper function to convert dictionary arrays to string arrays to avoid categorical issues
+
+fn convert_dictionary_to_strings(batch: RecordBatch) -> Result<RecordBatch> {
+    let mut new_columns = Vec::new();
+    let mut new_fields = Vec::new();
+    let schema = batch.schema();
+
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = schema.field(i);
+
+        match field.data_type() {
+            DataType::Dictionary(_, value_type) if is_string_type(value_type) => {
+                // Convert dictionary array to a string array of the same string width;
+                // Arrow cannot cast string view dictionaries (as Polars exports
+                // categoricals), so their values are looked up as plain strings
+                let (string_array, string_type) = if value_type.as_ref() == &DataType::Utf8View {
+                    use arrow::array::AsArray;
+
+                    let dictionary = column.as_any_dictionary();
+                    let values = compute::cast(dictionary.values(), &DataType::Utf8)
+                        .and_then(|values| compute::take(&values, dictionary.keys(), None));
+                    (values, DataType::Utf8)
+                } else {
+                    (compute::cast(column, value_type), value_type.as_ref().clone())
+                };
+                let string_array = string_array.map_err(|e| PolarsSerdeError::ConversionError {
+                    message: format!("Failed to convert dictionary to string: {}", e),
+                })?;
+                new_columns.push(string_array);
+                new_fields.push(Arc::new(Field::new(field.name(), string_type, field.is_nullable())));
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(Arc::new(field.clone()));
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+
+/// Whether any column of the batch contains only nulls
+fn has_all_null_column(batch: &RecordBatch) -> bool {
+    batch.num_rows() > 0
+        && batch.columns().iter().any(|column| column.null_count() == column.len())
+}
+
+/// Give entirely-null columns the type traced for the matching target field.
+///
+/// All-null columns carry no type information worth keeping (Polars `Null` columns
+/// arrive as Boolean), so they are replaced by a typed null array of the traced type.
+fn retype_null_columns(batch: RecordBatch, traced_fields: &[FieldRef]) -> Result<RecordBatch> {
+    let mut new_columns = Vec::new();
+    let mut new_fields = Vec::new();
+    let schema = batch.schema();
+
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = schema.field(i);
+        let traced = traced_fields.iter().find(|traced| traced.name() == field.name());
+
+        match traced {
+            Some(traced)
+                if column.null_count() == column.len() && traced.data_type() != field.data_type() =>
+            {
+                new_columns.push(arrow::array::new_null_array(traced.data_type(), column.len()));
+                new_fields.push(Arc::new(Field::new(field.name(), traced.data_type().clone(), true)));
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(Arc::new(field.clone()));
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Add a `Null` column for each of `names` to the batch
+fn append_null_columns(batch: RecordBatch, names: &[&str]) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_fields: Vec<FieldRef> = schema.fields().iter().cloned().collect();
+    let mut new_columns = batch.columns().to_vec();
+
+    for name in names {
+        new_fields.push(Arc::new(Field::new(*name, DataType::Null, true)));
+        new_columns.push(arrow::array::new_null_array(&DataType::Null, batch.num_rows()));
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Narrow integer columns to the integer type of the matching target field.
+///
+/// Only columns wider than their field are cast; narrower ones are left for serde
+/// to widen. Every non-null value must fit the narrower type; the first one that
+/// does not is reported together with its column instead of being turned into a null.
+fn downcast_integer_columns(batch: RecordBatch, field_types: &HashMap<String, DataType>) -> Result<RecordBatch> {
+    let mut new_columns = Vec::new();
+    let mut new_fields = Vec::new();
+    let schema = batch.schema();
+
+    for (i, column) in batch.columns().iter().enumerate() {
+        let field = schema.field(i);
+        let target = field_types.get(field.name()).filter(|target| {
+            field.data_type().is_integer() && target.primitive_width() < field.data_type().primitive_width()
+        });
+
+        match target {
+            Some(target) => {
+                let narrowed = compute::cast(column, target).map_err(|e| {
+                    PolarsSerdeError::ConversionError {
+                        message: format!("Failed to downcast column '{}': {}", field.name(), e),
+                    }
+                })?;
+
+                // The safe cast turns out-of-range values into nulls
+                if narrowed.null_count() != column.null_count() {
+                    let row = (0..column.len())
+                        .find(|&row| column.is_valid(row) && narrowed.is_null(row))
+                        .unwrap_or_default();
+                    let value = arrow::util::display::array_value_to_string(column, row)
+                        .unwrap_or_default();
+                    return Err(PolarsSerdeError::ConversionError {
+                        message: format!(
+                            "Value {} in column '{}' does not fit in {}",
+                            value,
+                            field.name(),
+                            target
+                        ),
+                    });
+                }
+
+                new_columns.push(narrowed);
+                new_fields.push(Arc::new(Field::new(field.name(), target.clone(), field.is_nullable())));
+            }
+            None => {
+                new_columns.push(column.clone());
+                new_fields.push(Arc::new(field.clone()));
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Helper function to deserialize with chrono type detection
+fn deserialize_with_chrono_detection<T>(batch: &RecordBatch, by_position: bool) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    // Reading fields by position skips serde_arrow's per-row name lookups, which
+    // add up on wide frames. It relies on `T` reading a sequence in field order,
+    // so it is opt-in; any failure is retried by name so errors read as before.
+    if by_position {
+        if let Some(ordered) = columns_in_field_order::<T>(batch) {
+            if let Ok(records) = deserialize_by_position(&ordered) {
+                return Ok(records);
+            }
+        }
+    }
+
+    // Use standard serde_arrow deserialization
+    let result: Vec<T> = from_record_batch(batch).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to deserialize batch: {}", e),
+    })?;
+    Ok(result)
+}
+
+/// The batch with its columns in the order of the fields of `T`, if it has a column
+/// for each field of the struct `T` and no others
+fn columns_in_field_order<T: DeserializeOwned>(batch: &RecordBatch) -> Option<RecordBatch> {
+    let names = struct_field_names::<T>()?;
+    if names.len() != batch.num_columns() {
+        return None;
+    }
+    let schema = batch.schema();
+    let indices = names
+        .iter()
+        .map(|name| schema.index_of(name).ok())
+        .collect::<Option<Vec<usize>>>()?;
+    batch.project(&indices).ok()
+}
+
+/// Deserialize the records of a batch whose columns follow the fields of `T`
+fn deserialize_by_position<T: DeserializeOwned>(batch: &RecordBatch) -> std::result::Result<Vec<T>, serde_arrow::Error> {
+    let deserializer = serde_arrow::Deserializer::from_record_batch(batch)?;
+    deserializer
+        .iter()
+        .map(|record| T::deserialize(FieldsByPosition(record)))
+        .collect()
+}
+
+/// Deserializer handing structs their fields as a sequence, in column order
+struct FieldsByPosition<D>(D);
+
+impl<'de, D: serde::Deserializer<'de>> serde::Deserializer<'de> for FieldsByPosition<D> {
+    type Error = D::Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.deserialize_any(visitor)
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        self.0.deserialize_tuple(fields.len(), visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// The chrono type a temporal column holds, as used by [`normalize_temporal_columns`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ChronoKind {
+    /// `chrono::NaiveDate`, stored as `Date32`
+    NaiveDate,
+    /// `chrono::NaiveDateTime`, stored as a tz-naive nanosecond `Timestamp`
+    NaiveDateTime,
+    /// `chrono::DateTime<Utc>`, stored as a UTC nanosecond `Timestamp`
+    DateTimeUtc,
+}
+
+impl ChronoKind {
+    /// The name [`detect_chrono_types`] reports for this kind
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChronoKind::NaiveDate => "NaiveDate",
+            ChronoKind::NaiveDateTime => "NaiveDateTime",
+            ChronoKind::DateTimeUtc => "DateTimeUtc",
+        }
+    }
+}
+
+/// Convert string columns of an Arrow record batch to Arrow temporal types.
+///
+/// This is the conversion [`to_dataframe`] applies to chrono fields, for batches
+/// that did not come from serde (Arrow Flight, IPC files, ...). Each column named in
+/// `fields` is parsed into `Date32` or a nanosecond `Timestamp`; other columns are
+/// left untouched, as are named columns that already have the temporal type.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::collections::HashMap;
+/// use serde_polars::arrow::record_batch::RecordBatch;
+/// use serde_polars::{normalize_temporal_columns, ChronoKind};
+///
+/// let fields = HashMap::from([("day".to_string(), ChronoKind::NaiveDate)]);
+/// let batch: RecordBatch = normalize_temporal_columns(batch, &fields)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn normalize_temporal_columns(
+    batch: RecordBatch,
+    fields: &HashMap<String, ChronoKind>,
+) -> Result<RecordBatch> {
+    let chrono_types: HashMap<String, String> = fields
+        .iter()
+        .map(|(name, kind)| (name.clone(), kind.as_str().to_string()))
+        .collect();
+    convert_chrono_columns(batch, &chrono_types, &ConversionOptions::default())
+}
+
+/// Prepare the temporal columns of an Arrow record batch for deserialization.
+///
+/// This is the conversion [`from_dataframe`] applies before handing a batch to
+/// serde: timestamps tagged with a zone other than UTC are relabelled as UTC, the
+/// only zone serde_arrow reads. Dates and UTC or tz-naive timestamps stay as they are.
+pub fn denormalize_temporal_columns(batch: RecordBatch) -> Result<RecordBatch> {
+    convert_from_chrono_columns(batch, &[], &[], &[], &HashMap::new())
+}
+
+/// Convert a Polars DataFrame to Vec<T> where T implements Deserialize.
+///
+/// # Examples
+///
+/// ```ignore
+/// use polars::prelude::*;
+/// use serde::Deserialize;
+/// use serde_polars::from_dataframe;
+///
+/// #[derive(Debug, Deserialize)]
+/// struct Record {
+///     a: f64,
+///     b: i64,
+/// }
+///
+/// let df = df!(
+///     "a" => [1.0, 2.0, 3.0],
+///     "b" => [10i64, 20i64, 30i64]
+/// )?;
+///
+/// let records: Vec<Record> = from_dataframe(df)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn from_dataframe<T>(df: DataFrame) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    from_dataframe_with(df, &ConversionOptions::default())
+}
+
+/// Like [`from_dataframe`], borrowing the DataFrame so the caller can keep using it.
+///
+/// The conversion works on a clone of the frame, which shares its column buffers, so no
+/// data is copied for a single-chunk frame. A frame of several chunks is rechunked
+/// into new buffers rather than in place, which makes this marginally slower than
+/// [`from_dataframe`] there.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::from_dataframe_ref;
+///
+/// let records: Vec<Record> = from_dataframe_ref(&df)?;
+/// println!("{} rows in {:?}", records.len(), df.shape());
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn from_dataframe_ref<T>(df: &DataFrame) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    from_dataframe(df.clone())
+}
+
+/// Convert a Polars DataFrame to a vector of records using custom [`ConversionOptions`].
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{from_dataframe_with, ConversionOptions};
+///
+/// let options = ConversionOptions::new().downcast_integers(true);
+/// let records: Vec<Record> = from_dataframe_with(df, &options)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn from_dataframe_with<T>(df: DataFrame, options: &ConversionOptions) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    from_dataframe_with_stats(df, options).map(|(records, _)| records)
+}
+
+/// Like [`from_dataframe_with`], also returning [`ConversionStats`] for the conversion.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{from_dataframe_with_stats, ConversionOptions};
+///
+/// let (records, stats) = from_dataframe_with_stats::<Record>(df, &ConversionOptions::new())?;
+/// println!("read {} rows, {:?} deserializing", stats.rows, stats.serde_time);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn from_dataframe_with_stats<T>(
+    df: DataFrame,
+    options: &ConversionOptions,
+) -> Result<(Vec<T>, ConversionStats)>
+where
+    T: DeserializeOwned,
+{
+    if let Some(max_rows) = options.max_rows {
+        if df.height() > max_rows {
+            return Err(PolarsSerdeError::InvalidRowCount {
+                expected: max_rows,
+                actual: df.height(),
+            });
+        }
+    }
+
+    let mut stats = ConversionStats::default();
+    let height = df.height();
+
+    let started = Instant::now();
+    let batches: Vec<RecordBatch> = dataframe_to_batches::<T>(df)?;
+    stats.interchange_time = started.elapsed();
+
+    let records = records_from_batches(&batches, options, &mut stats)?;
+    check_row_count(height, records.len())?;
+    Ok((records, stats))
+}
+
+/// Export `df` to record batches to be read into records of `T`
+#[cfg(feature = "dataframe")]
+// `T` only picks the struct nulls to rebuild, which Polars 0.44 and later keep
+#[allow(clippy::extra_unused_type_parameters)]
+fn dataframe_to_batches<T: DeserializeOwned>(df: DataFrame) -> Result<Vec<RecordBatch>> {
+    let batches = version_compat::dataframe_to_arrow(df)?;
+
+    // Before 0.44, Polars keeps the nulls of a `Struct` column in its fields only,
+    // so the exported struct has no nulls of its own. They are rebuilt where `T`
+    // reads a null, as other structs may well have all fields null. Polars does
+    // not count a row as null when a nested struct holds it, so every struct
+    // column is checked
+    #[cfg(any(
+        feature = "polars_0_40",
+        feature = "polars_0_41",
+        feature = "polars_0_42",
+        feature = "polars_0_43"
+    ))]
+    let batches = batches
+        .into_iter()
+        .map(|batch| version_compat::null_structs_from_fields(batch, &field_path_accepts_unit::<T>))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(batches)
+}
+
+/// Deserialize record batches sharing one schema into records of `T`
+fn records_from_batches<T>(
+    batches: &[RecordBatch],
+    options: &ConversionOptions,
+    stats: &mut ConversionStats,
+) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    // Only trace the target type when there are columns to fix up; types that
+    // cannot be traced from the type alone keep the columns unchanged
+    let started = Instant::now();
+    let traced_fields = if batches.iter().any(has_all_null_column) {
+        Vec::<FieldRef>::from_type::<T>(default_tracing_options()).unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    stats.tracing_time += started.elapsed();
+
+    // Every batch shares the frame's schema, so the columns are inspected once
+    let schema = batches.first().map(|batch| batch.schema());
+    let utc_columns: Vec<String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| matches!(field.data_type(), DataType::Timestamp(_, None)))
+        .filter(|field| !options.never_convert_columns.contains(field.name()))
+        .filter(|field| field_requires_utc_offset::<T>(field.name()))
+        .map(|field| field.name().clone())
+        .collect();
+    let naive_columns: Vec<String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| matches!(field.data_type(), DataType::Timestamp(_, Some(_))))
+        .filter(|field| !options.never_convert_columns.contains(field.name()))
+        .filter(|field| field_rejects_utc_offset::<T>(field.name()))
+        .map(|field| field.name().clone())
+        .collect();
+    let offset_columns: Vec<String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| match field.data_type() {
+            DataType::Timestamp(_, Some(tz)) => tz.parse::<chrono::FixedOffset>().is_ok(),
+            _ => false,
+        })
+        .filter(|field| !options.never_convert_columns.contains(field.name()) && !naive_columns.contains(field.name()))
+        .filter(|field| field_accepts::<T>(field.name(), "1970-01-01T00:00:00+00:00"))
+        .map(|field| field.name().clone())
+        .collect();
+    let calendar_columns: HashMap<String, String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| field.data_type() == &DataType::UInt8)
+        .filter(|field| !options.never_convert_columns.contains(field.name()))
+        .filter_map(|field| Some((field.name().clone(), calendar_field_kind::<T>(field.name())?.to_string())))
+        .collect();
+    // Date and timestamp columns read into `time` fields become the tuples those types expect
+    #[cfg(feature = "time")]
+    let time_columns: HashMap<String, &'static str> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| matches!(field.data_type(), DataType::Date32 | DataType::Timestamp(_, _)))
+        .filter(|field| !options.never_convert_columns.contains(field.name()))
+        .filter_map(|field| Some((field.name().clone(), time_types::field_kind::<T>(field.name())?)))
+        .collect();
+    // Duration columns read into `chrono::Duration` fields become its `(seconds, nanoseconds)` tuples
+    let duration_columns: Vec<String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| matches!(field.data_type(), DataType::Duration(_)))
+        .filter(|field| !options.never_convert_columns.contains(field.name()))
+        .filter(|field| durations::field_accepts_duration::<T>(field.name()))
+        .map(|field| field.name().clone())
+        .collect();
+    // Timestamps read as strings in a custom format, where the target field accepts that format
+    let formatted_columns: Vec<String> = match &options.timestamp_output_format {
+        Some(format) => schema
+            .iter()
+            .flat_map(|schema| schema.fields().iter())
+            .filter(|field| !options.never_convert_columns.contains(field.name()))
+            .filter(|field| match field.data_type() {
+                DataType::Timestamp(_, tz) => {
+                    use arrow::array::AsArray;
+                    let epoch = arrow::array::TimestampNanosecondArray::from(vec![0]);
+                    convert_timestamp_to_string(&(Arc::new(epoch) as arrow::array::ArrayRef), tz.clone(), Some(format))
+                        .ok()
+                        .is_some_and(|sample| field_accepts::<T>(field.name(), sample.as_string::<i32>().value(0)))
+                }
+                _ => false,
+            })
+            .map(|field| field.name().clone())
+            .collect(),
+        None => Vec::new(),
+    };
+    // Tagged struct columns read into enum fields become unions again
+    let enum_columns: HashMap<String, &'static [&'static str]> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| match field.data_type() {
+            DataType::Struct(children) => children.iter().any(|child| child.name() == enum_columns::VARIANT_FIELD),
+            _ => false,
+        })
+        .filter_map(|field| Some((field.name().clone(), enum_columns::field_variants::<T>(field.name())?)))
+        .collect();
+    // Fixed-size lists read into array fields become structs of the elements
+    let array_columns: Vec<String> = schema
+        .iter()
+        .flat_map(|schema| schema.fields().iter())
+        .filter(|field| match field.data_type() {
+            DataType::FixedSizeList(_, len) => {
+                fixed_size_lists::field_tuple_len::<T>(field.name()) == Some(*len as usize)
+            }
+            _ => false,
+        })
+        .map(|field| field.name().clone())
+        .collect();
+    // Nulls read into float fields that are not `Option`s, where the options allow it
+    let nan_columns: Vec<String> = match options.null_float_as_nan {
+        true => schema
+            .iter()
+            .flat_map(|schema| schema.fields().iter())
+            .filter(|field| matches!(field.data_type(), DataType::Float32 | DataType::Float64))
+            .filter(|field| !field_accepts_unit::<T>(field.name()))
+            .map(|field| field.name().clone())
+            .collect(),
+        false => Vec::new(),
+    };
+    // Integer fields, for narrowing wider integer columns where the options allow it
+    let integer_fields: HashMap<String, DataType> = match options.downcast_integers {
+        true => schema
+            .iter()
+            .flat_map(|schema| schema.fields().iter())
+            .filter_map(|field| Some((field.name().clone(), field_integer_type::<T>(field.name())?)))
+            .collect(),
+        false => HashMap::new(),
+    };
+    // Zero-sized fields such as `PhantomData` have no column and read from nulls
+    let unit_columns: Vec<&str> = struct_field_names::<T>()
+        .unwrap_or_default()
+        .iter()
+        .copied()
+        .filter(|name| schema.as_ref().is_some_and(|schema| schema.field_with_name(name).is_err()))
+        .filter(|name| field_accepts_unit::<T>(name))
+        .collect();
+    // Columns the options ask to parse go through the same conversion as chrono fields
+    let parsed_columns: HashMap<String, String> = options
+        .parse_columns_as
+        .iter()
+        .filter(|(name, _)| !options.never_convert_columns.contains(name))
+        .map(|(name, kind)| (name.clone(), kind.as_str().to_string()))
+        .collect();
+
+    stats.chrono_columns = utc_columns.len()
+        + calendar_columns.len()
+        + duration_columns.len()
+        + parsed_columns.len()
+        + schema.map_or(0, |schema| {
+            schema
+                .fields()
+                .iter()
+                .filter(|field| match field.data_type() {
+                    DataType::Timestamp(_, Some(tz)) => {
+                        !tz.eq_ignore_ascii_case("UTC") || naive_columns.contains(field.name())
+                    }
+                    _ => false,
+                })
+                .count()
+        });
+    #[cfg(feature = "time")]
+    {
+        stats.chrono_columns += time_columns.len();
+    }
+
+    let deserialize_batch = |batch: &RecordBatch, stats: &mut ConversionStats| -> Result<Vec<T>> {
+        // Apply reverse chrono conversion for DataFrame to struct conversion
+        let started = Instant::now();
+        let converted_batch = if parsed_columns.is_empty() {
+            batch.clone()
+        } else {
+            convert_chrono_columns(batch.clone(), &parsed_columns, options)?
+        };
+        let converted_batch = if duration_columns.is_empty() {
+            converted_batch
+        } else {
+            durations::duration_columns_to_tuples(converted_batch, &duration_columns)?
+        };
+        let converted_batch = if options.temporal_as_integers {
+            temporal_columns_to_integers(converted_batch, &options.never_convert_columns)?
+        } else {
+            converted_batch
+        };
+        let converted_batch = match &options.timestamp_output_format {
+            Some(format) if !formatted_columns.is_empty() => {
+                format_timestamp_columns(converted_batch, &formatted_columns, format)?
+            }
+            _ => converted_batch,
+        };
+        #[cfg(feature = "time")]
+        let converted_batch = if time_columns.is_empty() {
+            converted_batch
+        } else {
+            time_types::temporal_columns_to_tuples(converted_batch, &time_columns)?
+        };
+        let converted_batch = convert_from_chrono_columns(
+            converted_batch,
+            &utc_columns,
+            &naive_columns,
+            &offset_columns,
+            &calendar_columns,
+        )?;
+        stats.chrono_time += started.elapsed();
+
+        let converted_batch = if options.enum_discriminants.is_empty() {
+            converted_batch
+        } else {
+            enum_columns::discriminants_to_variants(converted_batch, &options.enum_discriminants)?
+        };
+        let converted_batch = if options.bool_as_enum.is_empty() {
+            converted_batch
+        } else {
+            enum_columns::booleans_to_variants(converted_batch, &options.bool_as_enum)?
+        };
+
+        // Dictionary-encoded strings from outside the crate are read as plain strings
+        let started = Instant::now();
+        let converted_batch = convert_dictionary_to_strings(converted_batch)?;
+        stats.dictionary_time += started.elapsed();
+
+        let started = Instant::now();
+        let converted_batch = if traced_fields.is_empty() {
+            converted_batch
+        } else {
+            retype_null_columns(converted_batch, &traced_fields)?
+        };
+        let converted_batch = if integer_fields.is_empty() {
+            converted_batch
+        } else {
+            downcast_integer_columns(converted_batch, &integer_fields)?
+        };
+        let converted_batch = if nan_columns.is_empty() {
+            converted_batch
+        } else {
+            nulls_to_nans(converted_batch, &nan_columns)?
+        };
+        let converted_batch = if enum_columns.is_empty() {
+            converted_batch
+        } else {
+            enum_columns::struct_columns_to_unions(converted_batch, &enum_columns)?
+        };
+        let converted_batch = if array_columns.is_empty() {
+            converted_batch
+        } else {
+            fixed_size_lists::lists_to_structs(converted_batch, &array_columns)?
+        };
+        let converted_batch = if unit_columns.is_empty() {
+            converted_batch
+        } else {
+            append_null_columns(converted_batch, &unit_columns)?
+        };
+        let records = deserialize_with_chrono_detection(&converted_batch, options.read_fields_by_position).map_err(|error| match &error {
+            // Name the column and the valid variants, which serde's message lacks
+            PolarsSerdeError::ConversionError { message } if message.contains("unknown variant") => {
+                enum_columns::find_unknown_variant::<T>(&converted_batch).unwrap_or(error)
+            }
+            _ => error,
+        })?;
+        stats.serde_time += started.elapsed();
+        stats.rows += records.len();
+        Ok(records)
+    };
+
+    // A single-chunk frame is the common case; its records need no collecting
+    let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    if let [batch] = batches {
+        let records = deserialize_batch(batch, stats)?;
+        check_row_count(total_rows, records.len())?;
+        return Ok(records);
+    }
+
+    let mut out = Vec::with_capacity(total_rows);
+    for batch in batches {
+        let mut part: Vec<T> = deserialize_batch(batch, stats)?;
+        out.append(&mut part);
+    }
+    check_row_count(total_rows, out.len())?;
+    Ok(out)
+}
+
+/// Convert a DataFrame into one JSON object per row, keyed by column name.
+///
+/// For tools that inspect or export DataFrames without a record type. Numbers,
+/// booleans and strings keep their JSON types and nulls become `null`. `Date`
+/// columns become ISO 8601 dates (`"2024-05-01"`), and `Datetime` columns RFC 3339
+/// timestamps for timezone-aware columns or ISO 8601 ones otherwise. Lists and
+/// structs become arrays and nested objects.
+///
+/// Requires the `json` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::from_dataframe_dynamic;
+///
+/// let df = df!("id" => [1i64], "name" => ["Alice"])?;
+/// let rows = from_dataframe_dynamic(df)?;
+/// assert_eq!(rows[0], serde_json::json!({"id": 1, "name": "Alice"}));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(all(feature = "json", feature = "dataframe"))]
+pub fn from_dataframe_dynamic(df: DataFrame) -> Result<Vec<serde_json::Value>> {
+    let height = df.height();
+    let batches: Vec<RecordBatch> = dataframe_to_batches::<serde_json::Value>(df)?;
+
+    let mut rows = Vec::new();
+    for batch in batches {
+        let batch = convert_dictionary_to_strings(batch)?;
+        let batch = temporal_columns_to_strings(batch)?;
+        let mut part: Vec<serde_json::Value> = from_record_batch(&batch).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to convert batch to JSON: {}", e),
+        })?;
+        rows.append(&mut part);
+    }
+    check_row_count(height, rows.len())?;
+    Ok(rows)
+}
+
+/// Replace `Date32` and `Timestamp` columns with their ISO 8601 strings
+#[cfg(all(feature = "json", feature = "dataframe"))]
+fn temporal_columns_to_strings(batch: RecordBatch) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let strings = match field.data_type() {
+            DataType::Date32 => Some(convert_date32_to_string(column)?),
+            DataType::Timestamp(_, tz) => Some(convert_timestamp_to_string(column, tz.clone(), None)?),
+            _ => None,
+        };
+
+        match strings {
+            Some(strings) => {
+                new_columns.push(strings);
+                new_fields.push(Arc::new(Field::new(field.name(), DataType::Utf8, field.is_nullable())));
+            }
+            None => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Tracing options shared by every schema tracing pass
+fn default_tracing_options() -> TracingOptions {
+    // Configure TracingOptions to support enums as strings and avoid dictionary arrays completely
+    TracingOptions::default()
+        .enums_without_data_as_strings(true) // Convert simple enums to strings
+        .allow_null_fields(true) // Allow nullable fields for better compatibility
+        .map_as_struct(false) // Don't use struct for maps
+        .string_dictionary_encoding(false) // Avoid dictionary encoding which requires categorical
+        .coerce_numbers(false) // Be strict about types
+}
+
+/// Check that a conversion kept every row, so an interchange bug cannot drop data silently
+fn check_row_count(expected: usize, actual: usize) -> Result<()> {
+    if expected != actual {
+        return Err(PolarsSerdeError::InvalidRowCount { expected, actual });
+    }
+    Ok(())
+}
+
+/// Error for record types that would produce a DataFrame without any columns
+fn no_serializable_fields<T>() -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!("type `{}` has no serializable fields", std::any::type_name::<T>()),
+    }
+}
+
+/// Error raised while probing how a struct field deserializes
+#[derive(Debug)]
+enum ProbeError {
+    /// The probed value was accepted and the struct then noticed its other fields are absent
+    MissingField,
+    /// The probed value asked for an integer of this type
+    Integer(DataType),
+    Other,
+}
+
+impl std::fmt::Display for ProbeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("field probe failed")
+    }
+}
+
+impl std::error::Error for ProbeError {}
+
+impl serde::de::Error for ProbeError {
+    fn custom<M: std::fmt::Display>(_msg: M) -> Self {
+        ProbeError::Other
+    }
+
+    fn missing_field(_field: &'static str) -> Self {
+        ProbeError::MissingField
+    }
+}
+
+/// Deserializer offering a struct a single field, holding a string value
+struct FieldProbe<'a> {
+    field: String,
+    value: &'a str,
+}
+
+impl<'de> serde::de::Deserializer<'de> for FieldProbe<'_> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_map(serde::de::value::MapDeserializer::new(std::iter::once((
+            self.field,
+            ProbeValue(self.value),
+        ))))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// String value that also passes through `Option` and newtype wrappers
+struct ProbeValue<'a>(&'a str);
+
+impl<'de> serde::de::IntoDeserializer<'de, ProbeError> for ProbeValue<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for ProbeValue<'_> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Whether field `field` of `T` accepts the string `value`
+fn field_accepts<T: DeserializeOwned>(field: &str, value: &str) -> bool {
+    let probe = FieldProbe {
+        field: field.to_string(),
+        value,
+    };
+    matches!(T::deserialize(probe), Ok(_) | Err(ProbeError::MissingField))
+}
+
+/// The Arrow type of field `field` of `T` when it is an integer, optional or not
+fn field_integer_type<T: DeserializeOwned>(field: &str) -> Option<DataType> {
+    let probe = serde::de::value::MapDeserializer::<_, ProbeError>::new(std::iter::once((field, IntegerProbe)));
+    match T::deserialize(probe) {
+        Err(ProbeError::Integer(data_type)) => Some(data_type),
+        _ => None,
+    }
+}
+
+/// Deserializer that fails with the integer type a value asks for
+struct IntegerProbe;
+
+impl<'de> serde::de::IntoDeserializer<'de, ProbeError> for IntegerProbe {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for IntegerProbe {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(ProbeError::Other)
+    }
+
+    fn deserialize_i8<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::Int8))
+    }
+
+    fn deserialize_i16<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::Int16))
+    }
+
+    fn deserialize_i32<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::Int32))
+    }
+
+    fn deserialize_i64<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::Int64))
+    }
+
+    fn deserialize_u8<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::UInt8))
+    }
+
+    fn deserialize_u16<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::UInt16))
+    }
+
+    fn deserialize_u32<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::UInt32))
+    }
+
+    fn deserialize_u64<V: serde::de::Visitor<'de>>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error> {
+        Err(ProbeError::Integer(DataType::UInt64))
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i128 u128 f32 f64 char str string bytes byte_buf unit unit_struct
+        seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Whether field `field` of `T` accepts a unit value, as `PhantomData` and `()` do.
+///
+/// `Option` fields accept it too, reading it as `None`.
+fn field_accepts_unit<T: DeserializeOwned>(field: &str) -> bool {
+    field_path_accepts_unit::<T>(&[field])
+}
+
+/// Whether the field at `path` of `T`, through nested structs, accepts a unit value.
+///
+/// A struct column is only given null rows where this holds, as `Option` fields read
+/// them as `None` and other structs reject them.
+fn field_path_accepts_unit<T: DeserializeOwned>(path: &[&str]) -> bool {
+    matches!(T::deserialize(PathProbe(path)), Ok(_) | Err(ProbeError::MissingField))
+}
+
+/// Deserializer offering nested structs a single field each along a path, ending in a unit value
+#[derive(Clone, Copy)]
+struct PathProbe<'a>(&'a [&'a str]);
+
+impl<'de> serde::de::IntoDeserializer<'de, ProbeError> for PathProbe<'_> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+impl<'de> serde::de::Deserializer<'de> for PathProbe<'_> {
+    type Error = ProbeError;
+
+    fn deserialize_any<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        match self.0.split_first() {
+            None => visitor.visit_unit(),
+            Some((field, rest)) => {
+                visitor.visit_map(serde::de::value::MapDeserializer::new(std::iter::once((*field, PathProbe(rest)))))
+            }
+        }
+    }
+
+    fn deserialize_option<V>(self, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V>(self, _name: &'static str, visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Whether field `field` of `T` needs an explicit UTC offset, as `DateTime<Utc>` does.
+///
+/// serde_arrow reads tz-naive timestamps as strings without an offset, which
+/// `NaiveDateTime` accepts and `DateTime<Utc>` rejects.
+fn field_requires_utc_offset<T: DeserializeOwned>(field: &str) -> bool {
+    !field_accepts::<T>(field, "1970-01-01T00:00:00")
+        && field_accepts::<T>(field, "1970-01-01T00:00:00Z")
+}
+
+/// Whether field `field` of `T` is a chrono `Weekday` or `Month`, which read from names
+fn calendar_field_kind<T: DeserializeOwned>(field: &str) -> Option<&'static str> {
+    // String fields accept any name, and so does a field `T` does not have
+    if field_accepts::<T>(field, "") {
+        None
+    } else if field_accepts::<T>(field, "Mon") {
+        Some("Weekday")
+    } else if field_accepts::<T>(field, "January") {
+        Some("Month")
+    } else {
+        None
+    }
+}
+
+/// Whether field `field` of `T` rejects an explicit UTC offset, as `NaiveDateTime` does
+fn field_rejects_utc_offset<T: DeserializeOwned>(field: &str) -> bool {
+    field_accepts::<T>(field, "1970-01-01T00:00:00")
+        && !field_accepts::<T>(field, "1970-01-01T00:00:00Z")
+}
+
+/// Deserializer that records the field names a struct asks for without reading any data
+struct FieldNameCollector<'a> {
+    fields: &'a mut Option<&'static [&'static str]>,
+}
+
+impl<'de> serde::de::Deserializer<'de> for FieldNameCollector<'_> {
+    type Error = serde::de::value::Error;
+
+    fn deserialize_any<V>(self, _visitor: V) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        Err(serde::de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> std::result::Result<V::Value, Self::Error>
+    where
+        V: serde::de::Visitor<'de>,
+    {
+        *self.fields = Some(fields);
+        // Abort once the names are known; no values are ever produced
+        Err(serde::de::Error::custom("field names collected"))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
+
+/// Names of the top-level struct fields `T` expects, if `T` deserializes from a struct
+fn struct_field_names<T: DeserializeOwned>() -> Option<&'static [&'static str]> {
+    let mut fields = None;
+    let _ = T::deserialize(FieldNameCollector { fields: &mut fields });
+    fields
+}
+
+/// Whether a column of type `actual` can be deserialized into a field traced as `expected`
+#[cfg(feature = "dataframe")]
+fn dtypes_compatible(expected: &DataType, actual: &DataType) -> bool {
+    expected == actual
+        || matches!(actual, DataType::Null)
+        || compute::can_cast_types(actual, expected)
+        // `chrono::Duration` fields read `Duration` columns as their tuples
+        || (matches!(actual, DataType::Duration(_)) && durations::is_duration_tuple(expected))
+}
+
+/// Check whether a DataFrame can be deserialized into `T` without deserializing it.
+///
+/// Every non-optional field of `T` must be present as a column, and each column's
+/// dtype must be castable to the type traced for the matching field. If `T` cannot
+/// be traced from its type alone (for example because it contains chrono types),
+/// only column presence is checked and every field is treated as required. Column
+/// dtypes are also only checked for non-empty DataFrames.
+///
+/// Returns `MissingColumn` or `SchemaMismatch` describing the first incompatibility.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde::Deserialize;
+/// use serde_polars::can_deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Record {
+///     a: f64,
+///     b: i64,
+/// }
+///
+/// let df = df!("a" => [1.0, 2.0], "b" => [10i64, 20i64])?;
+/// can_deserialize::<Record>(&df)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn can_deserialize<T>(df: &DataFrame) -> Result<()>
+where
+    T: DeserializeOwned,
+{
+    let columns: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+
+    let traced_fields = match Vec::<FieldRef>::from_type::<T>(default_tracing_options()) {
+        Ok(fields) => fields,
+        Err(_) => {
+            for field in struct_field_names::<T>().unwrap_or_default() {
+                if !columns.iter().any(|column| column == field) {
+                    return Err(PolarsSerdeError::MissingColumn {
+                        column: field.to_string(),
+                    });
+                }
+            }
+            return Ok(());
+        }
+    };
+
+    for field in &traced_fields {
+        if !columns.iter().any(|column| column == field.name()) && !field.is_nullable() {
+            return Err(PolarsSerdeError::MissingColumn {
+                column: field.name().to_string(),
+            });
+        }
+    }
+
+    // Exporting an empty DataFrame through the interchange is not reliable, so
+    // dtypes are read from a single-row slice instead
+    if df.height() == 0 {
+        return Ok(());
+    }
+
+    let batches = dataframe_to_batches::<T>(df.head(Some(1)))?;
+    let Some(batch) = batches.first() else {
+        return Ok(());
+    };
+    let schema = batch.schema();
+
+    for field in &traced_fields {
+        if let Ok(actual) = schema.field_with_name(field.name()) {
+            if !dtypes_compatible(field.data_type(), actual.data_type()) {
+                return Err(PolarsSerdeError::SchemaMismatch {
+                    column: field.name().to_string(),
+                    expected: field.data_type().to_string(),
+                    actual: actual.data_type().to_string(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert a slice of records, such as a `&Vec<T>`, to a Polars DataFrame where T
+/// implements Serialize and Deserialize.
+///
+/// Records produced by an iterator can be converted without collecting them first
+/// with [`to_dataframe_iter`].
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde::{Serialize, Deserialize};
+/// use serde_polars::to_dataframe;
+///
+/// #[derive(Debug, Serialize, Deserialize)]
+/// struct Record {
+///     a: f64,
+///     b: i64,
+/// }
+///
+/// let records = vec![
+///     Record { a: 1.0, b: 10 },
+///     Record { a: 2.0, b: 20 },
+/// ];
+///
+/// let df = to_dataframe(&records)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe<T>(rows: &[T]) -> Result<DataFrame>
+where
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+{
+    to_dataframe_with(rows, &ConversionOptions::default())
+}
+
+/// Convert a slice of records to a Polars DataFrame using custom [`ConversionOptions`].
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::collections::HashMap;
+/// use serde_polars::{to_dataframe_with, ConversionOptions};
+///
+/// let options = ConversionOptions::new()
+///     .column_timezones(HashMap::from([("created_at".to_string(), "Asia/Tokyo".to_string())]));
+/// let df = to_dataframe_with(&records, &options)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_with<T>(rows: &[T], options: &ConversionOptions) -> Result<DataFrame>
+where
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+{
+    to_dataframe_with_stats(rows, options).map(|(df, _)| df)
+}
+
+/// Like [`to_dataframe_with`], also returning [`ConversionStats`] for the conversion.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{to_dataframe_with_stats, ConversionOptions};
+///
+/// let (df, stats) = to_dataframe_with_stats(&records, &ConversionOptions::new())?;
+/// println!("{} chrono columns took {:?}", stats.chrono_columns, stats.chrono_time);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_with_stats<T>(
+    rows: &[T],
+    options: &ConversionOptions,
+) -> Result<(DataFrame, ConversionStats)>
+where
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+{
+    if rows.is_empty() {
+        return Err(PolarsSerdeError::EmptyInput);
+    }
+
+    let mut stats = ConversionStats {
+        rows: rows.len(),
+        ..ConversionStats::default()
+    };
+
+    let schema = trace_record_schema(rows, &rows[0], options, &mut stats)?;
+    let df = traced_records_to_dataframe(rows, &schema, None, options, &mut stats)?;
+    Ok((df, stats))
+}
+
+/// Convert records into a DataFrame of a schema traced by [`trace_record_schema`].
+///
+/// `builder`, if given, must be built from the schema's fields; it is left empty
+/// after a successful conversion.
+#[cfg(feature = "dataframe")]
+pub(crate) fn traced_records_to_dataframe<T: Serialize>(
+    rows: &[T],
+    schema: &RecordSchema,
+    builder: Option<&mut serde_arrow::ArrayBuilder>,
+    options: &ConversionOptions,
+    stats: &mut ConversionStats,
+) -> Result<DataFrame> {
+    let final_rb = records_to_record_batch(rows, schema, builder, options, stats)?;
+    let final_rb = if options.constant_columns.is_empty() {
+        final_rb
+    } else {
+        append_constant_columns(final_rb, &options.constant_columns)?
+    };
+
+    let started = Instant::now();
+    let df: DataFrame = version_compat::arrow_to_dataframe(vec![final_rb.clone()])?;
+    stats.interchange_time = started.elapsed();
+    check_row_count(rows.len(), df.height())?;
+
+    if options.check_unsigned_range {
+        check_unsigned_range(&final_rb, &version_compat::column_dtypes(&df))?;
+    }
+
+    match &options.column_order {
+        Some(order) => reorder_columns(&df, order),
+        None => Ok(df),
+    }
+}
+
+/// Convert records to a DataFrame with a given schema, for frames that must share one.
+///
+/// Frames converted independently can differ in dtype with their contents, for
+/// example a column that is all null in one batch. Here every column is cast to
+/// the dtype `schema` gives it and put in the schema's order, so frames converted
+/// with the same schema can always be stacked. Empty input gives an empty frame
+/// with the schema.
+///
+/// A field without a column in `schema`, a schema column without a field, or values
+/// that do not cast losslessly (such as `300` into `Int8`) are errors.
+///
+/// # Examples
+///
+/// ```ignore
+/// use polars::prelude::*;
+///
+/// let schema = Schema::from_iter([
+///     Field::new("id".into(), DataType::Int64),
+///     Field::new("comment".into(), DataType::String),
+/// ]);
+/// let mut df = serde_polars::to_dataframe_as(&monday, &schema)?;
+/// df.vstack_mut(&serde_polars::to_dataframe_as(&tuesday, &schema)?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_as<T>(rows: &[T], schema: &Schema) -> Result<DataFrame>
+where
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+{
+    if rows.is_empty() {
+        return Ok(version_compat::empty_frame_with_schema(schema));
+    }
+    let df = to_dataframe_with(rows, &ConversionOptions::default())?;
+    version_compat::cast_to_schema(&df, schema)
+}
+
+/// Put the columns of a DataFrame in the given order, which must list every column
+#[cfg(feature = "dataframe")]
+fn reorder_columns(df: &DataFrame, order: &[String]) -> Result<DataFrame> {
+    let columns: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+
+    if let Some(missing) = order.iter().find(|name| !columns.contains(name)) {
+        return Err(PolarsSerdeError::MissingColumn {
+            column: missing.clone(),
+        });
+    }
+    if let Some(unlisted) = columns.iter().find(|name| !order.contains(name)) {
+        return Err(PolarsSerdeError::ConversionError {
+            message: format!("Column '{}' is not listed in the column order", unlisted),
+        });
+    }
+
+    version_compat::select_columns(df, order)
+}
+
+/// Append columns repeating a literal value in every row of the batch
+#[cfg(feature = "dataframe")]
+fn append_constant_columns(batch: RecordBatch, constants: &[(String, ScalarValue)]) -> Result<RecordBatch> {
+    use arrow::array::{ArrayRef, BooleanArray, Float64Array, Int64Array, StringArray, TimestampNanosecondArray};
+
+    let rows = batch.num_rows();
+    let mut fields: Vec<FieldRef> = batch.schema().fields().iter().cloned().collect();
+    let mut columns = batch.columns().to_vec();
+
+    for (name, value) in constants {
+        if fields.iter().any(|field| field.name() == name) {
+            return Err(PolarsSerdeError::ConversionError {
+                message: format!("Constant column '{}' clashes with a column of the records", name),
+            });
+        }
+        let column: ArrayRef = match value {
+            ScalarValue::String(value) => Arc::new(StringArray::from(vec![value.as_str(); rows])),
+            ScalarValue::Int64(value) => Arc::new(Int64Array::from(vec![*value; rows])),
+            ScalarValue::Float64(value) => Arc::new(Float64Array::from(vec![*value; rows])),
+            ScalarValue::Boolean(value) => Arc::new(BooleanArray::from(vec![*value; rows])),
+            ScalarValue::Datetime(value) => {
+                let nanos = value.timestamp_nanos_opt().ok_or_else(|| PolarsSerdeError::ConversionError {
+                    message: format!("Constant column '{}': {} is out of the nanosecond range", name, value),
+                })?;
+                Arc::new(TimestampNanosecondArray::from(vec![nanos; rows]).with_timezone("UTC"))
+            }
+        };
+        fields.push(Arc::new(Field::new(name, column.data_type().clone(), false)));
+        columns.push(column);
+    }
+
+    RecordBatch::try_new(Arc::new(arrow::datatypes::Schema::new(fields)), columns).map_err(|e| {
+        PolarsSerdeError::ConversionError {
+            message: format!("Failed to create converted record batch: {}", e),
+        }
+    })
+}
+
+/// Check that the `UInt64` columns of a batch fit the dtypes Polars gave them.
+///
+/// `dtypes` are the Polars columns the batch became. A column stored with a
+/// narrower or signed dtype must not hold values beyond that dtype's range.
+#[cfg(feature = "dataframe")]
+fn check_unsigned_range(batch: &RecordBatch, dtypes: &[(String, polars::prelude::DataType)]) -> Result<()> {
+    use polars::prelude::DataType as PolarsDataType;
+
+    for (field, column) in batch.schema().fields().iter().zip(batch.columns()) {
+        if field.data_type() != &DataType::UInt64 {
+            continue;
+        }
+        let Some((_, dtype)) = dtypes.iter().find(|(name, _)| name == field.name()) else {
+            continue;
+        };
+        let limit = match dtype {
+            PolarsDataType::UInt64 => continue,
+            PolarsDataType::Int64 => i64::MAX as u64,
+            PolarsDataType::UInt32 => u32::MAX as u64,
+            PolarsDataType::Int32 => i32::MAX as u64,
+            PolarsDataType::UInt16 => u16::MAX as u64,
+            PolarsDataType::Int16 => i16::MAX as u64,
+            PolarsDataType::UInt8 => u8::MAX as u64,
+            PolarsDataType::Int8 => i8::MAX as u64,
+            // Integers above 2^53 lose precision as doubles
+            PolarsDataType::Float64 => 1 << f64::MANTISSA_DIGITS,
+            PolarsDataType::Float32 => 1 << f32::MANTISSA_DIGITS,
+            _ => 0,
+        };
+        let max = compute::max(column.as_any().downcast_ref::<arrow::array::UInt64Array>().ok_or_else(|| {
+            PolarsSerdeError::ConversionError {
+                message: format!("Column '{}' is not a UInt64 array", field.name()),
+            }
+        })?);
+        if let Some(max) = max.filter(|max| *max > limit) {
+            return Err(PolarsSerdeError::ConversionError {
+                message: format!(
+                    "Column '{}' holds the u64 value {}, which Polars cannot represent as {:?}",
+                    field.name(),
+                    max,
+                    dtype
+                ),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Convert several slices of records into a single Polars DataFrame.
+///
+/// Every slice is serialized into its own Arrow record batch against one shared
+/// schema, and the batches are concatenated before the data is handed to Polars,
+/// which avoids building a DataFrame per slice and stacking them afterwards.
+/// Empty slices are skipped; `EmptyInput` is returned only if all of them are empty.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::merge_to_dataframe;
+///
+/// let df = merge_to_dataframe(&[&from_api, &from_file, &from_queue])?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn merge_to_dataframe<T>(batches: &[&[T]]) -> Result<DataFrame>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let slices: Vec<&[T]> = batches.iter().copied().filter(|rows| !rows.is_empty()).collect();
+    let first = match slices.first() {
+        Some(rows) => &rows[0],
+        None => return Err(PolarsSerdeError::EmptyInput),
+    };
+
+    let options = ConversionOptions::default();
+    let mut stats = ConversionStats::default();
+
+    // Trace over the records of every slice so the batches agree on one schema
+    let all_rows = ChainedRecords(&slices);
+    let schema = trace_record_schema(&all_rows, first, &options, &mut stats)?;
+
+    let record_batches = slices
+        .iter()
+        .map(|rows| records_to_record_batch(rows, &schema, None, &options, &mut stats))
+        .collect::<Result<Vec<RecordBatch>>>()?;
+
+    let merged = compute::concat_batches(&record_batches[0].schema(), &record_batches)
+        .map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to concatenate record batches: {}", e),
+        })?;
+
+    let df = version_compat::arrow_to_dataframe(vec![merged])?;
+    check_row_count(slices.iter().map(|rows| rows.len()).sum(), df.height())?;
+    Ok(df)
+}
+
+/// Convert a slice of borrowed records to a Polars DataFrame.
+///
+/// Behaves like [`to_dataframe`], but takes references such as those collected
+/// from a filtered iterator, so the records do not need to be cloned first.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::to_dataframe_refs;
+///
+/// let active: Vec<&User> = users.iter().filter(|user| user.active).collect();
+/// let df = to_dataframe_refs(&active)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_refs<T>(rows: &[&T]) -> Result<DataFrame>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let first = match rows.first() {
+        Some(first) => *first,
+        None => return Err(PolarsSerdeError::EmptyInput),
+    };
+
+    let options = ConversionOptions::default();
+    let mut stats = ConversionStats::default();
+    let schema = trace_record_schema(rows, first, &options, &mut stats)?;
+    let batch = records_to_record_batch(rows, &schema, None, &options, &mut stats)?;
+
+    let df = version_compat::arrow_to_dataframe(vec![batch])?;
+    check_row_count(rows.len(), df.height())?;
+    Ok(df)
+}
+
+/// Number of records [`to_dataframe_iter`] takes from its iterator at a time
+#[cfg(feature = "dataframe")]
+const ITER_CHUNK_ROWS: usize = 8_192;
+
+/// Convert the records of an iterator to a Polars DataFrame, as [`to_dataframe`] does.
+///
+/// Records are taken and serialized a chunk at a time, so they are never all in
+/// memory at once, as they would be when collected into a `Vec` first. The schema
+/// traced from the first chunk is kept for the rest; a chunk that does not fit it,
+/// such as one with values for a field that was `None` in every record before, is
+/// traced afresh, as is every chunk of records with a flattened map. An iterator without records gives `EmptyInput`.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::to_dataframe_iter;
+///
+/// let df = to_dataframe_iter(lines.iter().filter_map(|line| parse_event(line)))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_iter<T, I>(rows: I) -> Result<DataFrame>
+where
+    I: IntoIterator<Item = T>,
+    T: Serialize + DeserializeOwned,
+{
+    let mut rows = rows.into_iter();
+    let mut chunk: Vec<T> = rows.by_ref().take(ITER_CHUNK_ROWS).collect();
+    if chunk.is_empty() {
+        return Err(PolarsSerdeError::EmptyInput);
+    }
+
+    let options = ConversionOptions::default();
+    let mut stats = ConversionStats::default();
+    let mut schema = trace_record_schema(&chunk, &chunk[0], &options, &mut stats)?;
+    let mut builder = schema.array_builder()?;
+
+    let mut record_batches = Vec::new();
+    let mut row_count = 0;
+    while !chunk.is_empty() {
+        // Keys that records with a flattened map had not held before would be dropped
+        if schema.record_is_map && row_count > 0 {
+            schema = trace_record_schema(&chunk, &chunk[0], &options, &mut stats)?;
+            builder = schema.array_builder()?;
+        }
+        let batch = match records_to_record_batch(&chunk, &schema, Some(&mut builder), &options, &mut stats) {
+            Ok(batch) => batch,
+            Err(_) => {
+                // The failed chunk can leave records in the builder, so both are replaced
+                schema = trace_record_schema(&chunk, &chunk[0], &options, &mut stats)?;
+                builder = schema.array_builder()?;
+                records_to_record_batch(&chunk, &schema, Some(&mut builder), &options, &mut stats)?
+            }
+        };
+        row_count += chunk.len();
+        record_batches.push(batch);
+        chunk.clear();
+        chunk.extend(rows.by_ref().take(ITER_CHUNK_ROWS));
+    }
+
+    let record_batches = unify_chunk_schemas(record_batches)?;
+    let df = version_compat::arrow_to_dataframe(record_batches)?;
+    check_row_count(row_count, df.height())?;
+    Ok(df)
+}
+
+/// Give the record batches of chunks traced with different schemas one schema.
+///
+/// Columns are matched by name, as the columns of records with a flattened map
+/// depend on the keys each chunk holds. A column missing from a batch is filled
+/// with nulls there, and a column that is `Null` in some batches, as an `Option`
+/// field that was `None` throughout a chunk traces, takes the type the other
+/// batches gave it. Columns keep the order they first appear in, sorted by name
+/// when every batch has them sorted, as flattened map records do.
+#[cfg(feature = "dataframe")]
+fn unify_chunk_schemas(batches: Vec<RecordBatch>) -> Result<Vec<RecordBatch>> {
+    let mut fields: Vec<Field> = Vec::new();
+    for batch in &batches {
+        for field in batch.schema().fields() {
+            match fields.iter_mut().find(|seen| seen.name() == field.name()) {
+                Some(seen) => {
+                    if seen.data_type() == &DataType::Null {
+                        seen.set_data_type(field.data_type().clone());
+                    }
+                    seen.set_nullable(seen.is_nullable() || field.is_nullable());
+                }
+                None => fields.push(field.as_ref().clone()),
+            }
+        }
+    }
+    for field in fields.iter_mut() {
+        if batches.iter().any(|batch| batch.schema().field_with_name(field.name()).is_err()) {
+            field.set_nullable(true);
+        }
+    }
+    let sorted = batches.iter().all(|batch| batch.schema().fields().is_sorted_by_key(|field| field.name()));
+    if sorted {
+        fields.sort_by(|a, b| a.name().cmp(b.name()));
+    }
+    let schema = Arc::new(arrow::datatypes::Schema::new(fields));
+
+    batches
+        .into_iter()
+        .map(|batch| {
+            let columns = schema
+                .fields()
+                .iter()
+                .map(|field| match batch.column_by_name(field.name()) {
+                    None => Ok(arrow::array::new_null_array(field.data_type(), batch.num_rows())),
+                    Some(column) if column.data_type() == &DataType::Null && field.data_type() != &DataType::Null => {
+                        compute::cast(column, field.data_type())
+                    }
+                    Some(column) => Ok(column.clone()),
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok(RecordBatch::try_new(schema.clone(), columns)?)
+        })
+        .collect()
+}
+
+/// Convert a map of records into a Polars DataFrame, with the keys as a column.
+///
+/// Each entry becomes a row: the key in a first column named `key_col`, followed
+/// by the columns of the record, handled as in [`to_dataframe`]. Keys are traced
+/// from their values, so any serializable key type works. `key_col` must differ
+/// from the record's column names.
+///
+/// Rows follow the map's iteration order, which for a `HashMap` is unspecified
+/// and changes between runs; use a `BTreeMap` for rows sorted by key.
+///
+/// # Examples
+///
+/// ```ignore
+/// use std::collections::BTreeMap;
+/// use serde_polars::to_dataframe_keyed;
+///
+/// let users: BTreeMap<u64, User> = load_users();
+/// let df = to_dataframe_keyed(&users, "user_id")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_dataframe_keyed<'a, M, K, V>(map: M, key_col: &str) -> Result<DataFrame>
+where
+    M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Serialize + 'a,
+    V: Serialize + DeserializeOwned + 'a,
+{
+    /// Single-field row holding a key, so keys trace like any record field
+    #[derive(Serialize)]
+    struct KeyRow<'a, K> {
+        key: &'a K,
+    }
+
+    let (keys, values): (Vec<&K>, Vec<&V>) = map.into_iter().unzip();
+    let first = match values.first() {
+        Some(first) => *first,
+        None => return Err(PolarsSerdeError::EmptyInput),
+    };
+
+    let options = ConversionOptions::default();
+    let mut stats = ConversionStats::default();
+    let schema = trace_record_schema(&values, first, &options, &mut stats)?;
+    let values = records_to_record_batch(&values, &schema, None, &options, &mut stats)?;
+
+    if values.schema().field_with_name(key_col).is_ok() {
+        return Err(PolarsSerdeError::ConversionError {
+            message: format!("Key column '{}' clashes with a field of the records", key_col),
+        });
+    }
+
+    let keys: Vec<KeyRow<K>> = keys.into_iter().map(|key| KeyRow { key }).collect();
+    let key_fields = Vec::<FieldRef>::from_samples(&keys, default_tracing_options())?;
+    let keys = to_record_batch(&key_fields, &keys)?;
+    let keys = convert_dictionary_to_strings(keys)?;
+
+    let key_field = keys.schema().field(0).clone().with_name(key_col);
+    let mut fields: Vec<FieldRef> = vec![Arc::new(key_field)];
+    fields.extend(values.schema().fields().iter().cloned());
+    let mut columns = vec![keys.column(0).clone()];
+    columns.extend(values.columns().iter().cloned());
+
+    let batch = RecordBatch::try_new(Arc::new(arrow::datatypes::Schema::new(fields)), columns).map_err(|e| {
+        PolarsSerdeError::ConversionError {
+            message: format!("Failed to create keyed record batch: {}", e),
+        }
+    })?;
+    let df = version_compat::arrow_to_dataframe(vec![batch])?;
+    check_row_count(keys.num_rows(), df.height())?;
+    Ok(df)
+}
+
+/// Convert records of a single-field struct into a Polars Series named `name`.
+///
+/// The record type must serialize to exactly one column; chrono fields are
+/// handled as in [`to_dataframe`].
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::to_series;
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Price {
+///     amount: f64,
+/// }
+///
+/// let series = to_series("price", &[Price { amount: 9.5 }, Price { amount: 12.0 }])?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn to_series<T>(name: &str, rows: &[T]) -> Result<Series>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let df = to_dataframe_with(rows, &ConversionOptions::default())?;
+    if df.width() != 1 {
+        return Err(PolarsSerdeError::ConversionError {
+            message: format!(
+                "to_series requires a record type with a single field, but {} has {} columns",
+                std::any::type_name::<T>(),
+                df.width()
+            ),
+        });
+    }
+
+    let series = version_compat::first_series(&df).expect("DataFrame has one column");
+    Ok(series.with_name(name.into()))
+}
+
+/// Convert a Polars Series into records of a single-field struct.
+///
+/// The series name does not need to match: its values are read into the one
+/// field of `T`. This is the reverse of [`to_series`].
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::from_series;
+///
+/// let prices: Vec<Price> = from_series(series)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn from_series<T>(series: Series) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let field = match struct_field_names::<T>() {
+        Some([field]) => *field,
+        _ => {
+            return Err(PolarsSerdeError::ConversionError {
+                message: format!(
+                    "from_series requires a struct with a single field, which {} is not",
+                    std::any::type_name::<T>()
+                ),
+            })
+        }
+    };
+
+    from_dataframe(series.with_name(field.into()).into_frame())
+}
+
+/// Run `f` with Polars' global string cache enabled.
+///
+/// `Categorical` columns, as written with
+/// [`ConversionOptions::keep_string_dictionary`], each get their own categories
+/// unless they are built under a string cache. Columns from different DataFrames
+/// then cannot be compared or joined on. Build and combine such frames inside `f`.
+/// From Polars 0.50 on, categories are always global and `f` simply runs.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{to_dataframe_with, with_string_cache, ConversionOptions};
+///
+/// let options = ConversionOptions::new().keep_string_dictionary(true);
+/// let joined = with_string_cache(|| -> serde_polars::Result<DataFrame> {
+///     let orders = to_dataframe_with(&orders, &options)?;
+///     let customers = to_dataframe_with(&customers, &options)?;
+///     Ok(orders.left_join(&customers, ["country"], ["country"])?)
+/// })?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn with_string_cache<R>(f: impl FnOnce() -> R) -> R {
+    version_compat::with_string_cache(f)
+}
+
+/// Convert records to Arrow record batches, without going through Polars.
+///
+/// Columns are typed as in [`to_dataframe`]: the batches are the ones handed to
+/// Polars, before the interchange. This function is available without any Polars
+/// version feature, for crates that only need the Arrow side.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{from_record_batches, to_record_batches};
+///
+/// let batches = to_record_batches(&records)?;
+/// let back: Vec<Record> = from_record_batches(&batches)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn to_record_batches<T>(rows: &[T]) -> Result<Vec<RecordBatch>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    if rows.is_empty() {
+        return Err(PolarsSerdeError::EmptyInput);
+    }
+
+    let options = ConversionOptions::default();
+    let mut stats = ConversionStats::default();
+    let schema = trace_record_schema(rows, &rows[0], &options, &mut stats)?;
+    let batch = records_to_record_batch(rows, &schema, None, &options, &mut stats)?;
+    Ok(vec![batch])
+}
+
+/// Convert Arrow record batches sharing one schema into records, without going through Polars.
+///
+/// Columns are read as in [`from_dataframe`], including temporal columns into
+/// chrono fields. Like [`to_record_batches`], this function is available without
+/// any Polars version feature.
+pub fn from_record_batches<T>(batches: &[RecordBatch]) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let mut stats = ConversionStats::default();
+    records_from_batches(batches, &ConversionOptions::default(), &mut stats)
+}
+
+/// Write records to an Arrow IPC file (Feather v2), without going through Polars.
+///
+/// Columns are typed as in [`to_dataframe`], so a DataFrame read from the file
+/// (with Polars' `IpcReader`, say) matches the one `to_dataframe` builds.
+///
+/// Requires the `ipc` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{read_ipc, write_ipc};
+///
+/// let mut buffer = Vec::new();
+/// write_ipc(&records, &mut buffer)?;
+/// let back: Vec<Record> = read_ipc(buffer.as_slice())?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "ipc")]
+pub fn write_ipc<T>(rows: &[T], writer: impl std::io::Write) -> Result<()>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let batches = to_record_batches(rows)?;
+
+    let mut writer = arrow::ipc::writer::FileWriter::try_new(writer, &batches[0].schema())?;
+    for batch in &batches {
+        writer.write(batch)?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Read records from Arrow IPC data, without going through Polars.
+///
+/// Accepts both the IPC file format (Feather v2), as written by [`write_ipc`] or
+/// Polars' `IpcWriter`, and the IPC stream format. Columns are read as in
+/// [`from_dataframe`]. The file format needs random access, so its data is
+/// buffered in memory first.
+///
+/// Requires the `ipc` feature.
+#[cfg(feature = "ipc")]
+pub fn read_ipc<T>(mut reader: impl std::io::Read) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    const FILE_MAGIC: &[u8] = b"ARROW1";
+
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to read IPC data: {}", e),
+    })?;
+
+    let batches: Vec<RecordBatch> = if data.starts_with(FILE_MAGIC) {
+        arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(data), None)?
+            .collect::<std::result::Result<_, _>>()?
+    } else {
+        arrow::ipc::reader::StreamReader::try_new(data.as_slice(), None)?
+            .collect::<std::result::Result<_, _>>()?
+    };
+
+    from_record_batches(&batches)
+}
+
+/// Write records to a Parquet file, without going through Polars.
+///
+/// Columns are typed as in [`to_dataframe`], and the file is left uncompressed. Use
+/// [`write_parquet_with`] to choose the compression and per-column encodings.
+///
+/// Requires the `parquet` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{read_parquet, write_parquet};
+///
+/// write_parquet(&records, std::fs::File::create("events.parquet")?)?;
+/// let back: Vec<Record> = read_parquet(std::fs::File::open("events.parquet")?)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "parquet")]
+pub fn write_parquet<T>(rows: &[T], writer: impl std::io::Write + Send) -> Result<()>
+where
+    T: Serialize + DeserializeOwned,
+{
+    write_parquet_with(rows, writer, &ConversionOptions::default())
+}
+
+/// Write records to a Parquet file using custom [`ConversionOptions`].
+///
+/// [`ConversionOptions::parquet_compression`] picks the codec and
+/// [`ConversionOptions::parquet_column_encoding`] the encoding of single columns.
+/// The other options shape the columns as in [`to_dataframe_with`].
+///
+/// Requires the `parquet` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// use serde_polars::{write_parquet_with, ConversionOptions, ParquetCompression, ParquetEncoding};
+///
+/// let options = ConversionOptions::new()
+///     .parquet_compression(ParquetCompression::Zstd)
+///     .parquet_column_encoding("request_id".to_string(), ParquetEncoding::Plain);
+/// write_parquet_with(&records, std::fs::File::create("requests.parquet")?, &options)?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(feature = "parquet")]
+pub fn write_parquet_with<T>(rows: &[T], writer: impl std::io::Write + Send, options: &ConversionOptions) -> Result<()>
+where
+    T: Serialize + DeserializeOwned,
+{
+    use parquet::basic::{Compression, Encoding, ZstdLevel};
+    use parquet::file::properties::WriterProperties;
+    use parquet::schema::types::ColumnPath;
+
+    if rows.is_empty() {
+        return Err(PolarsSerdeError::EmptyInput);
+    }
+    let mut stats = ConversionStats::default();
+    let schema = trace_record_schema(rows, &rows[0], options, &mut stats)?;
+    let batch = records_to_record_batch(rows, &schema, None, options, &mut stats)?;
+
+    let mut properties = WriterProperties::builder().set_compression(match options.parquet_compression {
+        ParquetCompression::Uncompressed => Compression::UNCOMPRESSED,
+        ParquetCompression::Snappy => Compression::SNAPPY,
+        ParquetCompression::Zstd => Compression::ZSTD(ZstdLevel::default()),
+    });
+    for (column, encoding) in &options.parquet_column_encodings {
+        let schema = batch.schema();
+        let Ok(field) = schema.field_with_name(column) else {
+            return Err(PolarsSerdeError::MissingColumn { column: column.clone() });
+        };
+        // Nested columns are stored as leaf columns with paths of their own, such as
+        // `tags.list.element`, which a top-level path does not reach
+        if field.data_type().is_nested() {
+            return Err(PolarsSerdeError::ConversionError {
+                message: format!(
+                    "Parquet encoding hints apply to columns of single values, but '{}' is a {} column",
+                    column,
+                    field.data_type()
+                ),
+            });
+        }
+        let path = ColumnPath::from(column.as_str());
+        properties = match encoding {
+            ParquetEncoding::Dictionary => properties.set_column_dictionary_enabled(path, true),
+            ParquetEncoding::Plain => properties
+                .set_column_dictionary_enabled(path.clone(), false)
+                .set_column_encoding(path, Encoding::PLAIN),
+        };
+    }
+
+    let mut writer = parquet::arrow::ArrowWriter::try_new(writer, batch.schema(), Some(properties.build()))?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
+
+/// Read records from Parquet data, without going through Polars.
+///
+/// Reads files written by [`write_parquet`], with any compression. Columns are read
+/// as in [`from_dataframe`]. The data is buffered in memory first, as Parquet keeps
+/// its metadata at the end of the file.
+///
+/// Requires the `parquet` feature.
+#[cfg(feature = "parquet")]
+pub fn read_parquet<T>(reader: impl std::io::Read) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    read_parquet_with(reader, &ConversionOptions::default())
+}
+
+/// Read records from Parquet data using custom [`ConversionOptions`].
+///
+/// The options apply as in [`from_dataframe_with`], so with
+/// [`ConversionOptions::temporal_as_integers`] a `Date` column reads into an `i32`
+/// field of days since the epoch.
+///
+/// Requires the `parquet` feature.
+#[cfg(feature = "parquet")]
+pub fn read_parquet_with<T>(mut reader: impl std::io::Read, options: &ConversionOptions) -> Result<Vec<T>>
+where
+    T: DeserializeOwned,
+{
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to read Parquet data: {}", e),
+    })?;
+
+    let batches: Vec<RecordBatch> =
+        parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder::try_new(bytes::Bytes::from(data))?
+            .build()?
+            .collect::<std::result::Result<_, _>>()?;
+
+    let mut stats = ConversionStats::default();
+    records_from_batches(&batches, options, &mut stats)
+}
+
+/// Write records to an Arrow IPC file without blocking the async runtime.
+///
+/// Runs [`write_ipc`] on tokio's blocking thread pool, as serializing the records
+/// is CPU-bound and file writes block. The records are moved into the task.
+///
+/// Requires the `tokio` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// serde_polars::write_ipc_async(records, "events.arrow").await?;
+/// let events: Vec<Event> = serde_polars::read_ipc_async("events.arrow").await?;
+/// ```
+#[cfg(feature = "tokio")]
+pub async fn write_ipc_async<T>(rows: Vec<T>, path: impl AsRef<std::path::Path>) -> Result<()>
+where
+    T: Serialize + DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    run_blocking(move || {
+        let file = std::fs::File::create(&path).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to create IPC file '{}': {}", path.display(), e),
+        })?;
+        let mut writer = std::io::BufWriter::new(file);
+        write_ipc(&rows, &mut writer)?;
+        std::io::Write::flush(&mut writer).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to write IPC file '{}': {}", path.display(), e),
+        })
+    })
+    .await
+}
+
+/// Read records from an Arrow IPC file without blocking the async runtime.
+///
+/// Runs [`read_ipc`] on tokio's blocking thread pool, as file reads block and
+/// deserializing the records is CPU-bound.
+///
+/// Requires the `tokio` feature.
+#[cfg(feature = "tokio")]
+pub async fn read_ipc_async<T>(path: impl AsRef<std::path::Path>) -> Result<Vec<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    run_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to open IPC file '{}': {}", path.display(), e),
+        })?;
+        read_ipc(file)
+    })
+    .await
+}
+
+/// Write records to a Parquet file without blocking the async runtime.
+///
+/// Runs [`write_parquet`] on tokio's blocking thread pool, as [`write_ipc_async`]
+/// does for IPC files. The records are moved into the task.
+///
+/// Requires the `tokio` and `parquet` features.
+///
+/// # Examples
+///
+/// ```ignore
+/// serde_polars::write_parquet_async(records, "events.parquet").await?;
+/// let events: Vec<Event> = serde_polars::read_parquet_async("events.parquet").await?;
+/// ```
+#[cfg(all(feature = "tokio", feature = "parquet"))]
+pub async fn write_parquet_async<T>(rows: Vec<T>, path: impl AsRef<std::path::Path>) -> Result<()>
+where
+    T: Serialize + DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    run_blocking(move || {
+        let file = std::fs::File::create(&path).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to create Parquet file '{}': {}", path.display(), e),
+        })?;
+        write_parquet(&rows, std::io::BufWriter::new(file))
+    })
+    .await
+}
+
+/// Read records from a Parquet file without blocking the async runtime.
+///
+/// Runs [`read_parquet`] on tokio's blocking thread pool, as [`read_ipc_async`]
+/// does for IPC files.
+///
+/// Requires the `tokio` and `parquet` features.
+#[cfg(all(feature = "tokio", feature = "parquet"))]
+pub async fn read_parquet_async<T>(path: impl AsRef<std::path::Path>) -> Result<Vec<T>>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    run_blocking(move || {
+        let file = std::fs::File::open(&path).map_err(|e| PolarsSerdeError::ConversionError {
+            message: format!("Failed to open Parquet file '{}': {}", path.display(), e),
+        })?;
+        read_parquet(file)
+    })
+    .await
+}
+
+/// Run a conversion on tokio's blocking thread pool, resuming its panic if it panics
+#[cfg(feature = "tokio")]
+async fn run_blocking<R>(convert: impl FnOnce() -> Result<R> + Send + 'static) -> Result<R>
+where
+    R: Send + 'static,
+{
+    match tokio::task::spawn_blocking(convert).await {
+        Ok(result) => result,
+        Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+        Err(error) => Err(PolarsSerdeError::ConversionError {
+            message: format!("Conversion task did not complete: {}", error),
+        }),
+    }
+}
+
+/// The records of several slices, serialized as one sequence
+#[cfg(feature = "dataframe")]
+struct ChainedRecords<'a, T>(&'a [&'a [T]]);
+
+#[cfg(feature = "dataframe")]
+impl<T: Serialize> Serialize for ChainedRecords<'_, T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().flat_map(|rows| rows.iter()))
+    }
+}
+
+/// Schema of a record type, along with the chrono fields that need converting
+pub(crate) struct RecordSchema {
+    fields: Vec<FieldRef>,
+    chrono_types: HashMap<String, String>,
+    zero_sized_fields: Vec<String>,
+    /// Fields that cannot be `None`, whose columns are marked non-nullable
+    required_fields: Vec<String>,
+    /// Whether the records serialize as maps, so their columns depend on the keys they hold
+    record_is_map: bool,
+}
+
+impl RecordSchema {
+    /// An empty builder for records of this schema
+    #[cfg(feature = "dataframe")]
+    pub(crate) fn array_builder(&self) -> Result<serde_arrow::ArrayBuilder> {
+        Ok(serde_arrow::ArrayBuilder::from_arrow(&self.fields)?)
+    }
+}
+
+/// Trace the Arrow schema of `T`, using `rows` as samples when the type alone is not enough.
+///
+/// `first` is any one of the records, used to detect chrono and map fields.
+pub(crate) fn trace_record_schema<T, S>(
+    rows: &S,
+    first: &T,
+    options: &ConversionOptions,
+    stats: &mut ConversionStats,
+) -> Result<RecordSchema>
+where
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+    S: Serialize + ?Sized,
+{
+    // Zero-sized types (e.g. `struct Empty;`) carry no data to turn into columns,
+    // and serde_arrow rejects them during tracing with a confusing root-type error
+    if std::mem::size_of::<T>() == 0 {
+        return Err(no_serializable_fields::<T>());
+    }
+
+    let tracing_options = options.tracing_options();
+
+    // Detect chrono types, and records that serialize as maps, first
+    let detected = detect_field_types(first).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to detect chrono types: {}", e),
+    })?;
+
+    // Get basic schema generation
+    let started = Instant::now();
+    let basic_fields: Vec<FieldRef> = if detected.record_is_map {
+        trace_map_records::<T, S>(rows, &tracing_options)?
+    } else {
+        trace_struct_records::<T, S>(rows, options, &tracing_options)?
+    };
+
+    if basic_fields.is_empty() {
+        return Err(no_serializable_fields::<T>());
+    }
 
-fn convert_dictionary_to_strings(batch: RecordBatch) -> Result<RecordBatch> {
-    let mut new_columns = Vec::new();
-    let mut new_fields = Vec::new();
-    let schema = batch.schema();
+    let mut chrono_types = detected.field_types;
+    chrono_types.retain(|name, _| {
+        basic_fields.iter().any(|field| field.name() == name) && !options.never_convert_columns.contains(name)
+    });
 
-    for (i, column) in batch.columns().iter().enumerate() {
-        let field = schema.field(i);
+    let basic_fields = retype_null_string_fields(
+        basic_fields,
+        &detected.optional_string_fields,
+        &tracing_options,
+    );
 
-        match field.data_type() {
-            DataType::Dictionary(_, value_type)
-                if matches!(value_type.as_ref(), DataType::Utf8) =>
-            {
-                // Convert dictionary array to string array
-                let string_array = compute::cast(column, &DataType::Utf8).map_err(|e| {
-                    PolarsSerdeError::ConversionError {
-                        message: format!("Failed to convert dictionary to string: {}", e),
-                    }
-                })?;
-                new_columns.push(string_array);
-                new_fields.push(Arc::new(Field::new(
-                    field.name(),
-                    DataType::Utf8,
-                    field.is_nullable(),
-                )));
-            }
-            _ => {
-                new_columns.push(column.clone());
-                new_fields.push(Arc::new(field.clone()));
-            }
-        }
+    let basic_fields = if detected.btree_map_fields.is_empty() {
+        basic_fields
+    } else {
+        trace_btree_maps_as_structs(basic_fields, rows, &detected.btree_map_fields, tracing_options)?
+    };
+    let basic_fields = if detected.array_fields.is_empty() {
+        basic_fields
+    } else {
+        fixed_size_lists::struct_fields_to_lists(basic_fields, &detected.array_fields)
+    };
+    let mut nested_chrono_fields = detected.nested_chrono_fields;
+    if !detected.hidden_nested_fields.is_empty() {
+        nested_chrono_fields.extend(hidden_nested_chrono_fields(rows, detected.hidden_nested_fields));
     }
+    let nested_chrono_fields: Vec<_> = nested_chrono_fields
+        .into_iter()
+        .filter(|(path, _)| !options.never_convert_columns.iter().any(|name| name == path[0]))
+        .collect();
+    let basic_fields = if nested_chrono_fields.is_empty() {
+        basic_fields
+    } else {
+        retype_nested_chrono_fields(basic_fields, &nested_chrono_fields)
+    };
+    stats.tracing_time += started.elapsed();
 
-    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
-    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
-        message: format!("Failed to create converted record batch: {}", e),
+    Ok(RecordSchema {
+        fields: basic_fields,
+        chrono_types,
+        zero_sized_fields: detected.zero_sized_fields,
+        required_fields: required_field_names::<T>(),
+        record_is_map: detected.record_is_map,
     })
 }
 
-
-/// Helper function to deserialize with chrono type detection
-fn deserialize_with_chrono_detection<T>(batch: &RecordBatch) -> Result<Vec<T>>
+/// Trace the schema of records that serialize as structs, from `T` or from `rows` as
+/// [`ConversionOptions::schema_source`] says
+fn trace_struct_records<T, S>(
+    rows: &S,
+    options: &ConversionOptions,
+    tracing_options: &TracingOptions,
+) -> Result<Vec<FieldRef>>
 where
-    T: DeserializeOwned,
+    T: Serialize + for<'de> serde::Deserialize<'de>,
+    S: Serialize + ?Sized,
 {
-    // eprintln!("DEBUG: deserialize_with_chrono_detection - Input batch has {} rows, {} columns", 
-    //          batch.num_rows(), batch.num_columns());
-    
-    // Use standard serde_arrow deserialization
-    let result: Vec<T> = from_record_batch(batch).map_err(|e| PolarsSerdeError::ConversionError {
-        message: format!("Failed to deserialize batch: {}", e),
-    })?;
-    
-    // eprintln!("DEBUG: deserialize_with_chrono_detection - Deserialized {} records", result.len());
-    Ok(result)
+    let basic_fields: Vec<FieldRef> = match options.schema_source {
+        SchemaSource::Auto => match Vec::<FieldRef>::from_type::<T>(tracing_options.clone()) {
+            Ok(basic_fields) => basic_fields,
+            Err(_) => {
+                // Fallback to samples-based schema generation
+                trace_samples(rows, tracing_options)?
+            }
+        },
+        SchemaSource::Type => Vec::<FieldRef>::from_type::<T>(tracing_options.clone())?,
+        SchemaSource::Samples => {
+            check_record_shapes(rows)?;
+            trace_samples(rows, tracing_options)?
+        }
+    };
+
+    let basic_fields = if options.merge_sample_schema {
+        merge_sample_fields::<T, S>(basic_fields, rows, tracing_options)?
+    } else {
+        basic_fields
+    };
+
+    // Records whose fields are skipped with `skip_serializing_if` still share one set of columns
+    let skipped = skipped_record_fields(rows);
+    Ok(if skipped.is_empty() {
+        basic_fields
+    } else {
+        fill_skipped_fields::<T>(basic_fields, &skipped)
+    })
 }
 
-/// Convert a Polars DataFrame to Vec<T> where T implements Deserialize.
-///
-/// # Examples
-///
-/// ```ignore
-/// use polars::prelude::*;
-/// use serde::Deserialize;
-/// use serde_polars::from_dataframe;
-///
-/// #[derive(Debug, Deserialize)]
-/// struct Record {
-///     a: f64,
-///     b: i64,
-/// }
-///
-/// let df = df!(
-///     "a" => [1.0, 2.0, 3.0],
-///     "b" => [10i64, 20i64, 30i64]
-/// )?;
+/// Trace the schema of `rows` from their values, naming the first record that does
+/// not fit the others when that fails
+fn trace_samples<S: Serialize + ?Sized>(rows: &S, tracing_options: &TracingOptions) -> Result<Vec<FieldRef>> {
+    Vec::<FieldRef>::from_samples(rows, tracing_options.clone())
+        .map_err(|error| divergent_records_error(rows, tracing_options, error))
+}
+
+/// Trace the schema of records that serialize as maps, such as structs with a
+/// `#[serde(flatten)]` map field.
 ///
-/// let records: Vec<Record> = from_dataframe(df)?;
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-pub fn from_dataframe<T>(df: DataFrame) -> Result<Vec<T>>
+/// Their columns are not known from the type, so every key seen in `rows` becomes a
+/// column, in sorted order. Keys missing from some records give nullable columns.
+fn trace_map_records<T, S>(rows: &S, tracing_options: &TracingOptions) -> Result<Vec<FieldRef>>
 where
-    T: DeserializeOwned,
+    S: Serialize + ?Sized,
 {
-    // eprintln!("DEBUG: from_dataframe - Input DataFrame has {} rows, {} columns", 
-    //          df.height(), df.width());
-    
-    let batches: Vec<RecordBatch> = version_compat::dataframe_to_arrow(df)?;
-    let total_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
-    let mut out = Vec::with_capacity(total_rows);
-
-    // eprintln!("DEBUG: from_dataframe - Converted to {} RecordBatches with total {} rows", 
-    //          batches.len(), total_rows);
+    Vec::<FieldRef>::from_samples(rows, tracing_options.clone().map_as_struct(true)).map_err(|error| {
+        PolarsSerdeError::ConversionError {
+            message: format!(
+                "records of type `{}` serialize as maps (e.g. through a `#[serde(flatten)]` map field), \
+                 whose keys become columns; every value of a key must have one type: {}",
+                std::any::type_name::<T>(),
+                error
+            ),
+        }
+    })
+}
 
-    for (_batch_idx, batch) in batches.iter().enumerate() {
-        // eprintln!("DEBUG: from_dataframe - Processing batch {} with {} rows", 
-        //          batch_idx, batch.num_rows());
-        
-        // Apply reverse chrono conversion for DataFrame to struct conversion
-        let converted_batch = convert_from_chrono_columns(batch.clone())?;
-        let mut part: Vec<T> = deserialize_with_chrono_detection(&converted_batch)?;
-        
-        // eprintln!("DEBUG: from_dataframe - Deserialized {} records from batch {}", 
-        //          part.len(), batch_idx);
-        
-        out.append(&mut part);
+/// Serialize records into a record batch of the given fields.
+///
+/// `builder`, if given, is built from `fields` and reused instead of a fresh one.
+/// With `locate_failed_rows`, a failure is traced back to the first record that
+/// fails on its own, reported with its index.
+fn serialize_records<T: Serialize>(
+    fields: &[FieldRef],
+    rows: &[T],
+    builder: Option<&mut serde_arrow::ArrayBuilder>,
+    options: &ConversionOptions,
+) -> Result<RecordBatch> {
+    let serialized = match builder {
+        Some(builder) => builder.extend(rows).and_then(|()| builder.to_record_batch()),
+        None => to_record_batch(fields, &rows),
+    };
+    match serialized {
+        Ok(batch) => Ok(batch),
+        Err(error) if options.locate_failed_rows => {
+            let failed = rows.iter().enumerate().find_map(|(index, row)| {
+                to_record_batch(fields, &std::slice::from_ref(row)).err().map(|error| (index, error))
+            });
+            Err(match failed {
+                Some((index, error)) => PolarsSerdeError::RowError {
+                    index,
+                    source: Box::new(error.into()),
+                },
+                // Only the records together fail
+                None => error.into(),
+            })
+        }
+        Err(error) => Err(error.into()),
     }
-    
-    // eprintln!("DEBUG: from_dataframe - Final result has {} records", out.len());
-    Ok(out)
 }
 
-/// Convert Vec<T> to a Polars DataFrame where T implements Serialize and Deserialize.
-///
-/// # Examples
-///
-/// ```ignore
-/// use serde::{Serialize, Deserialize};
-/// use serde_polars::to_dataframe;
-///
-/// #[derive(Debug, Serialize, Deserialize)]
-/// struct Record {
-///     a: f64,
-///     b: i64,
-/// }
+/// Complete a traced schema so it covers every field of `T`.
 ///
-/// let records = vec![
-///     Record { a: 1.0, b: 10 },
-///     Record { a: 2.0, b: 20 },
-/// ];
-///
-/// let df = to_dataframe(&records)?;
-/// # Ok::<(), Box<dyn std::error::Error>>(())
-/// ```
-pub fn to_dataframe<T>(rows: &Vec<T>) -> Result<DataFrame>
+/// Types seen in any of the sample records are kept. `Null` fields take the type
+/// traced from `T` where the type can be traced, and fields that no record
+/// serialized at all (e.g. skipped with `skip_serializing_if`) are added as
+/// nullable columns, in the struct's field order.
+fn merge_sample_fields<T, S>(
+    fields: Vec<FieldRef>,
+    rows: &S,
+    tracing_options: &TracingOptions,
+) -> Result<Vec<FieldRef>>
 where
-    T: Serialize + for<'de> serde::Deserialize<'de>,
+    T: for<'de> serde::Deserialize<'de>,
+    S: Serialize + ?Sized,
 {
-    if rows.is_empty() {
-        return Err(PolarsSerdeError::EmptyInput);
-    }
+    let sampled = Vec::<FieldRef>::from_samples(rows, tracing_options.clone())?;
+    let typed = Vec::<FieldRef>::from_type::<T>(tracing_options.clone()).unwrap_or_default();
 
-    // Configure TracingOptions to support enums as strings and avoid dictionary arrays completely
-    let tracing_options = TracingOptions::default()
-        .enums_without_data_as_strings(true) // Convert simple enums to strings
-        .allow_null_fields(true) // Allow nullable fields for better compatibility
-        .map_as_struct(false) // Don't use struct for maps
-        .string_dictionary_encoding(false) // Avoid dictionary encoding which requires categorical
-        .coerce_numbers(false); // Be strict about types
+    let find = |fields: &[FieldRef], name: &str| fields.iter().find(|field| field.name() == name).cloned();
+    let known_type = |field: &FieldRef| field.data_type() != &DataType::Null;
 
-    // Get basic schema generation
-    let basic_fields: Vec<FieldRef> = match Vec::<FieldRef>::from_type::<T>(tracing_options.clone()) {
-        Ok(basic_fields) => basic_fields,
-        Err(_) => {
-            // Fallback to samples-based schema generation
-            Vec::<FieldRef>::from_samples(rows, tracing_options)?
-        }
+    let names: Vec<String> = match struct_field_names::<T>() {
+        Some(names) => names.iter().map(|name| name.to_string()).collect(),
+        None => fields.iter().map(|field| field.name().clone()).collect(),
     };
 
-    // Detect chrono types first
-    let chrono_types = detect_chrono_types(&rows[0]).map_err(|e| PolarsSerdeError::ConversionError {
-        message: format!("Failed to detect chrono types: {}", e),
-    })?;
-    
+    Ok(names
+        .iter()
+        .map(|name| {
+            find(&fields, name)
+                .filter(known_type)
+                .or_else(|| find(&sampled, name).filter(known_type))
+                .or_else(|| find(&typed, name).filter(known_type))
+                .unwrap_or_else(|| Arc::new(Field::new(name.as_str(), DataType::Null, true)))
+        })
+        .collect())
+}
+
+/// Serialize records into a record batch ready to be handed to Polars
+fn records_to_record_batch<T: Serialize>(
+    rows: &[T],
+    schema: &RecordSchema,
+    builder: Option<&mut serde_arrow::ArrayBuilder>,
+    options: &ConversionOptions,
+    stats: &mut ConversionStats,
+) -> Result<RecordBatch> {
+    let basic_fields = &schema.fields;
+    let chrono_types = &schema.chrono_types;
+
     // Create the record batch with chrono conversion
+    let started = Instant::now();
     let rb: RecordBatch = if chrono_types.is_empty() {
         // No chrono types, use normal serialization
-        to_record_batch(&basic_fields, rows)?
+        serialize_records(basic_fields, rows, builder, options)?
     } else {
         // We have chrono types, serialize with numeric conversion
         // Note: This is a workaround - we serialize normally then convert the columns
         // The proper solution would be to use a custom serializer for each row,
         // but that's more complex and this works for our use case
-        to_record_batch(&basic_fields, rows)?
+        serialize_records(basic_fields, rows, builder, options)?
+    };
+    stats.serde_time += started.elapsed();
+
+    // Enums with data arrive as unions, which Polars cannot hold
+    let rb = if enum_columns::has_union_column(&rb) {
+        enum_columns::union_columns_to_structs(rb)?
+    } else {
+        rb
+    };
+
+    // Zero-sized fields such as `PhantomData` get no column
+    let rb = if schema.zero_sized_fields.is_empty() {
+        rb
+    } else {
+        let kept: Vec<usize> = (0..rb.num_columns())
+            .filter(|&i| !schema.zero_sized_fields.contains(rb.schema().field(i).name()))
+            .collect();
+        rb.project(&kept)?
     };
     
     // Apply chrono column conversion for detected chrono fields
-    let converted_rb = convert_chrono_columns(rb, &chrono_types)?;
+    stats.chrono_columns = rb
+        .schema()
+        .fields()
+        .iter()
+        .filter(|field| chrono_types.contains_key(field.name()))
+        .count();
+    let started = Instant::now();
+    let converted_rb = convert_chrono_columns(rb, chrono_types, options)?;
+    stats.chrono_time += started.elapsed();
 
-    // Convert any dictionary arrays to string arrays to avoid categorical requirements
-    let final_rb = convert_dictionary_to_strings(converted_rb)?;
+    // Enums registered in the options are stored as their discriminants
+    let converted_rb = if options.enum_discriminants.is_empty() {
+        converted_rb
+    } else {
+        enum_columns::variants_to_discriminants(converted_rb, &options.enum_discriminants)?
+    };
 
-    let df: DataFrame = version_compat::arrow_to_dataframe(vec![final_rb])?;
-    Ok(df)
+    // Convert any dictionary arrays to string arrays to avoid categorical requirements,
+    // unless the options ask for categorical columns
+    let final_rb = if options.keep_string_dictionary {
+        converted_rb
+    } else {
+        stats.dictionary_columns = converted_rb
+            .schema()
+            .fields()
+            .iter()
+            .filter(|field| matches!(field.data_type(), DataType::Dictionary(_, value_type) if is_string_type(value_type)))
+            .count();
+        let started = Instant::now();
+        let final_rb = convert_dictionary_to_strings(converted_rb)?;
+        stats.dictionary_time += started.elapsed();
+        final_rb
+    };
+
+    let final_rb = if options.infinity_as_null {
+        infinities_to_nulls(final_rb)?
+    } else {
+        final_rb
+    };
+    check_row_count(rows.len(), final_rb.num_rows())?;
+    mark_required_columns(final_rb, &schema.required_fields)
+}
+
+/// The fields of `T` that do not accept `None`, unlike `Option` fields
+fn required_field_names<T: DeserializeOwned>() -> Vec<String> {
+    struct_field_names::<T>()
+        .unwrap_or_default()
+        .iter()
+        .filter(|name| !field_accepts_unit::<T>(name))
+        .map(|name| name.to_string())
+        .collect()
+}
+
+/// Mark the columns of required fields non-nullable.
+///
+/// Tracing from samples or merging schemas can leave such columns nullable.
+/// Columns that do hold nulls, from skipped fields or `infinity_as_null`, stay nullable.
+fn mark_required_columns(batch: RecordBatch, required: &[String]) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let relaxed = |(field, column): (&FieldRef, &arrow::array::ArrayRef)| {
+        field.is_nullable() && column.null_count() == 0 && required.contains(field.name())
+    };
+    if !schema.fields().iter().zip(batch.columns()).any(relaxed) {
+        return Ok(batch);
+    }
+
+    let fields: Vec<FieldRef> = schema
+        .fields()
+        .iter()
+        .zip(batch.columns())
+        .map(|(field, column)| {
+            if relaxed((field, column)) {
+                Arc::new(field.as_ref().clone().with_nullable(false))
+            } else {
+                field.clone()
+            }
+        })
+        .collect();
+    RecordBatch::try_new(Arc::new(arrow::datatypes::Schema::new(fields)), batch.columns().to_vec()).map_err(|e| {
+        PolarsSerdeError::ConversionError {
+            message: format!("Failed to create converted record batch: {}", e),
+        }
+    })
+}
+
+/// Replace `±inf` in the float columns of a batch with nulls
+fn infinities_to_nulls(batch: RecordBatch) -> Result<RecordBatch> {
+    use arrow::array::{Array, BooleanArray, Float32Array, Float64Array};
+
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        let infinite = match field.data_type() {
+            DataType::Float32 => column
+                .as_any()
+                .downcast_ref::<Float32Array>()
+                .map(|values| BooleanArray::from_unary(values, f32::is_infinite)),
+            DataType::Float64 => column
+                .as_any()
+                .downcast_ref::<Float64Array>()
+                .map(|values| BooleanArray::from_unary(values, f64::is_infinite)),
+            _ => None,
+        };
+
+        match infinite {
+            Some(infinite) if infinite.true_count() > 0 => {
+                new_columns.push(compute::nullif(column, &infinite)?);
+                new_fields.push(Arc::new(field.as_ref().clone().with_nullable(true)));
+            }
+            _ => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+/// Replace the nulls of the named float columns with NaN
+fn nulls_to_nans(batch: RecordBatch, columns: &[String]) -> Result<RecordBatch> {
+    use arrow::array::{Array, ArrayRef, AsArray, Float32Array, Float64Array};
+    use arrow::datatypes::{Float32Type, Float64Type};
+
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        if column.null_count() == 0 || !columns.contains(field.name()) {
+            new_columns.push(column.clone());
+            new_fields.push(field.clone());
+            continue;
+        }
+        let filled: ArrayRef = match field.data_type() {
+            DataType::Float32 => Arc::new(
+                column.as_primitive::<Float32Type>().iter().map(|value| Some(value.unwrap_or(f32::NAN))).collect::<Float32Array>(),
+            ),
+            DataType::Float64 => Arc::new(
+                column.as_primitive::<Float64Type>().iter().map(|value| Some(value.unwrap_or(f64::NAN))).collect::<Float64Array>(),
+            ),
+            _ => column.clone(),
+        };
+        new_columns.push(filled);
+        new_fields.push(Arc::new(field.as_ref().clone().with_nullable(false)));
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
 }
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "dataframe"))]
 mod tests {
     use super::*;
     use serde::{Deserialize, Serialize};
@@ -850,6 +4773,19 @@ mod tests {
         assert_eq!(records, converted_back);
     }
 
+    #[test]
+    fn test_row_count_check_reports_dropped_rows() {
+        check_row_count(3, 3).unwrap();
+
+        match check_row_count(200_000, 0) {
+            Err(PolarsSerdeError::InvalidRowCount { expected, actual }) => {
+                assert_eq!(expected, 200_000);
+                assert_eq!(actual, 0);
+            }
+            other => panic!("expected InvalidRowCount, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_empty_vec_error() {
         let records: Vec<TestRecord> = vec![];
@@ -858,6 +4794,33 @@ mod tests {
         assert!(matches!(result.unwrap_err(), PolarsSerdeError::EmptyInput));
     }
 
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Empty;
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct EmptyBraces {}
+
+    #[test]
+    fn test_zero_field_struct_error() {
+        let result = to_dataframe(&[Empty, Empty]);
+        match result {
+            Err(PolarsSerdeError::ConversionError { message }) => {
+                assert!(message.contains("Empty"));
+                assert!(message.contains("has no serializable fields"));
+            }
+            other => panic!("expected ConversionError, got {:?}", other),
+        }
+
+        let result = to_dataframe(&[EmptyBraces {}]);
+        match result {
+            Err(PolarsSerdeError::ConversionError { message }) => {
+                assert!(message.contains("EmptyBraces"));
+                assert!(message.contains("has no serializable fields"));
+            }
+            other => panic!("expected ConversionError, got {:?}", other),
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     struct DateRecord {
         name: String,
@@ -915,6 +4878,79 @@ mod tests {
         assert_eq!(converted[1].birth_date, dates[1]);
     }
 
+    #[test]
+    fn test_pre_epoch_date32_string_conversion() {
+        use arrow::array::{Array, ArrayRef, Date32Array, StringArray};
+
+        let column: ArrayRef = Arc::new(StringArray::from(vec!["1900-01-01", "1969-12-31", "1970-01-01"]));
+        let dates = convert_string_dates_to_date32(&column, OnParseError::Strict).unwrap();
+        let date_array = dates.as_any().downcast_ref::<Date32Array>().unwrap();
+        assert_eq!(date_array.values().to_vec(), vec![-25567, -1, 0]);
+
+        // Negative day offsets must reconstruct the same pre-epoch dates
+        let strings = convert_date32_to_string(&dates).unwrap();
+        let string_array = strings.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(string_array.value(0), "1900-01-01");
+        assert_eq!(string_array.value(1), "1969-12-31");
+        assert_eq!(string_array.value(2), "1970-01-01");
+        assert_eq!(string_array.len(), 3);
+    }
+
+    #[test]
+    fn test_iso_week_and_ordinal_date_parsing() {
+        use arrow::array::{Array, ArrayRef, Date32Array, StringArray};
+
+        // 2023-06-15 is a Thursday in ISO week 24 and the 166th day of the year
+        let expected = NaiveDate::from_ymd_opt(2023, 6, 15).unwrap();
+        let expected_days = (expected - NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()).num_days() as i32;
+
+        let column: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("2023-06-15"),
+            Some("2023-W24-4"),
+            Some("2023-166"),
+            None,
+        ]));
+        let dates = convert_string_dates_to_date32(&column, OnParseError::Strict).unwrap();
+        let date_array = dates.as_any().downcast_ref::<Date32Array>().unwrap();
+
+        assert_eq!(date_array.value(0), expected_days);
+        assert_eq!(date_array.value(1), expected_days);
+        assert_eq!(date_array.value(2), expected_days);
+        assert!(date_array.is_null(3));
+
+        let bad: ArrayRef = Arc::new(StringArray::from(vec!["2023-W99-9"]));
+        assert!(convert_string_dates_to_date32(&bad, OnParseError::Strict).is_err());
+        let nulled = convert_string_dates_to_date32(&bad, OnParseError::Null).unwrap();
+        assert!(nulled.is_null(0));
+
+        // Truncated dates are not read as ordinal days
+        let truncated: ArrayRef = Arc::new(StringArray::from(vec!["2023-06", "2023-1"]));
+        assert!(convert_string_dates_to_date32(&truncated, OnParseError::Strict).is_err());
+        let nulled = convert_string_dates_to_date32(&truncated, OnParseError::Null).unwrap();
+        assert_eq!(nulled.null_count(), 2);
+    }
+
+    #[test]
+    fn test_fast_date_parser_matches_chrono() {
+        let inputs = [
+            "2023-06-15", "1900-01-01", "0001-01-01", "9999-12-31", "2024-02-29",
+            "2023-02-29", "2023-13-01", "2023-00-10", "2023-06-00", "2023-6-15",
+            "2023/06/15", "20230615", "2023-06-1a", " 2023-06-1", "+2023-06-15",
+            "-0001-06-15", "", "2023-06-15T00:00:00",
+        ];
+
+        for input in inputs {
+            let chrono = NaiveDate::parse_from_str(input, "%Y-%m-%d").ok();
+            let fast = parse_iso_date_fast(input);
+            if fast.is_some() {
+                assert_eq!(fast, chrono, "fast path disagrees with chrono for {:?}", input);
+            }
+            if chrono.is_some() {
+                assert_eq!(parse_date_string(input), chrono, "input {:?}", input);
+            }
+        }
+    }
+
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     struct UtcRecord {
         name: String,
@@ -956,6 +4992,79 @@ mod tests {
         let result: Vec<TestRecord> = from_dataframe(empty_df).unwrap();
         assert_eq!(result.len(), 0);
     }
+
+    #[test]
+    fn test_large_string_columns_into_enum() {
+        use arrow::array::{Array, ArrayRef, DictionaryArray, LargeStringArray};
+        use arrow::datatypes::{Int32Type, Schema};
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        enum Level {
+            Low,
+            High,
+        }
+
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Alert {
+            level: Level,
+            previous: Level,
+        }
+
+        let previous: DictionaryArray<Int32Type> = vec!["High", "Low", "High"].into_iter().collect();
+        let previous = previous.with_values(Arc::new(LargeStringArray::from(vec!["High", "Low"])));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("level", DataType::LargeUtf8, false),
+            Field::new("previous", previous.data_type().clone(), false),
+        ]));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(LargeStringArray::from(vec!["Low", "High", "Low"])),
+            Arc::new(previous),
+        ];
+        let batch = RecordBatch::try_new(schema, columns).unwrap();
+
+        let normalized = convert_dictionary_to_strings(batch).unwrap();
+        assert_eq!(normalized.schema().field(1).data_type(), &DataType::LargeUtf8);
+
+        let alerts: Vec<Alert> = deserialize_with_chrono_detection(&normalized, false).unwrap();
+        assert_eq!(alerts, vec![
+            Alert { level: Level::Low, previous: Level::High },
+            Alert { level: Level::High, previous: Level::Low },
+            Alert { level: Level::Low, previous: Level::High },
+        ]);
+    }
+
+    #[test]
+    fn test_check_unsigned_range() {
+        use arrow::array::UInt64Array;
+        use arrow::datatypes::Schema;
+
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::UInt64, false)]));
+        let ids = Arc::new(UInt64Array::from(vec![1, u64::MAX]));
+        let batch = RecordBatch::try_new(schema, vec![ids]).unwrap();
+
+        let kept = vec![("id".to_string(), polars::prelude::DataType::UInt64)];
+        assert!(check_unsigned_range(&batch, &kept).is_ok());
+
+        // A Polars version storing the column as Int64 would wrap u64::MAX to -1
+        let signed = vec![("id".to_string(), polars::prelude::DataType::Int64)];
+        match check_unsigned_range(&batch, &signed) {
+            Err(PolarsSerdeError::ConversionError { message }) => {
+                assert!(message.contains("'id'"));
+                assert!(message.contains(&u64::MAX.to_string()));
+            }
+            other => panic!("expected ConversionError, got {:?}", other),
+        }
+
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Account {
+            id: u64,
+        }
+        let accounts = vec![Account { id: 0 }, Account { id: u64::MAX }];
+        let options = ConversionOptions::new().check_unsigned_range(true);
+        let df = to_dataframe_with(&accounts, &options).unwrap();
+        let back: Vec<Account> = from_dataframe(df).unwrap();
+        assert_eq!(back, accounts);
+    }
     
    
 }
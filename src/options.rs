@@ -0,0 +1,450 @@
+//! Options for customizing DataFrame conversions
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde_arrow::schema::TracingOptions;
+
+use crate::{ChronoKind, EnumDiscriminant};
+
+/// Options controlling how records are converted to and from DataFrames.
+///
+/// The default options reproduce the behavior of [`to_dataframe`](crate::to_dataframe).
+/// Options are set with chained builder methods:
+///
+/// ```ignore
+/// use std::collections::HashMap;
+/// use serde_polars::{to_dataframe_with, ConversionOptions};
+///
+/// let options = ConversionOptions::new()
+///     .column_timezones(HashMap::from([("created_at".to_string(), "Europe/Berlin".to_string())]));
+/// let df = to_dataframe_with(&records, &options)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ConversionOptions {
+    pub(crate) column_timezones: HashMap<String, String>,
+    pub(crate) downcast_integers: bool,
+    pub(crate) schema_source: SchemaSource,
+    pub(crate) merge_sample_schema: bool,
+    pub(crate) parse_columns_as: HashMap<String, ChronoKind>,
+    pub(crate) max_rows: Option<usize>,
+    pub(crate) infinity_as_null: bool,
+    pub(crate) null_float_as_nan: bool,
+    pub(crate) temporal_as_integers: bool,
+    pub(crate) keep_string_dictionary: bool,
+    pub(crate) column_order: Option<Vec<String>>,
+    pub(crate) timestamp_output_format: Option<String>,
+    pub(crate) check_unsigned_range: bool,
+    pub(crate) locate_failed_rows: bool,
+    pub(crate) read_fields_by_position: bool,
+    pub(crate) constant_columns: Vec<(String, ScalarValue)>,
+    pub(crate) never_convert_columns: Vec<String>,
+    pub(crate) enum_discriminants: HashMap<String, Vec<(&'static str, i32)>>,
+    pub(crate) bool_as_enum: HashMap<String, (String, String)>,
+    pub(crate) on_temporal_parse_error: OnParseError,
+    pub(crate) parquet_compression: ParquetCompression,
+    pub(crate) parquet_column_encodings: HashMap<String, ParquetEncoding>,
+    tracing: Option<TracingCustomizer>,
+}
+
+/// Where the Arrow schema of the records comes from when writing a DataFrame
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SchemaSource {
+    /// Trace the record type, falling back to the records when the type alone
+    /// cannot be traced (as for chrono fields)
+    #[default]
+    Auto,
+    /// Trace the record type only; types that cannot be traced are an error
+    Type,
+    /// Trace the records themselves, so column types follow the actual data.
+    /// Columns without any non-null value become `Null` columns, except for
+    /// `Option<String>` fields, which stay strings. Every record must have the
+    /// fields of the first one, apart from those skipped by `skip_serializing_if`
+    Samples,
+}
+
+/// What happens to a date or datetime string that cannot be parsed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OnParseError {
+    /// Fail the conversion with a `ConversionError`
+    #[default]
+    Strict,
+    /// Store a null in place of the value
+    Null,
+}
+
+/// Compression codec of the Parquet files written by `write_parquet_with`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ParquetCompression {
+    /// No compression
+    #[default]
+    Uncompressed,
+    /// Snappy, which is fast to read and write
+    Snappy,
+    /// Zstandard at its default level, which gives smaller files than Snappy
+    Zstd,
+}
+
+/// How `write_parquet_with` encodes the values of a Parquet column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetEncoding {
+    /// A dictionary of the distinct values, suited to columns that repeat a few values
+    Dictionary,
+    /// Plain values without a dictionary, suited to columns of mostly unique values
+    Plain,
+}
+
+/// A literal value for every row of a constant column
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScalarValue {
+    /// A `String` column
+    String(String),
+    /// An `Int64` column
+    Int64(i64),
+    /// A `Float64` column
+    Float64(f64),
+    /// A `Boolean` column
+    Boolean(bool),
+    /// A `Datetime` column in nanoseconds, tagged `"UTC"` like `DateTime<Utc>` fields
+    Datetime(DateTime<Utc>),
+}
+
+impl From<&str> for ScalarValue {
+    fn from(value: &str) -> Self {
+        ScalarValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ScalarValue {
+    fn from(value: String) -> Self {
+        ScalarValue::String(value)
+    }
+}
+
+impl From<i64> for ScalarValue {
+    fn from(value: i64) -> Self {
+        ScalarValue::Int64(value)
+    }
+}
+
+impl From<f64> for ScalarValue {
+    fn from(value: f64) -> Self {
+        ScalarValue::Float64(value)
+    }
+}
+
+impl From<bool> for ScalarValue {
+    fn from(value: bool) -> Self {
+        ScalarValue::Boolean(value)
+    }
+}
+
+impl From<DateTime<Utc>> for ScalarValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        ScalarValue::Datetime(value)
+    }
+}
+
+/// User-supplied adjustment of the schema tracing options
+#[derive(Clone)]
+struct TracingCustomizer(Arc<dyn Fn(TracingOptions) -> TracingOptions + Send + Sync>);
+
+impl fmt::Debug for TracingCustomizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TracingCustomizer(..)")
+    }
+}
+
+impl ConversionOptions {
+    /// Create options with the default conversion behavior
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tag `DateTime<Utc>` columns with a timezone other than `"UTC"`.
+    ///
+    /// Keys are column names and values are IANA timezone names such as
+    /// `"America/New_York"`. The stored instants are unchanged (always UTC
+    /// internally); only the column's timezone metadata carries the chosen zone.
+    pub fn column_timezones(mut self, timezones: HashMap<String, String>) -> Self {
+        self.column_timezones = timezones;
+        self
+    }
+
+    /// Narrow wider integer columns to the struct field's integer type when reading.
+    ///
+    /// Each column is range checked as a whole before deserialization, so a value
+    /// that does not fit (say `3_000_000_000` read into an `i32` field) fails with a
+    /// `ConversionError` naming the column and the value. Columns narrower than their
+    /// field are left as they are, and only top-level fields are narrowed.
+    pub fn downcast_integers(mut self, enabled: bool) -> Self {
+        self.downcast_integers = enabled;
+        self
+    }
+
+    /// Write `±inf` float values as nulls.
+    ///
+    /// For DataFrames headed to systems that cannot represent infinities, such as
+    /// JSON. Infinite values of top-level `f32` and `f64` fields become null when
+    /// writing, and read back as `None` into `Option` fields. NaN is unaffected.
+    pub fn infinity_as_null(mut self, enabled: bool) -> Self {
+        self.infinity_as_null = enabled;
+        self
+    }
+
+    /// Read nulls in float columns as NaN into `f32` and `f64` fields.
+    ///
+    /// By default, a null read into a float field that is not an `Option` is an
+    /// error. With this option, such nulls become `f32::NAN` or `f64::NAN`, as
+    /// pandas-style data expects. `Option` fields still read nulls as `None`.
+    pub fn null_float_as_nan(mut self, enabled: bool) -> Self {
+        self.null_float_as_nan = enabled;
+        self
+    }
+
+    /// Write string fields as dictionary-encoded `Categorical` columns.
+    ///
+    /// Each distinct string is stored once, which saves memory for low-cardinality
+    /// fields such as country codes. Applies to every string field of the record
+    /// type; categorical columns read back into `String` fields as usual.
+    pub fn keep_string_dictionary(mut self, enabled: bool) -> Self {
+        self.keep_string_dictionary = enabled;
+        self
+    }
+
+    /// Arrange the columns of written DataFrames in the given order.
+    ///
+    /// By default columns follow the order of the struct fields. The order must
+    /// list every column exactly once: a listed name without a column fails with
+    /// `MissingColumn`, and a column left out of the list with `ConversionError`.
+    pub fn column_order(mut self, order: Vec<String>) -> Self {
+        self.column_order = Some(order);
+        self
+    }
+
+    /// Check that `u64` columns survive the conversion to Polars unchanged.
+    ///
+    /// Some Polars versions store unsigned 64-bit columns with another dtype, such
+    /// as `Int64`, which silently wraps values above `i64::MAX`. With this check,
+    /// writing fails with a `ConversionError` naming the column instead. Useful for
+    /// ID columns that use the full `u64` range. (`u128` fields are not supported
+    /// by the Arrow conversion at all and always fail.)
+    pub fn check_unsigned_range(mut self, enabled: bool) -> Self {
+        self.check_unsigned_range = enabled;
+        self
+    }
+
+    /// Report which record failed when writing a DataFrame fails.
+    ///
+    /// serde_arrow's error names the failing field but not the record. With this
+    /// option, a failed conversion is retried record by record to find the first
+    /// one that fails, returned as [`PolarsSerdeError::RowError`](crate::PolarsSerdeError::RowError)
+    /// with its index. The retry only runs after a failure, but then costs a pass
+    /// over the records up to the failing one.
+    pub fn locate_failed_rows(mut self, enabled: bool) -> Self {
+        self.locate_failed_rows = enabled;
+        self
+    }
+
+    /// Read record fields by position instead of by name.
+    ///
+    /// When a frame's columns are exactly the fields of the record struct, each row
+    /// is handed to its `Deserialize` impl as a sequence in field order, which skips
+    /// the per-row name lookups and makes reading wide frames about 3x faster. A
+    /// batch that fails to read this way is read again by name.
+    ///
+    /// Only enable it for record types whose `Deserialize` impl reads a sequence in
+    /// the order of its fields, as serde's derive does. A hand-written impl reading
+    /// them in another order would silently get values of matching types in the
+    /// wrong fields.
+    pub fn read_fields_by_position(mut self, enabled: bool) -> Self {
+        self.read_fields_by_position = enabled;
+        self
+    }
+
+    /// Append columns holding the same value in every row to written DataFrames.
+    ///
+    /// For stamping records with values they do not carry themselves, such as a
+    /// batch id or an ingestion time. The columns follow the record's columns in
+    /// the given order; a name that clashes with a record column fails with a
+    /// `ConversionError`.
+    ///
+    /// ```ignore
+    /// let options = ConversionOptions::new().add_constant_columns(vec![
+    ///     ("batch_id".to_string(), ScalarValue::from("2024-06-01-a")),
+    ///     ("ingested_at".to_string(), ScalarValue::from(Utc::now())),
+    /// ]);
+    /// ```
+    pub fn add_constant_columns(mut self, columns: Vec<(String, ScalarValue)>) -> Self {
+        self.constant_columns.extend(columns);
+        self
+    }
+
+    /// Keep the named columns out of every temporal conversion.
+    ///
+    /// Columns are normally converted from what their struct field or dtype
+    /// suggests: chrono fields become `Date` or `Datetime` columns when writing, and
+    /// options such as [`temporal_as_integers`](Self::temporal_as_integers) or
+    /// [`parse_columns_as`](Self::parse_columns_as) change columns when reading.
+    /// The named columns are passed through unchanged in both directions instead,
+    /// so an integer column such as `calc_min_date` stays an integer.
+    pub fn never_convert_columns(mut self, columns: Vec<String>) -> Self {
+        self.never_convert_columns = columns;
+        self
+    }
+
+    /// Store the fieldless enum column `column` as the discriminants of its variants.
+    ///
+    /// Instead of a `String` column of variant names, the column is written as an
+    /// `Int32` column holding [`EnumDiscriminant::discriminant`] of each value, and
+    /// read back by matching the integers to the variants of `E`. An integer that
+    /// is no discriminant of `E` fails to read with
+    /// [`PolarsSerdeError::UnknownVariant`](crate::PolarsSerdeError::UnknownVariant).
+    ///
+    /// ```ignore
+    /// let options = ConversionOptions::new().enum_as_discriminant::<Priority>("priority".to_string());
+    /// ```
+    pub fn enum_as_discriminant<E: EnumDiscriminant>(mut self, column: String) -> Self {
+        self.enum_discriminants.insert(column, crate::enum_columns::discriminants::<E>());
+        self
+    }
+
+    /// Choose how the Arrow schema is traced when writing a DataFrame
+    pub fn schema_source(mut self, source: SchemaSource) -> Self {
+        self.schema_source = source;
+        self
+    }
+
+    /// Read temporal columns as their integer representation.
+    ///
+    /// `Date` columns become days since the Unix epoch (`i32`), and `Datetime`,
+    /// `Duration` and `Time` columns become counts of their time unit (`i64`), for
+    /// record types that model temporal values as plain integers. Applies to every
+    /// temporal column of the DataFrame, such as `Date` columns read from Parquet.
+    ///
+    /// Polars `Time` columns hold nanoseconds since midnight, so they read into
+    /// `i64` fields; a day's nanoseconds do not fit in an `i32` or `u32`.
+    pub fn temporal_as_integers(mut self, enabled: bool) -> Self {
+        self.temporal_as_integers = enabled;
+        self
+    }
+
+    /// Format timestamps with `format` when a field reads them as strings.
+    ///
+    /// `format` uses chrono's strftime syntax, such as `"%d/%m/%Y %H:%M"`. It
+    /// applies to `Datetime` columns whose target field accepts a timestamp in this
+    /// format, for example a field with a `#[serde(with = ...)]` module that parses
+    /// its own format. Timezone-aware timestamps are formatted in UTC. Other fields,
+    /// such as chrono ones, keep reading timestamps as before.
+    pub fn timestamp_output_format(mut self, format: String) -> Self {
+        self.timestamp_output_format = Some(format);
+        self
+    }
+
+    /// Parse string columns as dates or datetimes when reading.
+    ///
+    /// Keys are column names. Each named string column is parsed into the given
+    /// temporal type before deserialization, for DataFrames whose source lost the
+    /// column types (CSV without a schema, for example). The target struct fields
+    /// then use the matching chrono type.
+    pub fn parse_columns_as(mut self, columns: HashMap<String, ChronoKind>) -> Self {
+        self.parse_columns_as = columns;
+        self
+    }
+
+    /// Choose what happens to date and datetime strings that cannot be parsed.
+    ///
+    /// Applies wherever strings are parsed into `Date` and `Datetime` columns:
+    /// chrono fields when writing, and the columns of
+    /// [`parse_columns_as`](Self::parse_columns_as) when reading. With
+    /// [`OnParseError::Null`], values that match none of the accepted formats, or
+    /// datetimes outside the nanosecond timestamp range, become nulls, so the
+    /// target fields must be `Option`s. The default is [`OnParseError::Strict`].
+    pub fn on_temporal_parse_error(mut self, action: OnParseError) -> Self {
+        self.on_temporal_parse_error = action;
+        self
+    }
+
+    /// Read `Boolean` columns into fieldless enum fields with two variants.
+    ///
+    /// Keys are column names and values the `(true_variant, false_variant)` names
+    /// each named `Boolean` column maps to, such as `("On", "Off")` for a field of
+    /// `enum Toggle { On, Off }`. Nulls stay null, for `Option` fields. Writing is
+    /// unaffected: the enum fields are stored as variant names as usual.
+    pub fn bool_as_enum(mut self, columns: HashMap<String, (String, String)>) -> Self {
+        self.bool_as_enum = columns;
+        self
+    }
+
+    /// Refuse to read DataFrames with more than `max_rows` rows.
+    ///
+    /// The height is checked before any data is converted or allocated, and a
+    /// larger frame fails with [`PolarsSerdeError::InvalidRowCount`](crate::PolarsSerdeError::InvalidRowCount).
+    /// Use this as a guard when reading DataFrames from untrusted sources.
+    pub fn max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = Some(max_rows);
+        self
+    }
+
+    /// Make the traced schema cover every field of the record type.
+    ///
+    /// The types found across all records are combined with what the record type
+    /// declares: fields that are `None` in every record take their declared type
+    /// where it can be traced, and fields no record serialized at all (such as
+    /// `Option` fields skipped with `skip_serializing_if`) are typed the same way
+    /// rather than as `Null` columns.
+    pub fn merge_sample_schema(mut self, enabled: bool) -> Self {
+        self.merge_sample_schema = enabled;
+        self
+    }
+
+    /// Adjust the serde_arrow [`TracingOptions`] used to derive the Arrow schema.
+    ///
+    /// This is an escape hatch for settings this crate has no dedicated option for.
+    /// The closure receives the crate's default tracing options and returns the
+    /// options to trace with. Some settings work against the crate's own handling:
+    /// chrono fields are expected to trace as strings, and dictionary encoding is
+    /// undone before the data reaches Polars.
+    ///
+    /// ```ignore
+    /// let options = ConversionOptions::new().tracing(|tracing| tracing.coerce_numbers(true));
+    /// ```
+    pub fn tracing<F>(mut self, customize: F) -> Self
+    where
+        F: Fn(TracingOptions) -> TracingOptions + Send + Sync + 'static,
+    {
+        self.tracing = Some(TracingCustomizer(Arc::new(customize)));
+        self
+    }
+
+    /// Compress the Parquet files written by `write_parquet_with` with the given codec.
+    ///
+    /// Requires the `parquet` feature to take effect.
+    pub fn parquet_compression(mut self, compression: ParquetCompression) -> Self {
+        self.parquet_compression = compression;
+        self
+    }
+
+    /// Encode the Parquet column `column` as `encoding` in files written by `write_parquet_with`.
+    ///
+    /// Columns without a hint are dictionary encoded until their dictionary grows too
+    /// large, and then fall back to plain values. Hints name top-level columns of single
+    /// values; naming a list or struct column, or one the records do not have, is an
+    /// error when writing. Requires the `parquet` feature to take effect.
+    pub fn parquet_column_encoding(mut self, column: String, encoding: ParquetEncoding) -> Self {
+        self.parquet_column_encodings.insert(column, encoding);
+        self
+    }
+
+    /// The tracing options to use, with any customization applied to the defaults
+    pub(crate) fn tracing_options(&self) -> TracingOptions {
+        let defaults = crate::default_tracing_options()
+            .string_dictionary_encoding(self.keep_string_dictionary);
+        match &self.tracing {
+            Some(TracingCustomizer(customize)) => customize(defaults),
+            None => defaults,
+        }
+    }
+}
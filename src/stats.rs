@@ -0,0 +1,28 @@
+//! Statistics collected while converting between records and DataFrames
+
+use std::time::Duration;
+
+/// Row and column counts plus per-phase timings of a single conversion.
+///
+/// Returned by [`to_dataframe_with_stats`](crate::to_dataframe_with_stats) and
+/// [`from_dataframe_with_stats`](crate::from_dataframe_with_stats). Phases that a
+/// conversion direction does not run are left at zero.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConversionStats {
+    /// Number of records written or read
+    pub rows: usize,
+    /// Number of temporal columns rewritten by the chrono conversion passes
+    pub chrono_columns: usize,
+    /// Number of dictionary-encoded columns rewritten as plain strings
+    pub dictionary_columns: usize,
+    /// Time spent tracing the Arrow schema of the record type
+    pub tracing_time: Duration,
+    /// Time spent serializing records to, or deserializing them from, Arrow arrays
+    pub serde_time: Duration,
+    /// Time spent in the chrono conversion passes
+    pub chrono_time: Duration,
+    /// Time spent rewriting dictionary columns
+    pub dictionary_time: Duration,
+    /// Time spent exchanging data between Arrow and Polars
+    pub interchange_time: Duration,
+}
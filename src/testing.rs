@@ -0,0 +1,122 @@
+//! DataFrame comparison helpers for tests, enabled with the `testing` feature
+
+#[cfg(feature = "polars_0_40")]
+use polars_crate_0_40 as polars;
+
+#[cfg(feature = "polars_0_41")]
+use polars_crate_0_41 as polars;
+
+#[cfg(feature = "polars_0_42")]
+use polars_crate_0_42 as polars;
+
+#[cfg(feature = "polars_0_43")]
+use polars_crate_0_43 as polars;
+
+#[cfg(feature = "polars_0_44")]
+use polars_crate_0_44 as polars;
+
+#[cfg(feature = "polars_0_45")]
+use polars_crate_0_45 as polars;
+
+#[cfg(feature = "polars_0_46")]
+use polars_crate_0_46 as polars;
+
+#[cfg(feature = "polars_0_47")]
+use polars_crate_0_47 as polars;
+
+#[cfg(feature = "polars_0_48")]
+use polars_crate_0_48 as polars;
+
+#[cfg(feature = "polars_0_49")]
+use polars_crate_0_49 as polars;
+
+#[cfg(feature = "polars_0_50")]
+use polars_crate_0_50 as polars;
+
+use polars::prelude::{AnyValue, DataFrame};
+
+/// Compare two DataFrames cell by cell.
+///
+/// The frames must have the same shape, column names and dtypes, and equal values
+/// in every cell. Unlike `DataFrame::equals`, NaN is equal to NaN, including inside
+/// list cells, so frames holding NaN compare equal on every supported Polars version.
+/// A null is only equal to a null, not to NaN. Returns a description of the first
+/// difference found.
+pub fn compare_frames(left: &DataFrame, right: &DataFrame) -> Result<(), String> {
+    if left.shape() != right.shape() {
+        return Err(format!("shape mismatch: {:?} vs {:?}", left.shape(), right.shape()));
+    }
+
+    for (left_column, right_column) in left.get_columns().iter().zip(right.get_columns()) {
+        let name = left_column.name().to_string();
+        if name != right_column.name().to_string() {
+            return Err(format!("column name mismatch: '{}' vs '{}'", name, right_column.name()));
+        }
+        if left_column.dtype() != right_column.dtype() {
+            return Err(format!(
+                "dtype mismatch in column '{}': {} vs {}",
+                name,
+                left_column.dtype(),
+                right_column.dtype()
+            ));
+        }
+
+        for row in 0..left.height() {
+            let left_value = left_column.get(row).map_err(|e| e.to_string())?;
+            let right_value = right_column.get(row).map_err(|e| e.to_string())?;
+            if !values_equal(&left_value, &right_value) {
+                return Err(format!(
+                    "value mismatch in column '{}' at row {}: {} vs {}",
+                    name, row, left_value, right_value
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The first difference between two DataFrames, or `None` if they are equal.
+///
+/// Equality is as defined by [`compare_frames`]. The description names the column,
+/// and the row for a differing cell:
+///
+/// ```ignore
+/// use serde_polars::testing::dataframe_diff;
+///
+/// let expected = serde_polars::to_dataframe(&records)?;
+/// assert_eq!(dataframe_diff(&expected, &actual), None);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn dataframe_diff(a: &DataFrame, b: &DataFrame) -> Option<String> {
+    compare_frames(a, b).err()
+}
+
+/// Assert that two DataFrames are equal as defined by [`compare_frames`].
+///
+/// # Panics
+///
+/// Panics with a description of the first difference found.
+#[track_caller]
+pub fn assert_frames_equal(left: &DataFrame, right: &DataFrame) {
+    if let Err(difference) = compare_frames(left, right) {
+        panic!("DataFrames differ: {}", difference);
+    }
+}
+
+/// Cell equality that treats NaN as equal to NaN
+fn values_equal(left: &AnyValue, right: &AnyValue) -> bool {
+    match (left, right) {
+        (AnyValue::Float32(a), AnyValue::Float32(b)) => a == b || (a.is_nan() && b.is_nan()),
+        (AnyValue::Float64(a), AnyValue::Float64(b)) => a == b || (a.is_nan() && b.is_nan()),
+        (AnyValue::List(a), AnyValue::List(b)) => {
+            a.dtype() == b.dtype()
+                && a.len() == b.len()
+                && (0..a.len()).all(|index| match (a.get(index), b.get(index)) {
+                    (Ok(a), Ok(b)) => values_equal(&a, &b),
+                    _ => false,
+                })
+        }
+        _ => left == right,
+    }
+}
@@ -0,0 +1,354 @@
+//! Conversion of `time` crate types, enabled with the `time` feature.
+//!
+//! The `time` crate serializes its types to non-human-readable formats (such as
+//! Arrow) as tuples, which serde_arrow stores as struct columns:
+//!
+//! - `Date` as `(year, ordinal)`
+//! - `PrimitiveDateTime` as `(year, ordinal, hour, minute, second, nanosecond)`
+//! - `OffsetDateTime` as the local `PrimitiveDateTime` fields followed by the
+//!   offset's `(hours, minutes, seconds)`
+//!
+//! These columns are turned into `Date32` and nanosecond `Timestamp` columns when
+//! writing, and back into tuples when reading. The types are recognized by name,
+//! so this module does not depend on the `time` crate itself.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow::array::{
+    Array, ArrayRef, AsArray, Date32Array, Int32Array, Int64Array, Int8Array, StructArray,
+    TimestampNanosecondArray, UInt16Array, UInt32Array, UInt8Array,
+};
+use arrow::compute;
+use arrow::datatypes::{DataType, Field, Fields, TimeUnit};
+use arrow::record_batch::RecordBatch;
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
+use serde::de::DeserializeOwned;
+
+use crate::{PolarsSerdeError, Result};
+
+/// Kind name of `time::Date` fields
+pub(crate) const DATE: &str = "TimeDate";
+/// Kind name of `time::PrimitiveDateTime` fields
+pub(crate) const PRIMITIVE_DATE_TIME: &str = "TimePrimitiveDateTime";
+/// Kind name of `time::OffsetDateTime` fields
+pub(crate) const OFFSET_DATE_TIME: &str = "TimeOffsetDateTime";
+
+/// The kind name of a `time` type, given the type name of a struct field
+pub(crate) fn kind_of_type_name(type_name: &str) -> Option<&'static str> {
+    let type_name = type_name
+        .strip_prefix("core::option::Option<")
+        .and_then(|inner| inner.strip_suffix('>'))
+        .unwrap_or(type_name);
+    match type_name {
+        "time::date::Date" => Some(DATE),
+        "time::primitive_date_time::PrimitiveDateTime" => Some(PRIMITIVE_DATE_TIME),
+        "time::offset_date_time::OffsetDateTime" => Some(OFFSET_DATE_TIME),
+        _ => None,
+    }
+}
+
+/// The kind name of the `time` type field `field` of `T` deserializes from, if any.
+///
+/// `time` types ask for a tuple of a length unique to each type; the length is
+/// recorded without reading any data.
+pub(crate) fn field_kind<T: DeserializeOwned>(field: &str) -> Option<&'static str> {
+    match crate::fixed_size_lists::field_tuple_len::<T>(field)? {
+        2 => Some(DATE),
+        6 => Some(PRIMITIVE_DATE_TIME),
+        9 => Some(OFFSET_DATE_TIME),
+        _ => None,
+    }
+}
+
+fn conversion_error(column: &str, message: impl std::fmt::Display) -> PolarsSerdeError {
+    PolarsSerdeError::ConversionError {
+        message: format!("Failed to convert column '{}': {}", column, message),
+    }
+}
+
+/// The tuple elements of a struct column, widened to `i64`
+fn tuple_elements<'a>(column: &'a ArrayRef, name: &str, len: usize) -> Result<(Vec<Int64Array>, &'a StructArray)> {
+    let tuples = column
+        .as_struct_opt()
+        .ok_or_else(|| conversion_error(name, format!("expected a struct column, found {}", column.data_type())))?;
+    if tuples.num_columns() != len {
+        return Err(conversion_error(
+            name,
+            format!("expected {} tuple elements, found {}", len, tuples.num_columns()),
+        ));
+    }
+
+    let elements = tuples
+        .columns()
+        .iter()
+        .map(|element| {
+            compute::cast(element, &DataType::Int64)
+                .map(|element| element.as_primitive::<arrow::datatypes::Int64Type>().clone())
+                .map_err(|e| conversion_error(name, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok((elements, tuples))
+}
+
+fn unix_epoch() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1970, 1, 1).unwrap()
+}
+
+/// The local date and time held by the first six tuple elements of `row`
+fn local_date_time(elements: &[Int64Array], row: usize) -> Option<NaiveDateTime> {
+    let value = |i: usize| elements[i].value(row);
+    let date = NaiveDate::from_yo_opt(i32::try_from(value(0)).ok()?, u32::try_from(value(1)).ok()?)?;
+    if elements.len() == 2 {
+        return date.and_hms_opt(0, 0, 0);
+    }
+    date.and_hms_nano_opt(
+        u32::try_from(value(2)).ok()?,
+        u32::try_from(value(3)).ok()?,
+        u32::try_from(value(4)).ok()?,
+        u32::try_from(value(5)).ok()?,
+    )
+}
+
+/// Convert a struct column of `time` tuples into a `Date32` or nanosecond `Timestamp` column.
+///
+/// `OffsetDateTime` values are stored as UTC instants and tagged with `timezone`.
+pub(crate) fn tuples_to_temporal(
+    column: &ArrayRef,
+    name: &str,
+    kind: &str,
+    timezone: Arc<str>,
+) -> Result<(ArrayRef, DataType)> {
+    let len = match kind {
+        DATE => 2,
+        PRIMITIVE_DATE_TIME => 6,
+        _ => 9,
+    };
+    let (elements, tuples) = tuple_elements(column, name, len)?;
+    let invalid = |row: usize| conversion_error(name, format!("invalid {} value in row {}", kind, row));
+
+    if kind == DATE {
+        let days = (0..tuples.len())
+            .map(|row| {
+                if tuples.is_null(row) {
+                    return Ok(None);
+                }
+                let date = local_date_time(&elements, row).ok_or_else(|| invalid(row))?.date();
+                Ok(Some((date - unix_epoch()).num_days() as i32))
+            })
+            .collect::<Result<Date32Array>>()?;
+        return Ok((Arc::new(days), DataType::Date32));
+    }
+
+    let nanos = (0..tuples.len())
+        .map(|row| {
+            if tuples.is_null(row) {
+                return Ok(None);
+            }
+            let local = local_date_time(&elements, row).ok_or_else(|| invalid(row))?;
+            let offset_seconds = if kind == OFFSET_DATE_TIME {
+                elements[6].value(row) * 3600 + elements[7].value(row) * 60 + elements[8].value(row)
+            } else {
+                0
+            };
+            let utc = local - chrono::Duration::seconds(offset_seconds);
+            utc.and_utc().timestamp_nanos_opt().map(Some).ok_or_else(|| invalid(row))
+        })
+        .collect::<Result<TimestampNanosecondArray>>()?;
+
+    let timezone = (kind == OFFSET_DATE_TIME).then_some(timezone);
+    let data_type = DataType::Timestamp(TimeUnit::Nanosecond, timezone.clone());
+    Ok((Arc::new(nanos.with_timezone_opt(timezone)), data_type))
+}
+
+/// Convert a `Date32` or `Timestamp` column into the struct column of `time` tuples
+/// that `kind` deserializes from. `OffsetDateTime` values are read at UTC.
+pub(crate) fn temporal_to_tuples(column: &ArrayRef, name: &str, kind: &str) -> Result<ArrayRef> {
+    let out_of_range = |row: usize| conversion_error(name, format!("value in row {} is out of range", row));
+
+    let local: Vec<Option<NaiveDateTime>> = match (kind, column.data_type()) {
+        (DATE, DataType::Date32) => column
+            .as_primitive::<arrow::datatypes::Date32Type>()
+            .iter()
+            .enumerate()
+            .map(|(row, days)| match days {
+                Some(days) => unix_epoch()
+                    .checked_add_signed(chrono::Duration::days(days.into()))
+                    .and_then(|date| date.and_hms_opt(0, 0, 0))
+                    .map(Some)
+                    .ok_or_else(|| out_of_range(row)),
+                None => Ok(None),
+            })
+            .collect::<Result<_>>()?,
+        (PRIMITIVE_DATE_TIME | OFFSET_DATE_TIME, DataType::Timestamp(unit, _)) => {
+            let nanos_per_unit = match unit {
+                TimeUnit::Second => 1_000_000_000,
+                TimeUnit::Millisecond => 1_000_000,
+                TimeUnit::Microsecond => 1_000,
+                TimeUnit::Nanosecond => 1,
+            };
+            let values = compute::cast(column, &DataType::Int64).map_err(|e| conversion_error(name, e))?;
+            values
+                .as_primitive::<arrow::datatypes::Int64Type>()
+                .iter()
+                .enumerate()
+                .map(|(row, value)| match value {
+                    Some(value) => value
+                        .checked_mul(nanos_per_unit)
+                        .map(|nanos| Some(DateTime::from_timestamp_nanos(nanos).naive_utc()))
+                        .ok_or_else(|| out_of_range(row)),
+                    None => Ok(None),
+                })
+                .collect::<Result<_>>()?
+        }
+        (_, other) => {
+            return Err(conversion_error(name, format!("cannot read {} values from {}", kind, other)));
+        }
+    };
+
+    let element = |f: &dyn Fn(&NaiveDateTime) -> i64| -> Vec<Option<i64>> {
+        local.iter().map(|value| value.as_ref().map(f)).collect()
+    };
+    let years = element(&|value| value.year().into());
+    let ordinals = element(&|value| value.ordinal().into());
+
+    let mut fields = vec![Field::new("0", DataType::Int32, true), Field::new("1", DataType::UInt16, true)];
+    let mut arrays: Vec<ArrayRef> = vec![
+        Arc::new(years.iter().map(|v| v.map(|v| v as i32)).collect::<Int32Array>()),
+        Arc::new(ordinals.iter().map(|v| v.map(|v| v as u16)).collect::<UInt16Array>()),
+    ];
+
+    if kind != DATE {
+        for (i, part) in [
+            element(&|value| value.hour().into()),
+            element(&|value| value.minute().into()),
+            element(&|value| value.second().into()),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            fields.push(Field::new((i + 2).to_string(), DataType::UInt8, true));
+            arrays.push(Arc::new(part.iter().map(|v| v.map(|v| v as u8)).collect::<UInt8Array>()));
+        }
+        let nanos = element(&|value| value.nanosecond().into());
+        fields.push(Field::new("5", DataType::UInt32, true));
+        arrays.push(Arc::new(nanos.iter().map(|v| v.map(|v| v as u32)).collect::<UInt32Array>()));
+    }
+
+    if kind == OFFSET_DATE_TIME {
+        // Values are read at UTC, so the offset is zero
+        for i in 6..9 {
+            fields.push(Field::new(i.to_string(), DataType::Int8, true));
+            arrays.push(Arc::new(local.iter().map(|value| value.map(|_| 0)).collect::<Int8Array>()));
+        }
+    }
+
+    let tuples = StructArray::try_new(Fields::from(fields), arrays, column.nulls().cloned())
+        .map_err(|e| conversion_error(name, e))?;
+    Ok(Arc::new(tuples))
+}
+
+/// Convert the named `Date32` and `Timestamp` columns into `time` tuples of the given kinds
+pub(crate) fn temporal_columns_to_tuples(
+    batch: RecordBatch,
+    time_columns: &HashMap<String, &'static str>,
+) -> Result<RecordBatch> {
+    let schema = batch.schema();
+    let mut new_columns = Vec::with_capacity(batch.num_columns());
+    let mut new_fields = Vec::with_capacity(batch.num_columns());
+
+    for (field, column) in schema.fields().iter().zip(batch.columns()) {
+        match time_columns.get(field.name()) {
+            Some(kind) => {
+                let tuples = temporal_to_tuples(column, field.name(), kind)?;
+                new_fields.push(Arc::new(Field::new(field.name(), tuples.data_type().clone(), field.is_nullable())));
+                new_columns.push(tuples);
+            }
+            None => {
+                new_columns.push(column.clone());
+                new_fields.push(field.clone());
+            }
+        }
+    }
+
+    let new_schema = Arc::new(arrow::datatypes::Schema::new(new_fields));
+    RecordBatch::try_new(new_schema, new_columns).map_err(|e| PolarsSerdeError::ConversionError {
+        message: format!("Failed to create converted record batch: {}", e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tuples(elements: Vec<(Field, ArrayRef)>) -> ArrayRef {
+        Arc::new(StructArray::from(
+            elements
+                .into_iter()
+                .map(|(field, array)| (Arc::new(field), array))
+                .collect::<Vec<_>>(),
+        ))
+    }
+
+    #[test]
+    fn test_date_tuples_roundtrip() {
+        // 2024-03-01 is day 61 of a leap year
+        let column = tuples(vec![
+            (Field::new("0", DataType::Int32, false), Arc::new(Int32Array::from(vec![1970, 2024])) as ArrayRef),
+            (Field::new("1", DataType::UInt16, false), Arc::new(UInt16Array::from(vec![1, 61]))),
+        ]);
+
+        let (dates, data_type) = tuples_to_temporal(&column, "day", DATE, "UTC".into()).unwrap();
+        assert_eq!(data_type, DataType::Date32);
+        assert_eq!(dates.as_primitive::<arrow::datatypes::Date32Type>().values(), &[0, 19783]);
+
+        let back = temporal_to_tuples(&dates, "day", DATE).unwrap();
+        let back = back.as_struct();
+        assert_eq!(back.column(0).as_primitive::<arrow::datatypes::Int32Type>().values(), &[1970, 2024]);
+        assert_eq!(back.column(1).as_primitive::<arrow::datatypes::UInt16Type>().values(), &[1, 61]);
+    }
+
+    #[test]
+    fn test_offset_date_time_tuples_are_stored_as_utc() {
+        // 1970-01-01T02:00:00.5+02:00 is half a second past the epoch
+        let int = |value: i64, data_type: DataType| compute::cast(&(Arc::new(Int64Array::from(vec![value])) as ArrayRef), &data_type).unwrap();
+        let column = tuples(
+            [
+                (1970, DataType::Int32),
+                (1, DataType::UInt16),
+                (2, DataType::UInt8),
+                (0, DataType::UInt8),
+                (0, DataType::UInt8),
+                (500_000_000, DataType::UInt32),
+                (2, DataType::Int8),
+                (0, DataType::Int8),
+                (0, DataType::Int8),
+            ]
+            .into_iter()
+            .enumerate()
+            .map(|(i, (value, data_type))| (Field::new(i.to_string(), data_type.clone(), false), int(value, data_type)))
+            .collect(),
+        );
+
+        let (nanos, data_type) = tuples_to_temporal(&column, "at", OFFSET_DATE_TIME, "UTC".into()).unwrap();
+        assert_eq!(data_type, DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into())));
+        assert_eq!(nanos.as_primitive::<arrow::datatypes::TimestampNanosecondType>().values(), &[500_000_000]);
+
+        let back = temporal_to_tuples(&nanos, "at", OFFSET_DATE_TIME).unwrap();
+        let back = back.as_struct();
+        assert_eq!(back.num_columns(), 9);
+        assert_eq!(back.column(2).as_primitive::<arrow::datatypes::UInt8Type>().value(0), 0);
+        assert_eq!(back.column(5).as_primitive::<arrow::datatypes::UInt32Type>().value(0), 500_000_000);
+        assert_eq!(back.column(6).as_primitive::<arrow::datatypes::Int8Type>().value(0), 0);
+    }
+
+    #[test]
+    fn test_kind_of_type_name() {
+        assert_eq!(kind_of_type_name("time::date::Date"), Some(DATE));
+        assert_eq!(
+            kind_of_type_name("core::option::Option<time::offset_date_time::OffsetDateTime>"),
+            Some(OFFSET_DATE_TIME)
+        );
+        assert_eq!(kind_of_type_name("chrono::naive::date::NaiveDate"), None);
+    }
+}
@@ -33,35 +33,8 @@ use polars_crate_0_49 as polars;
 #[cfg(feature = "polars_0_50")]
 use polars_crate_0_50 as polars;
 
-#[cfg(not(any(
-    feature = "polars_0_40",
-    feature = "polars_0_41",
-    feature = "polars_0_42",
-    feature = "polars_0_43",
-    feature = "polars_0_44",
-    feature = "polars_0_45",
-    feature = "polars_0_46",
-    feature = "polars_0_47",
-    feature = "polars_0_48",
-    feature = "polars_0_49",
-    feature = "polars_0_50"
-)))]
-use crate::PolarsSerdeError;
 use crate::Result;
 use arrow::record_batch::RecordBatch;
-#[cfg(any(
-    feature = "polars_0_40",
-    feature = "polars_0_41",
-    feature = "polars_0_42",
-    feature = "polars_0_43",
-    feature = "polars_0_44",
-    feature = "polars_0_45",
-    feature = "polars_0_46",
-    feature = "polars_0_47",
-    feature = "polars_0_48",
-    feature = "polars_0_49",
-    feature = "polars_0_50"
-))]
 use df_interchange::Interchange;
 
 /// Macro to generate version-specific conversion functions based on enabled features
@@ -69,8 +42,187 @@ macro_rules! impl_version_conversions {
     () => {
         /// Convert DataFrame to RecordBatch using the appropriate Polars version
         pub fn dataframe_to_arrow(mut df: polars::prelude::DataFrame) -> Result<Vec<RecordBatch>> {
+            // A frame without columns has no arrays to export, which the interchange
+            // cannot handle, so it gives no batches
+            if df.width() == 0 {
+                return Ok(Vec::new());
+            }
+
+            // Callers append the batches in turn, so they must follow the frame's row
+            // order; one chunk gives one batch whatever chunks the frame was built from
             df.as_single_chunk();
 
+            // The C data interface export rejects `Null` dtype columns, so they are
+            // sent as all-null Boolean columns instead
+            let null_columns: Vec<String> = df
+                .get_columns()
+                .iter()
+                .filter(|column| column.dtype() == &polars::prelude::DataType::Null)
+                .map(|column| column.name().to_string())
+                .collect();
+            for name in null_columns {
+                let typed = df.column(&name)?.cast(&polars::prelude::DataType::Boolean)?;
+                df.with_column(typed)?;
+            }
+
+            // Sliced `Array` columns do not survive the export, so they are sent as
+            // lists and turned back into fixed-size lists afterwards
+            let array_columns: Vec<(String, usize)> = df
+                .get_columns()
+                .iter()
+                .filter_map(|column| match column.dtype() {
+                    polars::prelude::DataType::Array(_, width) => Some((column.name().to_string(), *width)),
+                    _ => None,
+                })
+                .collect();
+            for (name, _) in &array_columns {
+                let column = df.column(name)?;
+                let polars::prelude::DataType::Array(inner, _) = column.dtype() else {
+                    unreachable!("array columns were selected by dtype");
+                };
+                let typed = column.cast(&polars::prelude::DataType::List(inner.clone()))?;
+                df.with_column(typed)?;
+            }
+
+            // A sliced `Struct` column with nulls is exported with an offset as well
+            // as already sliced fields, which the import then slices a second time.
+            // Gathering the rows gives fresh arrays that start at offset zero
+            let null_struct_columns: Vec<String> = df
+                .get_columns()
+                .iter()
+                .filter(|column| {
+                    matches!(column.dtype(), polars::prelude::DataType::Struct(_)) && column.null_count() > 0
+                })
+                .map(|column| column.name().to_string())
+                .collect();
+            for name in &null_struct_columns {
+                let copied = df.column(name)?.reverse().reverse();
+                df.with_column(copied)?;
+            }
+
+            // A list column without a single string value is exported with an empty,
+            // misaligned buffer of string views, which the import rejects. As it
+            // holds only empty lists and nulls, it is sent as a list of integers
+            let empty_string_lists: Vec<String> = df
+                .get_columns()
+                .iter()
+                .filter(|column| match column.dtype() {
+                    polars::prelude::DataType::List(inner) => {
+                        inner.as_ref() == &polars::prelude::DataType::String
+                            && column.list().is_ok_and(|list| list.get_inner().is_empty())
+                    }
+                    _ => false,
+                })
+                .map(|column| column.name().to_string())
+                .collect();
+            for name in &empty_string_lists {
+                let list_type = polars::prelude::DataType::List(Box::new(polars::prelude::DataType::Int32));
+                let typed = df.column(name)?.cast(&list_type)?;
+                df.with_column(typed)?;
+            }
+
+            let batches = export_to_arrow(df)?;
+
+            if array_columns.is_empty() && empty_string_lists.is_empty() {
+                Ok(batches)
+            } else {
+                batches
+                    .into_iter()
+                    .map(|batch| lists_to_fixed_size_lists(batch, &array_columns))
+                    .map(|batch| integer_lists_to_string_lists(batch?, &empty_string_lists))
+                    .collect()
+            }
+        }
+
+        /// Mark the rows of struct columns, and of the structs nested in them, whose fields
+        /// are all null as null rows, where `accepts_null` holds for the path of the struct
+        #[cfg(any(
+            feature = "polars_0_40",
+            feature = "polars_0_41",
+            feature = "polars_0_42",
+            feature = "polars_0_43"
+        ))]
+        pub(crate) fn null_structs_from_fields(
+            batch: RecordBatch,
+            accepts_null: &dyn Fn(&[&str]) -> bool,
+        ) -> Result<RecordBatch> {
+            use arrow::array::{ArrayRef, StructArray};
+
+            let schema = batch.schema();
+            if !schema.fields().iter().any(|field| matches!(field.data_type(), arrow::datatypes::DataType::Struct(_))) {
+                return Ok(batch);
+            }
+            let mut arrays: Vec<ArrayRef> = Vec::with_capacity(batch.num_columns());
+            for (field, column) in schema.fields().iter().zip(batch.columns()) {
+                let Some(array) = column.as_any().downcast_ref::<StructArray>() else {
+                    arrays.push(column.clone());
+                    continue;
+                };
+                let own_nulls = accepts_null(&[field.name()]);
+                let array = struct_nulls_from_fields(array, &[field.name()], own_nulls, accepts_null).map_err(|e| {
+                    crate::PolarsSerdeError::ConversionError {
+                        message: format!("Failed to convert struct column '{}': {}", field.name(), e),
+                    }
+                })?;
+                arrays.push(std::sync::Arc::new(array));
+            }
+            RecordBatch::try_new(schema, arrays).map_err(|e| crate::PolarsSerdeError::ConversionError {
+                message: format!("Failed to create converted record batch: {}", e),
+            })
+        }
+
+        /// `array`, found at `path`, with the nulls of its struct fields rebuilt from their
+        /// own fields where `accepts_null` allows, and its own nulls too when `own_nulls` is set
+        #[cfg(any(
+            feature = "polars_0_40",
+            feature = "polars_0_41",
+            feature = "polars_0_42",
+            feature = "polars_0_43"
+        ))]
+        fn struct_nulls_from_fields(
+            array: &arrow::array::StructArray,
+            path: &[&str],
+            own_nulls: bool,
+            accepts_null: &dyn Fn(&[&str]) -> bool,
+        ) -> std::result::Result<arrow::array::StructArray, arrow::error::ArrowError> {
+            use arrow::array::{Array, ArrayRef, StructArray};
+            use arrow::buffer::NullBuffer;
+
+            let children = array
+                .fields()
+                .iter()
+                .zip(array.columns())
+                .map(|(field, child)| match child.as_any().downcast_ref::<StructArray>() {
+                    Some(nested) => {
+                        let path = [path, &[field.name().as_str()]].concat();
+                        let own_nulls = accepts_null(&path);
+                        Ok(std::sync::Arc::new(struct_nulls_from_fields(nested, &path, own_nulls, accepts_null)?) as ArrayRef)
+                    }
+                    None => Ok(child.clone()),
+                })
+                .collect::<std::result::Result<Vec<ArrayRef>, arrow::error::ArrowError>>()?;
+            // A nested struct holds a value when any of its own fields does, whether or
+            // not its nulls were rebuilt
+            fn holds_value(array: &dyn Array, row: usize) -> bool {
+                match array.as_any().downcast_ref::<StructArray>() {
+                    Some(nested) => nested.columns().iter().any(|child| holds_value(child, row)),
+                    None => array.is_valid(row),
+                }
+            }
+            let nulls = if own_nulls {
+                Some(
+                    (0..array.len())
+                        .map(|row| children.iter().any(|child| holds_value(child, row)))
+                        .collect::<NullBuffer>(),
+                )
+            } else {
+                array.nulls().cloned()
+            };
+            StructArray::try_new(array.fields().clone(), children, nulls)
+        }
+
+        /// Export a DataFrame through the interchange of the enabled Polars version
+        fn export_to_arrow(df: polars::prelude::DataFrame) -> Result<Vec<RecordBatch>> {
             #[cfg(feature = "polars_0_40")]
             return Ok(Interchange::from_polars_0_40(df)?.to_arrow_55()?);
 
@@ -103,21 +255,199 @@ macro_rules! impl_version_conversions {
 
             #[cfg(feature = "polars_0_50")]
             return Ok(Interchange::from_polars_0_50(df)?.to_arrow_55()?);
+        }
+
+        /// Cast the named list columns of a batch to fixed-size lists of the given widths
+        fn lists_to_fixed_size_lists(batch: RecordBatch, columns: &[(String, usize)]) -> Result<RecordBatch> {
+            use arrow::datatypes::{DataType, Field, Schema};
+
+            let schema = batch.schema();
+            let mut fields = Vec::with_capacity(batch.num_columns());
+            let mut arrays = Vec::with_capacity(batch.num_columns());
+            for (field, column) in schema.fields().iter().zip(batch.columns()) {
+                let width = columns.iter().find(|(name, _)| name == field.name()).map(|(_, width)| *width);
+                match (width, field.data_type()) {
+                    (Some(width), DataType::List(item) | DataType::LargeList(item)) => {
+                        let data_type = DataType::FixedSizeList(item.clone(), width as i32);
+                        let array = arrow::compute::cast(column, &data_type).map_err(|e| {
+                            crate::PolarsSerdeError::ConversionError {
+                                message: format!("Failed to convert array column '{}': {}", field.name(), e),
+                            }
+                        })?;
+                        fields.push(std::sync::Arc::new(Field::new(field.name(), data_type, field.is_nullable())));
+                        arrays.push(array);
+                    }
+                    _ => {
+                        fields.push(field.clone());
+                        arrays.push(column.clone());
+                    }
+                }
+            }
+            RecordBatch::try_new(std::sync::Arc::new(Schema::new(fields)), arrays).map_err(|e| {
+                crate::PolarsSerdeError::ConversionError {
+                    message: format!("Failed to create converted record batch: {}", e),
+                }
+            })
+        }
+
+        /// Cast the named lists of integers in a batch, which hold no values, to lists of strings
+        fn integer_lists_to_string_lists(batch: RecordBatch, columns: &[String]) -> Result<RecordBatch> {
+            use arrow::datatypes::{DataType, Field, Schema};
+
+            if columns.is_empty() {
+                return Ok(batch);
+            }
+            let schema = batch.schema();
+            let mut fields = Vec::with_capacity(batch.num_columns());
+            let mut arrays = Vec::with_capacity(batch.num_columns());
+            for (field, column) in schema.fields().iter().zip(batch.columns()) {
+                let string_item = |item: &Field| std::sync::Arc::new(item.clone().with_data_type(DataType::Utf8View));
+                let data_type = match field.data_type() {
+                    DataType::List(item) if columns.contains(field.name()) => DataType::List(string_item(item)),
+                    DataType::LargeList(item) if columns.contains(field.name()) => {
+                        DataType::LargeList(string_item(item))
+                    }
+                    _ => {
+                        fields.push(field.clone());
+                        arrays.push(column.clone());
+                        continue;
+                    }
+                };
+                let array = arrow::compute::cast(column, &data_type).map_err(|e| {
+                    crate::PolarsSerdeError::ConversionError {
+                        message: format!("Failed to convert list column '{}': {}", field.name(), e),
+                    }
+                })?;
+                fields.push(std::sync::Arc::new(Field::new(field.name(), data_type, field.is_nullable())));
+                arrays.push(array);
+            }
+            RecordBatch::try_new(std::sync::Arc::new(Schema::new(fields)), arrays).map_err(|e| {
+                crate::PolarsSerdeError::ConversionError {
+                    message: format!("Failed to create converted record batch: {}", e),
+                }
+            })
+        }
+
+        /// The first column of a DataFrame as a Series
+        pub fn first_series(df: &polars::prelude::DataFrame) -> Option<polars::prelude::Series> {
+            let column = df.select_at_idx(0)?;
+
+            // Polars 0.44 made DataFrame columns `Column`s wrapping the Series
+            #[cfg(any(
+                feature = "polars_0_40",
+                feature = "polars_0_41",
+                feature = "polars_0_42",
+                feature = "polars_0_43"
+            ))]
+            return Some(column.clone());
 
-            // Fallback error if no feature is enabled
             #[cfg(not(any(
-                feature = "polars_0_40", feature = "polars_0_41", feature = "polars_0_42",
-                feature = "polars_0_43", feature = "polars_0_44", feature = "polars_0_45",
-                feature = "polars_0_46", feature = "polars_0_47", feature = "polars_0_48",
-                feature = "polars_0_49", feature = "polars_0_50"
+                feature = "polars_0_40",
+                feature = "polars_0_41",
+                feature = "polars_0_42",
+                feature = "polars_0_43"
             )))]
-            Err(PolarsSerdeError::ConversionError {
-                message: "No Polars version feature enabled. Please enable one of: polars-0-40, polars-0-41, ..., polars-0-50".to_string()
-            })
+            Some(column.as_materialized_series().clone())
+        }
+
+        /// The name and dtype of each column of a DataFrame, in column order.
+        ///
+        /// The dtypes are those of the enabled Polars version, so the output schema
+        /// can be checked without version-specific code.
+        pub fn column_dtypes(df: &polars::prelude::DataFrame) -> Vec<(String, polars::prelude::DataType)> {
+            df.get_columns()
+                .iter()
+                .map(|column| (column.name().to_string(), column.dtype().clone()))
+                .collect()
+        }
+
+        /// Run `f` with the global string cache enabled.
+        ///
+        /// Polars 0.50 replaced the string cache with global categories, which are
+        /// always comparable, so there `f` simply runs.
+        pub fn with_string_cache<R>(f: impl FnOnce() -> R) -> R {
+            #[cfg(not(feature = "polars_0_50"))]
+            let _holder = polars::prelude::StringCacheHolder::hold();
+            f()
+        }
+
+        /// Cast the columns of a DataFrame to the dtypes of `schema`, in the schema's order.
+        ///
+        /// A column the schema lists but the frame lacks is a `MissingColumn` error. A
+        /// column the schema does not list, or whose values do not survive the cast,
+        /// is a `SchemaMismatch`.
+        pub fn cast_to_schema(
+            df: &polars::prelude::DataFrame,
+            schema: &polars::prelude::Schema,
+        ) -> Result<polars::prelude::DataFrame> {
+            if let Some(extra) = df.get_columns().iter().find(|column| schema.get(column.name()).is_none()) {
+                return Err(crate::PolarsSerdeError::SchemaMismatch {
+                    column: extra.name().to_string(),
+                    expected: "no column".to_string(),
+                    actual: extra.dtype().to_string(),
+                });
+            }
+
+            let mut columns = Vec::with_capacity(schema.len());
+            for (name, dtype) in schema.iter() {
+                let column = df.column(name).map_err(|_| crate::PolarsSerdeError::MissingColumn {
+                    column: name.to_string(),
+                })?;
+                if column.dtype() == dtype {
+                    columns.push(column.clone());
+                    continue;
+                }
+                let cast = column.strict_cast(dtype).map_err(|_| crate::PolarsSerdeError::SchemaMismatch {
+                    column: name.to_string(),
+                    expected: dtype.to_string(),
+                    actual: column.dtype().to_string(),
+                })?;
+                columns.push(cast);
+            }
+            Ok(polars::prelude::DataFrame::new(columns)?)
+        }
+
+        /// The name and dtype of each column of a DataFrame, as a `Schema`
+        pub fn frame_schema(df: &polars::prelude::DataFrame) -> polars::prelude::Schema {
+            df.get_columns()
+                .iter()
+                .map(|column| polars::prelude::Field::new(column.name().clone(), column.dtype().clone()))
+                .collect()
+        }
+
+        /// A DataFrame without rows, with the columns of `schema`
+        pub fn empty_frame_with_schema(schema: &polars::prelude::Schema) -> polars::prelude::DataFrame {
+            #[cfg(feature = "polars_0_40")]
+            return polars::prelude::DataFrame::from(schema);
+
+            #[cfg(not(feature = "polars_0_40"))]
+            polars::prelude::DataFrame::empty_with_schema(schema)
         }
 
-        /// Convert RecordBatch to DataFrame using the appropriate Polars version
+        /// Select the named columns of a DataFrame, in the given order
+        pub fn select_columns(
+            df: &polars::prelude::DataFrame,
+            names: &[String],
+        ) -> Result<polars::prelude::DataFrame> {
+            Ok(df.select(names.iter().map(|name| name.as_str()))?)
+        }
+
+        /// Convert RecordBatch to DataFrame using the appropriate Polars version.
+        ///
+        /// Zero-row batches are skipped, except that a list of only zero-row
+        /// batches gives an empty frame with the columns of the first one. An empty
+        /// list gives a frame without columns.
         pub fn arrow_to_dataframe(batches: Vec<RecordBatch>) -> Result<polars::prelude::DataFrame> {
+            // The interchange reads the schema from the first batch, so it needs one
+            if batches.is_empty() {
+                return Ok(polars::prelude::DataFrame::empty());
+            }
+            let batches: Vec<RecordBatch> = if batches.iter().any(|batch| batch.num_rows() > 0) {
+                batches.into_iter().filter(|batch| batch.num_rows() > 0).collect()
+            } else {
+                batches.into_iter().take(1).collect()
+            };
+
             #[cfg(feature = "polars_0_40")]
             return Ok(Interchange::from_arrow_55(batches)?.to_polars_0_40()?);
 
@@ -150,17 +480,6 @@ macro_rules! impl_version_conversions {
 
             #[cfg(feature = "polars_0_50")]
             return Ok(Interchange::from_arrow_55(batches)?.to_polars_0_50()?);
-
-            // Fallback error if no feature is enabled
-            #[cfg(not(any(
-                feature = "polars_0_40", feature = "polars_0_41", feature = "polars_0_42",
-                feature = "polars_0_43", feature = "polars_0_44", feature = "polars_0_45",
-                feature = "polars_0_46", feature = "polars_0_47", feature = "polars_0_48",
-                feature = "polars_0_49", feature = "polars_0_50"
-            )))]
-            Err(PolarsSerdeError::ConversionError {
-                message: "No Polars version feature enabled. Please enable one of: polars-0-40, polars-0-41, ..., polars-0-50".to_string()
-            })
         }
     };
 }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{from_dataframe, to_dataframe, PolarsSerdeError};
  #[cfg(feature = "polars_0_40")]
     use polars_crate_0_40 as polars;
     
@@ -117,11 +117,7 @@ enum Priority {
     Critical,
 }
 
-// NOTE: Direct enum serialization has limitations with serde_arrow/polars interchange
-// For working enum support, see tests/enum_working.rs which shows the proper pattern
-
 #[test]
-#[ignore = "Direct enum serialization requires categorical feature - see enum_working.rs for working pattern"]
 fn test_enum_serialization() {
     let records = vec![
         EnumRecord {
@@ -148,16 +144,144 @@ fn test_enum_serialization() {
 
     let df = to_dataframe(&records).expect("Failed to convert enum records");
 
-    // Verify the DataFrame structure - enums should be string columns
-    println!("DataFrame schema:");
-    println!("{:?}", df.schema());
-    println!("DataFrame content:");
-    println!("{}", df);
+    // Fieldless enums are stored as string columns holding the variant names
+    assert_eq!(df.column("status").unwrap().dtype(), &polars::prelude::DataType::String);
+    let statuses: Vec<Option<&str>> = df.column("status").unwrap().str().unwrap().into_iter().collect();
+    assert_eq!(statuses, vec![Some("Pending"), Some("InProgress"), Some("Completed"), Some("Failed")]);
 
     let converted: Vec<EnumRecord> = from_dataframe(df).expect("Failed to convert back");
 
     assert_eq!(records, converted);
-    println!("✅ Enum serialization test passed! Enums are stored as strings in Polars.");
+}
+
+#[test]
+fn test_unknown_enum_variant_is_reported() {
+    use polars::prelude::*;
+
+    let df = df!(
+        "id" => [1i64, 2],
+        "status" => ["Pending", "Archived"],
+        "priority" => ["Low", "High"]
+    )
+    .unwrap();
+
+    match from_dataframe::<EnumRecord>(df) {
+        Err(PolarsSerdeError::UnknownVariant { column, value, expected }) => {
+            assert_eq!(column, "status");
+            assert_eq!(value, "Archived");
+            assert_eq!(expected, vec!["Pending", "InProgress", "Completed", "Failed"]);
+        }
+        other => panic!("expected UnknownVariant, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_enum_discriminant_columns() {
+    use polars::prelude::*;
+    use serde_polars::{from_dataframe_with, to_dataframe_with, ConversionOptions, EnumDiscriminant};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    #[repr(i32)]
+    enum Severity {
+        Debug = -1,
+        Info = 10,
+        Error = 40,
+    }
+
+    impl EnumDiscriminant for Severity {
+        fn discriminant(&self) -> i32 {
+            *self as i32
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct LogLine {
+        id: i64,
+        severity: Severity,
+        escalated_to: Option<Severity>,
+    }
+
+    let lines = vec![
+        LogLine { id: 1, severity: Severity::Info, escalated_to: None },
+        LogLine { id: 2, severity: Severity::Error, escalated_to: Some(Severity::Error) },
+        LogLine { id: 3, severity: Severity::Debug, escalated_to: Some(Severity::Info) },
+    ];
+
+    // Variant names by default
+    let df = to_dataframe(&lines).unwrap();
+    assert_eq!(df.column("severity").unwrap().dtype(), &DataType::String);
+
+    let options = ConversionOptions::new()
+        .enum_as_discriminant::<Severity>("severity".to_string())
+        .enum_as_discriminant::<Severity>("escalated_to".to_string());
+    let df = to_dataframe_with(&lines, &options).unwrap();
+    assert_eq!(df.column("severity").unwrap().dtype(), &DataType::Int32);
+    let severities: Vec<Option<i32>> = df.column("severity").unwrap().i32().unwrap().into_iter().collect();
+    assert_eq!(severities, vec![Some(10), Some(40), Some(-1)]);
+    assert_eq!(df.column("escalated_to").unwrap().null_count(), 1);
+
+    let converted: Vec<LogLine> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(converted, lines);
+
+    // Integers that are no discriminant are reported like unknown variant names
+    let df = df!(
+        "id" => [1i64, 2],
+        "severity" => [10i64, 30],
+        "escalated_to" => [None::<i32>, None]
+    )
+    .unwrap();
+    match from_dataframe_with::<LogLine>(df, &options) {
+        Err(PolarsSerdeError::UnknownVariant { column, value, expected }) => {
+            assert_eq!(column, "severity");
+            assert_eq!(value, "30");
+            assert_eq!(expected, vec!["-1", "10", "40"]);
+        }
+        other => panic!("expected UnknownVariant, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_boolean_columns_into_two_variant_enums() {
+    use polars::prelude::*;
+    use serde_polars::{from_dataframe_with, ConversionOptions};
+    use std::collections::HashMap;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    enum Toggle {
+        On,
+        Off,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Switch {
+        id: i64,
+        power: Toggle,
+        backlight: Option<Toggle>,
+    }
+
+    let df = df!(
+        "id" => [1i64, 2],
+        "power" => [true, false],
+        "backlight" => [None, Some(true)]
+    )
+    .unwrap();
+
+    // Without the mapping, booleans do not read as enum variants
+    assert!(from_dataframe::<Switch>(df.clone()).is_err());
+
+    let on_off = ("On".to_string(), "Off".to_string());
+    let options = ConversionOptions::new().bool_as_enum(HashMap::from([
+        ("power".to_string(), on_off.clone()),
+        ("backlight".to_string(), on_off),
+    ]));
+    let switches: Vec<Switch> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(
+        switches,
+        vec![
+            Switch { id: 1, power: Toggle::On, backlight: None },
+            Switch { id: 2, power: Toggle::Off, backlight: Some(Toggle::On) },
+        ]
+    );
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -413,3 +537,755 @@ fn test_enum_with_polars_created_values() {
 
     println!("✅ Enum deserialization works with Polars-created values!");
 }
+
+// The macro replaces the string-typed struct and `From` impls of the pattern above
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Stage {
+    Draft,
+    Review,
+    Approved,
+    Archived,
+}
+
+serde_polars::serde_polars_enum!(Stage { Draft, Review, Approved, Archived });
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Document {
+    id: i64,
+    stage: Stage,
+    previous_stage: Option<Stage>,
+}
+
+#[test]
+fn test_serde_polars_enum_macro() {
+    use polars::prelude::*;
+
+    let documents = vec![
+        Document { id: 1, stage: Stage::Draft, previous_stage: None },
+        Document { id: 2, stage: Stage::Review, previous_stage: Some(Stage::Draft) },
+        Document { id: 3, stage: Stage::Approved, previous_stage: Some(Stage::Review) },
+        Document { id: 4, stage: Stage::Archived, previous_stage: Some(Stage::Approved) },
+    ];
+
+    let df = to_dataframe(&documents).unwrap();
+    assert_eq!(df.column("stage").unwrap().dtype(), &DataType::String);
+    let stages: Vec<Option<&str>> = df.column("stage").unwrap().str().unwrap().into_iter().collect();
+    assert_eq!(stages, vec![Some("Draft"), Some("Review"), Some("Approved"), Some("Archived")]);
+
+    let converted: Vec<Document> = from_dataframe(df).unwrap();
+    assert_eq!(converted, documents);
+
+    let df = df!(
+        "id" => [1i64],
+        "stage" => ["Deleted"],
+        "previous_stage" => [None::<&str>]
+    )
+    .unwrap();
+    match from_dataframe::<Document>(df) {
+        Err(PolarsSerdeError::UnknownVariant { column, value, expected }) => {
+            assert_eq!(column, "stage");
+            assert_eq!(value, "Deleted");
+            assert_eq!(expected, vec!["Draft", "Review", "Approved", "Archived"]);
+        }
+        other => panic!("expected UnknownVariant, got {:?}", other),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct MeasurementRecord {
+    id: i64,
+    readings: std::collections::BTreeMap<String, f64>,
+}
+
+#[test]
+fn test_btree_map_as_sorted_struct_column() {
+    use polars::prelude::DataType;
+    use std::collections::BTreeMap;
+
+    // Insert keys out of order; BTreeMap iterates them sorted regardless
+    let mut first = BTreeMap::new();
+    first.insert("temperature".to_string(), 21.5);
+    first.insert("humidity".to_string(), 40.0);
+    first.insert("pressure".to_string(), 1013.2);
+
+    let mut second = BTreeMap::new();
+    second.insert("pressure".to_string(), 1009.8);
+    second.insert("temperature".to_string(), 19.0);
+    second.insert("humidity".to_string(), 55.5);
+
+    let records = vec![
+        MeasurementRecord { id: 1, readings: first },
+        MeasurementRecord { id: 2, readings: second },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert BTreeMap records");
+
+    match df.column("readings").unwrap().dtype() {
+        DataType::Struct(fields) => {
+            let names: Vec<String> = fields.iter().map(|f| f.name().to_string()).collect();
+            assert_eq!(names, vec!["humidity", "pressure", "temperature"]);
+        }
+        other => panic!("expected a Struct column, got {:?}", other),
+    }
+
+    let converted: Vec<MeasurementRecord> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct PathRecord {
+    id: i64,
+    points: Vec<Point>,
+}
+
+#[test]
+fn test_list_of_structs_with_empty_lists() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        PathRecord {
+            id: 1,
+            points: vec![Point { x: 0.0, y: 0.0 }, Point { x: 1.5, y: -2.0 }],
+        },
+        PathRecord { id: 2, points: vec![] },
+        PathRecord {
+            id: 3,
+            points: vec![Point { x: 3.0, y: 4.0 }],
+        },
+        PathRecord { id: 4, points: vec![] },
+        PathRecord {
+            id: 5,
+            points: vec![
+                Point { x: -1.0, y: 1.0 },
+                Point { x: 2.0, y: 2.0 },
+                Point { x: 9.5, y: 0.25 },
+            ],
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert list-of-struct records");
+
+    match df.column("points").unwrap().dtype() {
+        DataType::List(inner) => match inner.as_ref() {
+            DataType::Struct(fields) => {
+                let names: Vec<String> = fields.iter().map(|f| f.name().to_string()).collect();
+                assert_eq!(names, vec!["x", "y"]);
+            }
+            other => panic!("expected List<Struct>, got List<{:?}>", other),
+        },
+        other => panic!("expected a List column, got {:?}", other),
+    }
+
+    let converted: Vec<PathRecord> = from_dataframe(df.clone()).expect("Failed to convert back");
+    assert_eq!(records, converted);
+
+    // A slice starts at a non-zero list offset
+    let converted: Vec<PathRecord> =
+        from_dataframe(df.slice(1, 3)).expect("Failed to convert sliced frame back");
+    assert_eq!(&records[1..4], converted.as_slice());
+
+    // Every list empty, so the struct child arrays have no values at all
+    let records = vec![
+        PathRecord { id: 1, points: vec![] },
+        PathRecord { id: 2, points: vec![] },
+    ];
+    let df = to_dataframe(&records).expect("Failed to convert list-of-struct records");
+    let converted: Vec<PathRecord> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct OptionalNestedRecord {
+    id: i64,
+    readings: Option<Vec<i64>>,
+    labels: Option<Vec<String>>,
+    metadata: Option<MetadataRecord>,
+}
+
+#[test]
+fn test_optional_list_and_struct_fields() {
+    use polars::prelude::DataType;
+
+    let metadata = MetadataRecord {
+        created_by: "sensor".to_string(),
+        version: 2,
+        is_active: true,
+    };
+    let records = vec![
+        OptionalNestedRecord { id: 1, readings: None, labels: None, metadata: None },
+        OptionalNestedRecord {
+            id: 2,
+            readings: Some(vec![]),
+            labels: Some(vec![]),
+            metadata: Some(metadata.clone()),
+        },
+        OptionalNestedRecord {
+            id: 3,
+            readings: Some(vec![4, -8, 15]),
+            labels: Some(vec!["warm".to_string(), "humid".to_string()]),
+            metadata: None,
+        },
+        OptionalNestedRecord {
+            id: 4,
+            readings: None,
+            labels: Some(vec!["dry".to_string()]),
+            metadata: Some(MetadataRecord { is_active: false, ..metadata }),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert optional nested records");
+    assert!(matches!(df.column("readings").unwrap().dtype(), DataType::List(_)));
+    assert!(matches!(df.column("labels").unwrap().dtype(), DataType::List(_)));
+    assert!(matches!(df.column("metadata").unwrap().dtype(), DataType::Struct(_)));
+
+    // `None` is a null list, while `Some(vec![])` is an empty one
+    assert_eq!(df.column("readings").unwrap().null_count(), 2);
+    assert_eq!(df.column("labels").unwrap().null_count(), 1);
+    assert_eq!(df.column("metadata").unwrap().null_count(), 2);
+
+    let converted: Vec<OptionalNestedRecord> = from_dataframe(df.clone()).expect("Failed to convert back");
+    assert_eq!(records, converted);
+
+    let converted: Vec<OptionalNestedRecord> =
+        from_dataframe(df.slice(1, 3)).expect("Failed to convert sliced frame back");
+    assert_eq!(&records[1..], converted.as_slice());
+
+    // Without a single `Some`, the column types come from the record type alone
+    let records = vec![
+        OptionalNestedRecord { id: 1, readings: None, labels: None, metadata: None },
+        OptionalNestedRecord { id: 2, readings: None, labels: None, metadata: None },
+    ];
+    let df = to_dataframe(&records).expect("Failed to convert all-None records");
+    let converted: Vec<OptionalNestedRecord> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[test]
+fn test_required_structs_with_all_fields_none() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        low: Option<i64>,
+        high: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Calibration {
+        offset: Option<f64>,
+        limits: Limits,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Sensor {
+        id: i64,
+        limits: Limits,
+        calibration: Option<Calibration>,
+    }
+
+    // A struct whose fields are all `None` is not a null row unless its field is an `Option`
+    let unset = Limits { low: None, high: None };
+    let records = vec![
+        Sensor { id: 1, limits: unset.clone(), calibration: None },
+        Sensor {
+            id: 2,
+            limits: Limits { low: Some(-5), high: None },
+            calibration: Some(Calibration { offset: Some(1.5), limits: unset.clone() }),
+        },
+        Sensor {
+            id: 3,
+            limits: unset.clone(),
+            calibration: Some(Calibration { offset: Some(0.5), limits: Limits { low: None, high: Some(9) } }),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert sensors");
+    let converted: Vec<Sensor> = from_dataframe(df.clone()).expect("Failed to convert back");
+    assert_eq!(records, converted);
+
+    let converted: Vec<Sensor> = from_dataframe(df.slice(1, 2)).expect("Failed to convert sliced frame back");
+    assert_eq!(&records[1..], converted.as_slice());
+}
+
+#[test]
+fn test_polars_built_list_columns() {
+    use polars::prelude::*;
+
+    // Polars lists have 64-bit offsets, so they reach the deserializer as `LargeList`
+    let df = df!(
+        "id" => &[1i64, 2],
+        "track_ids" => &[Series::new("".into(), &[10i64, 11, 12]), Series::new("".into(), Vec::<i64>::new())],
+        "genres" => &[Some(Series::new("".into(), &["jazz", "soul"])), None],
+    )
+    .unwrap();
+    assert!(matches!(df.column("track_ids").unwrap().dtype(), DataType::List(_)));
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Playlist {
+        id: i64,
+        track_ids: Vec<i64>,
+        genres: Option<Vec<String>>,
+    }
+
+    let converted: Vec<Playlist> = from_dataframe(df).expect("Failed to convert Polars lists");
+    assert_eq!(
+        converted,
+        vec![
+            Playlist {
+                id: 1,
+                track_ids: vec![10, 11, 12],
+                genres: Some(vec!["jazz".to_string(), "soul".to_string()]),
+            },
+            Playlist { id: 2, track_ids: vec![], genres: None },
+        ]
+    );
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CompactStringRecord {
+    id: i64,
+    code: compact_str::CompactString,
+    label: smartstring::alias::String,
+    alias: Option<compact_str::CompactString>,
+    seen_on: chrono::NaiveDate,
+}
+
+#[test]
+fn test_optimized_string_types() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        CompactStringRecord {
+            id: 1,
+            code: "A1".into(),
+            label: "short label".into(),
+            alias: Some("first".into()),
+            seen_on: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        },
+        CompactStringRecord {
+            id: 2,
+            // Longer than the inline capacity, so both types allocate on the heap
+            code: "a code that is far too long to be stored inline".into(),
+            label: "a label that is far too long to be stored inline".into(),
+            alias: None,
+            seen_on: chrono::NaiveDate::from_ymd_opt(2024, 1, 2).unwrap(),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert optimized string records");
+    for column in ["code", "label", "alias"] {
+        assert_eq!(df.column(column).unwrap().dtype(), &DataType::String, "column {}", column);
+    }
+    assert_eq!(df.column("seen_on").unwrap().dtype(), &DataType::Date);
+
+    let converted: Vec<CompactStringRecord> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct BoxedStrRecord {
+    id: i64,
+    name: Box<str>,
+    nickname: Option<Box<str>>,
+    joined_on: chrono::NaiveDate,
+}
+
+#[test]
+fn test_boxed_str_fields() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        BoxedStrRecord {
+            id: 1,
+            name: "Ada".into(),
+            nickname: Some("countess".into()),
+            joined_on: chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+        },
+        BoxedStrRecord {
+            id: 2,
+            name: "2024-05-02".into(),
+            nickname: None,
+            joined_on: chrono::NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert Box<str> records");
+    // A string that looks like a date stays a string
+    assert_eq!(df.column("name").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("nickname").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("joined_on").unwrap().dtype(), &DataType::Date);
+
+    let converted: Vec<BoxedStrRecord> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+
+    // Like `Option<String>`, an optional field that is `None` throughout keeps its type
+    let df = to_dataframe(&records[1..]).expect("Failed to convert records without nicknames");
+    assert_eq!(df.column("nickname").unwrap().dtype(), &DataType::String);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct CowRecord<'a> {
+    name: std::borrow::Cow<'a, str>,
+    readings: std::borrow::Cow<'a, [i64]>,
+    note: Option<std::borrow::Cow<'a, str>>,
+}
+
+#[test]
+fn test_cow_fields() {
+    use polars::prelude::DataType;
+    use std::borrow::Cow;
+
+    let name = String::from("borrowed");
+    let readings = vec![1i64, 2, 3];
+    let records = vec![
+        CowRecord { name: Cow::Borrowed(&name), readings: Cow::Borrowed(&readings), note: None },
+        CowRecord {
+            name: Cow::Owned("owned".to_string()),
+            readings: Cow::Owned(vec![4, 5]),
+            note: Some(Cow::Borrowed("kept")),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert Cow records");
+    assert_eq!(df.column("name").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("readings").unwrap().dtype(), &DataType::List(Box::new(DataType::Int64)));
+    assert_eq!(df.column("note").unwrap().dtype(), &DataType::String);
+
+    // Deserialized records own their data
+    let converted: Vec<CowRecord<'static>> = from_dataframe(df).expect("Failed to convert Cow records back");
+    assert_eq!(converted, records);
+    assert!(converted.iter().all(|record| matches!(record.name, Cow::Owned(_))));
+    assert!(converted.iter().all(|record| matches!(record.readings, Cow::Owned(_))));
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Tagged<T> {
+    id: i64,
+    label: String,
+    _marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Meters;
+
+#[test]
+fn test_phantom_data_fields_have_no_column() {
+    use std::marker::PhantomData;
+
+    let records = vec![
+        Tagged::<Meters> { id: 1, label: "first".to_string(), _marker: PhantomData },
+        Tagged::<Meters> { id: 2, label: "second".to_string(), _marker: PhantomData },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert records with PhantomData");
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["id", "label"]);
+
+    let converted: Vec<Tagged<Meters>> = from_dataframe(df).expect("Failed to convert records with PhantomData back");
+    assert_eq!(converted, records);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct FailureInfo {
+    code: i32,
+    message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Attempt {
+    id: i64,
+    outcome: Result<f64, FailureInfo>,
+}
+
+#[test]
+fn test_result_fields_as_tagged_structs() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        Attempt { id: 1, outcome: Ok(0.5) },
+        Attempt { id: 2, outcome: Err(FailureInfo { code: 503, message: "unavailable".to_string() }) },
+        Attempt { id: 3, outcome: Ok(2.5) },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert Result fields");
+    let outcome = df.column("outcome").unwrap();
+    let DataType::Struct(fields) = outcome.dtype() else {
+        panic!("expected a struct column, got {}", outcome.dtype());
+    };
+    let names: Vec<String> = fields.iter().map(|field| field.name().to_string()).collect();
+    assert_eq!(names, vec!["variant", "Ok", "Err"]);
+
+    let unnested = df.clone().unnest(["outcome"]).unwrap();
+    let variants: Vec<Option<&str>> = unnested.column("variant").unwrap().str().unwrap().into_iter().collect();
+    assert_eq!(variants, vec![Some("Ok"), Some("Err"), Some("Ok")]);
+    let values: Vec<Option<f64>> = unnested.column("Ok").unwrap().f64().unwrap().into_iter().collect();
+    assert_eq!(values, vec![Some(0.5), None, Some(2.5)]);
+
+    let converted: Vec<Attempt> = from_dataframe(df).expect("Failed to convert Result fields back");
+    assert_eq!(converted, records);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Waypoint {
+    id: i64,
+    coords: [f64; 3],
+    grid_cell: Option<[i32; 2]>,
+}
+
+#[test]
+fn test_fixed_size_array_fields() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        Waypoint { id: 1, coords: [40.7, -74.0, 10.0], grid_cell: Some([3, 7]) },
+        Waypoint { id: 2, coords: [34.0, -118.2, 89.5], grid_cell: None },
+        Waypoint { id: 3, coords: [51.5, -0.1, 11.0], grid_cell: Some([0, -2]) },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert array records");
+    assert_eq!(
+        df.column("coords").unwrap().dtype(),
+        &DataType::Array(Box::new(DataType::Float64), 3)
+    );
+    assert_eq!(
+        df.column("grid_cell").unwrap().dtype(),
+        &DataType::Array(Box::new(DataType::Int32), 2)
+    );
+    assert_eq!(df.column("grid_cell").unwrap().null_count(), 1);
+
+    let converted: Vec<Waypoint> = from_dataframe(df.clone()).expect("Failed to convert back");
+    assert_eq!(records, converted);
+
+    let converted: Vec<Waypoint> = from_dataframe(df.slice(1, 2)).expect("Failed to convert sliced frame back");
+    assert_eq!(&records[1..], converted.as_slice());
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Attachment {
+    name: String,
+    #[serde(with = "serde_bytes")]
+    content: Vec<u8>,
+    checksum: Vec<u8>,
+}
+
+#[test]
+fn test_byte_fields_become_binary_columns() {
+    use polars::prelude::DataType;
+
+    let records = vec![
+        Attachment { name: "a.bin".to_string(), content: vec![0, 159, 146, 150], checksum: vec![7] },
+        Attachment { name: "empty.bin".to_string(), content: vec![], checksum: vec![] },
+        Attachment { name: "b.bin".to_string(), content: vec![255; 3], checksum: vec![1, 2] },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert byte records");
+    assert_eq!(df.column("content").unwrap().dtype(), &DataType::Binary);
+    // Without `serialize_bytes`, a `Vec<u8>` is a list of numbers
+    assert_eq!(df.column("checksum").unwrap().dtype(), &DataType::List(Box::new(DataType::UInt8)));
+
+    let converted: Vec<Attachment> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+struct CustomsCheck {
+    checked_at: chrono::NaiveDateTime,
+    officer_id: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+struct ShipmentInfo {
+    tracking_code: String,
+    shipped_on: chrono::NaiveDate,
+    customs_check: Option<CustomsCheck>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all_fields = "camelCase")]
+enum Delivery {
+    Courier { driver_name: String },
+    Pickup { store_code: i32 },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CustomerOrder {
+    order_id: i64,
+    shipment_info: ShipmentInfo,
+    delivery: Delivery,
+}
+
+#[test]
+fn test_renamed_nested_struct_fields() {
+    use polars::prelude::{DataType, Field, TimeUnit};
+
+    let day = |d| chrono::NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+    let records = vec![
+        CustomerOrder {
+            order_id: 1,
+            shipment_info: ShipmentInfo {
+                tracking_code: "TRK-1".to_string(),
+                shipped_on: day(4),
+                customs_check: Some(CustomsCheck { checked_at: day(5).and_hms_opt(9, 30, 0).unwrap(), officer_id: 7 }),
+            },
+            delivery: Delivery::Courier { driver_name: "Sam".to_string() },
+        },
+        CustomerOrder {
+            order_id: 2,
+            shipment_info: ShipmentInfo {
+                tracking_code: "TRK-2".to_string(),
+                shipped_on: day(6),
+                customs_check: None,
+            },
+            delivery: Delivery::Pickup { store_code: 12 },
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert renamed records");
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, ["orderId", "shipmentInfo", "delivery"]);
+
+    // Inner structs keep their own casing, and their dates become temporal types
+    let customs_check = DataType::Struct(vec![
+        Field::new("CHECKED_AT".into(), DataType::Datetime(TimeUnit::Nanoseconds, None)),
+        Field::new("OFFICER_ID".into(), DataType::Int32),
+    ]);
+    assert_eq!(
+        df.column("shipmentInfo").unwrap().dtype(),
+        &DataType::Struct(vec![
+            Field::new("TRACKING_CODE".into(), DataType::String),
+            Field::new("SHIPPED_ON".into(), DataType::Date),
+            Field::new("CUSTOMS_CHECK".into(), customs_check),
+        ])
+    );
+    let DataType::Struct(delivery) = df.column("delivery").unwrap().dtype() else {
+        panic!("expected a struct column for the enum");
+    };
+    assert_eq!(
+        delivery[1],
+        Field::new("Courier".into(), DataType::Struct(vec![Field::new("driverName".into(), DataType::String)]))
+    );
+
+    let converted: Vec<CustomerOrder> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[test]
+fn test_nested_dates_found_past_records_without_them() {
+    use polars::prelude::{DataType, Field, TimeUnit};
+
+    let day = |d| chrono::NaiveDate::from_ymd_opt(2024, 3, d).unwrap();
+    let shipment = |id, customs_check, delivery| CustomerOrder {
+        order_id: id,
+        shipment_info: ShipmentInfo { tracking_code: format!("TRK-{}", id), shipped_on: day(4), customs_check },
+        delivery,
+    };
+    let checked_at = day(5).and_hms_opt(9, 30, 0).unwrap();
+    // Only the last record has the inner struct that holds a datetime
+    let records = vec![
+        shipment(1, None, Delivery::Courier { driver_name: "Sam".to_string() }),
+        shipment(2, None, Delivery::Pickup { store_code: 12 }),
+        shipment(3, Some(CustomsCheck { checked_at, officer_id: 7 }), Delivery::Pickup { store_code: 4 }),
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert records");
+    let DataType::Struct(fields) = df.column("shipmentInfo").unwrap().dtype() else {
+        panic!("expected a struct column");
+    };
+    assert_eq!(
+        fields[2],
+        Field::new(
+            "CUSTOMS_CHECK".into(),
+            DataType::Struct(vec![
+                Field::new("CHECKED_AT".into(), DataType::Datetime(TimeUnit::Nanoseconds, None)),
+                Field::new("OFFICER_ID".into(), DataType::Int32),
+            ])
+        )
+    );
+
+    let converted: Vec<CustomerOrder> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(records, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct SensorEvent {
+    id: i64,
+    name: String,
+    #[serde(flatten)]
+    readings: std::collections::BTreeMap<String, Option<f64>>,
+}
+
+#[test]
+fn test_flattened_map_keys_become_columns() {
+    use std::collections::BTreeMap;
+
+    let records = vec![
+        SensorEvent {
+            id: 1,
+            name: "boiler".to_string(),
+            readings: BTreeMap::from([("temp".to_string(), Some(71.5))]),
+        },
+        SensorEvent {
+            id: 2,
+            name: "pump".to_string(),
+            readings: BTreeMap::from([("temp".to_string(), None), ("flow".to_string(), Some(3.25))]),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert records with a flattened map");
+    assert_eq!(df.get_column_names(), ["flow", "id", "name", "temp"]);
+    assert_eq!(df.column("flow").unwrap().null_count(), 1);
+
+    // Keys a record did not have read back as `None`
+    let converted: Vec<SensorEvent> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(converted[0].readings.get("flow"), Some(&None));
+    assert_eq!(converted[1], records[1]);
+}
+
+#[test]
+fn test_flattened_map_keys_changing_between_iterator_chunks() {
+    use serde_polars::to_dataframe_iter;
+    use std::collections::BTreeMap;
+
+    // More records than one chunk of the iterator holds, with the key changing after them
+    let event = |id: i64| SensorEvent {
+        id,
+        name: format!("sensor-{}", id),
+        readings: BTreeMap::from([(if id < 8_192 { "b" } else { "c" }.to_string(), Some(id as f64))]),
+    };
+    let records: Vec<SensorEvent> = (0..8_202).map(event).collect();
+
+    let df = to_dataframe_iter(records.iter().cloned()).expect("Failed to convert an iterator");
+    assert_eq!(df.get_column_names(), ["b", "c", "id", "name"]);
+    assert_eq!(df, to_dataframe(&records).unwrap());
+    assert_eq!(df.column("b").unwrap().null_count(), 10);
+    assert_eq!(df.column("c").unwrap().null_count(), 8_192);
+}
+
+#[test]
+fn test_flattened_map_with_mixed_value_types_is_rejected() {
+    #[derive(Debug, Serialize, Deserialize)]
+    struct LooseEvent {
+        id: i64,
+        #[serde(flatten)]
+        extra: std::collections::HashMap<String, serde_json::Value>,
+    }
+
+    let records = vec![
+        LooseEvent { id: 1, extra: [("code".to_string(), serde_json::json!(7))].into() },
+        LooseEvent { id: 2, extra: [("code".to_string(), serde_json::json!("E7"))].into() },
+    ];
+
+    match to_dataframe(&records) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("#[serde(flatten)]"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other.map(|df| df.shape())),
+    }
+}
@@ -0,0 +1,167 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize, Serializer};
+use serde_polars::{from_dataframe, to_dataframe, Converter, PolarsSerdeError};
+
+#[cfg(feature = "polars_0_40")]
+use polars_crate_0_40 as polars;
+
+#[cfg(feature = "polars_0_41")]
+use polars_crate_0_41 as polars;
+
+#[cfg(feature = "polars_0_42")]
+use polars_crate_0_42 as polars;
+
+#[cfg(feature = "polars_0_43")]
+use polars_crate_0_43 as polars;
+
+#[cfg(feature = "polars_0_44")]
+use polars_crate_0_44 as polars;
+
+#[cfg(feature = "polars_0_45")]
+use polars_crate_0_45 as polars;
+
+#[cfg(feature = "polars_0_46")]
+use polars_crate_0_46 as polars;
+
+#[cfg(feature = "polars_0_47")]
+use polars_crate_0_47 as polars;
+
+#[cfg(feature = "polars_0_48")]
+use polars_crate_0_48 as polars;
+
+#[cfg(feature = "polars_0_49")]
+use polars_crate_0_49 as polars;
+
+#[cfg(feature = "polars_0_50")]
+use polars_crate_0_50 as polars;
+
+use polars::prelude::DataType;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Request {
+    id: i64,
+    day: NaiveDate,
+    received_at: DateTime<Utc>,
+    path: String,
+    referrer: Option<String>,
+    status: Option<u16>,
+}
+
+fn requests(first_id: i64, count: i64) -> Vec<Request> {
+    let day = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    (first_id..first_id + count)
+        .map(|id| Request {
+            id,
+            day,
+            received_at: day.and_hms_opt(8, 0, id as u32 % 60).unwrap().and_utc(),
+            path: format!("/items/{}", id),
+            referrer: (id % 2 == 0).then(|| "https://example.com".to_string()),
+            status: Some(200),
+        })
+        .collect()
+}
+
+#[test]
+fn test_converter_matches_to_dataframe() {
+    let converter = Converter::<Request>::new();
+
+    for first_id in [0, 10, 20] {
+        let batch = requests(first_id, 10);
+        let df = converter.convert(&batch).unwrap();
+        let expected = to_dataframe(&batch).unwrap();
+        assert_eq!(df.schema(), expected.schema());
+        assert_eq!(df.column("day").unwrap().dtype(), &DataType::Date);
+
+        let converted: Vec<Request> = from_dataframe(df).unwrap();
+        assert_eq!(converted, batch);
+    }
+}
+
+#[test]
+fn test_converter_keeps_column_types_between_batches() {
+    let converter = Converter::<Request>::new();
+    let typed = converter.convert(&requests(0, 4)).unwrap();
+
+    // On its own, a batch without statuses has a `Null` column for them
+    let mut quiet = requests(10, 3);
+    for request in &mut quiet {
+        request.referrer = None;
+        request.status = None;
+    }
+    let df = converter.convert(&quiet).unwrap();
+    assert_eq!(df.schema(), typed.schema());
+    let converted: Vec<Request> = from_dataframe(df).unwrap();
+    assert_eq!(converted, quiet);
+}
+
+#[test]
+fn test_converter_retraces_batches_that_do_not_fit() {
+    let converter = Converter::<Request>::new();
+
+    let mut quiet = requests(0, 3);
+    for request in &mut quiet {
+        request.referrer = None;
+    }
+    converter.convert(&quiet).unwrap();
+
+    let busy = requests(10, 3);
+    let df = converter.convert(&busy).unwrap();
+    assert_eq!(df.column("referrer").unwrap().dtype(), &DataType::String);
+    let converted: Vec<Request> = from_dataframe(df).unwrap();
+    assert_eq!(converted, busy);
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Reading(f64);
+
+impl Serialize for Reading {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0.is_nan() {
+            return Err(serde::ser::Error::custom("reading is not a number"));
+        }
+        serializer.serialize_f64(self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Sample {
+    sensor: i32,
+    reading: Reading,
+}
+
+#[test]
+fn test_converter_errors() {
+    let converter = Converter::<Sample>::new();
+    assert!(matches!(converter.convert(&[]), Err(PolarsSerdeError::EmptyInput)));
+
+    let good = vec![Sample { sensor: 1, reading: Reading(0.5) }, Sample { sensor: 2, reading: Reading(1.5) }];
+    converter.convert(&good).unwrap();
+
+    let bad = vec![Sample { sensor: 3, reading: Reading(f64::NAN) }];
+    let error = converter.convert(&bad).unwrap_err();
+    assert!(error.to_string().contains("reading is not a number"));
+
+    // A failed batch leaves nothing behind for the next one
+    let df = converter.convert(&good).unwrap();
+    let converted: Vec<Sample> = from_dataframe(df).unwrap();
+    assert_eq!(converted, good);
+}
+
+#[test]
+fn test_converter_shared_between_threads() {
+    let converter = Converter::<Request>::new();
+
+    std::thread::scope(|scope| {
+        for thread in 0..4 {
+            let converter = &converter;
+            scope.spawn(move || {
+                for round in 0..20 {
+                    let batch = requests(thread * 1000 + round * 10, 5);
+                    let df = converter.convert(&batch).unwrap();
+                    let converted: Vec<Request> = from_dataframe(df).unwrap();
+                    assert_eq!(converted, batch);
+                }
+            });
+        }
+    });
+}
@@ -0,0 +1,93 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_polars::{from_dataframe, DataFrameBuilder, PolarsSerdeError};
+
+#[cfg(feature = "polars_0_40")]
+use polars_crate_0_40 as polars;
+
+#[cfg(feature = "polars_0_41")]
+use polars_crate_0_41 as polars;
+
+#[cfg(feature = "polars_0_42")]
+use polars_crate_0_42 as polars;
+
+#[cfg(feature = "polars_0_43")]
+use polars_crate_0_43 as polars;
+
+#[cfg(feature = "polars_0_44")]
+use polars_crate_0_44 as polars;
+
+#[cfg(feature = "polars_0_45")]
+use polars_crate_0_45 as polars;
+
+#[cfg(feature = "polars_0_46")]
+use polars_crate_0_46 as polars;
+
+#[cfg(feature = "polars_0_47")]
+use polars_crate_0_47 as polars;
+
+#[cfg(feature = "polars_0_48")]
+use polars_crate_0_48 as polars;
+
+#[cfg(feature = "polars_0_49")]
+use polars_crate_0_49 as polars;
+
+#[cfg(feature = "polars_0_50")]
+use polars_crate_0_50 as polars;
+
+use polars::prelude::DataType;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LogEntry {
+    line: i64,
+    day: NaiveDate,
+    logged_at: DateTime<Utc>,
+    message: String,
+}
+
+fn parse_line(line: i64, text: &str) -> Option<LogEntry> {
+    let (day, message) = text.split_once(' ')?;
+    let day = NaiveDate::parse_from_str(day, "%Y-%m-%d").ok()?;
+    Some(LogEntry {
+        line,
+        day,
+        logged_at: day.and_hms_opt(12, 0, 0)?.and_utc(),
+        message: message.to_string(),
+    })
+}
+
+#[test]
+fn test_collect_iterator_chain_into_builder() {
+    let log = ["2024-01-01 started", "garbage", "2024-01-02 running", "2024-01-03 stopped"];
+
+    let df = log
+        .iter()
+        .zip(1..)
+        .filter_map(|(text, line)| parse_line(line, text))
+        .collect::<DataFrameBuilder<LogEntry>>()
+        .build()
+        .unwrap();
+
+    assert_eq!(df.height(), 3);
+    assert_eq!(df.column("day").unwrap().dtype(), &DataType::Date);
+    assert!(matches!(df.column("logged_at").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+
+    let entries: Vec<LogEntry> = from_dataframe(df).unwrap();
+    let lines: Vec<i64> = entries.iter().map(|entry| entry.line).collect();
+    assert_eq!(lines, vec![1, 3, 4]);
+    assert_eq!(entries[2].message, "stopped");
+}
+
+#[test]
+fn test_extend_builder_in_steps() {
+    let mut builder = DataFrameBuilder::new();
+    assert!(builder.is_empty());
+    assert!(matches!(builder.build(), Err(PolarsSerdeError::EmptyInput)));
+
+    builder.push(parse_line(1, "2024-02-01 first").unwrap());
+    builder.extend((2..=4).filter_map(|line| parse_line(line, "2024-02-02 more")));
+    assert_eq!(builder.len(), 4);
+
+    let df = builder.build().unwrap();
+    assert_eq!(df.height(), 4);
+}
@@ -1,6 +1,6 @@
 use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{from_dataframe, from_dataframe_with, to_dataframe, to_dataframe_with, ConversionOptions};
 
 #[cfg(feature = "polars_0_40")]
 use polars_crate_0_40 as polars;
@@ -321,4 +321,375 @@ fn test_edge_case_empty_and_single_record() {
         Ok(_) => panic!("Empty vector should not create a valid DataFrame"),
         Err(_) => println!("✓ Empty vector correctly rejected"),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_edge_case_null_dtype_column() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct SparseRecord {
+        id: i64,
+        parent_id: Option<i64>,
+    }
+
+    // A column built without any type information has Polars dtype `Null`
+    let mut df = df! {
+        "id" => [1i64, 2i64, 3i64],
+    }.unwrap();
+    df.with_column(Series::new_null("parent_id".into(), 3)).unwrap();
+    assert_eq!(df.column("parent_id").unwrap().dtype(), &DataType::Null);
+
+    let records: Vec<SparseRecord> = from_dataframe(df).unwrap();
+    assert_eq!(records, vec![
+        SparseRecord { id: 1, parent_id: None },
+        SparseRecord { id: 2, parent_id: None },
+        SparseRecord { id: 3, parent_id: None },
+    ]);
+}
+
+#[test]
+fn test_edge_case_all_null_optional_string_keeps_string_dtype() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct NoteRecord {
+        id: i64,
+        created_on: NaiveDate,
+        note: Option<String>,
+    }
+
+    // The chrono field forces sample-based tracing, which sees no strings in `note`
+    let records: Vec<NoteRecord> = (1..=3)
+        .map(|i| NoteRecord {
+            id: i,
+            created_on: NaiveDate::from_ymd_opt(2024, 1, i as u32).unwrap(),
+            note: None,
+        })
+        .collect();
+
+    let df = to_dataframe(&records).unwrap();
+    assert_eq!(df.column("note").unwrap().dtype(), &DataType::String);
+
+    // A second round trip must produce the same schema
+    let roundtripped: Vec<NoteRecord> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, records);
+    let df = to_dataframe(&roundtripped).unwrap();
+    assert_eq!(df.column("note").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("note").unwrap().null_count(), 3);
+}
+
+#[test]
+fn test_edge_case_nan_survives_chrono_conversion() {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct MeasuredOn {
+        taken_on: NaiveDate,
+        value: f64,
+        label: String,
+    }
+
+    let records = vec![
+        MeasuredOn {
+            taken_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            value: f64::NAN,
+            label: "missing".to_string(),
+        },
+        MeasuredOn {
+            taken_on: NaiveDate::from_ymd_opt(2024, 3, 2).unwrap(),
+            value: 1.25,
+            label: "ok".to_string(),
+        },
+    ];
+
+    let df = to_dataframe(&records).unwrap();
+    assert_eq!(df.column("taken_on").unwrap().dtype(), &DataType::Date);
+    assert_eq!(df.column("value").unwrap().dtype(), &DataType::Float64);
+
+    // NaN is a value, not a missing entry
+    assert_eq!(df.column("value").unwrap().null_count(), 0);
+    let values: Vec<Option<f64>> = df.column("value").unwrap().f64().unwrap().into_iter().collect();
+    assert!(values[0].unwrap().is_nan());
+    assert_eq!(values[1], Some(1.25));
+
+    let converted: Vec<MeasuredOn> = from_dataframe(df).unwrap();
+    assert_eq!(converted.len(), 2);
+    assert_eq!(converted[0].taken_on, records[0].taken_on);
+    assert!(converted[0].value.is_nan());
+    assert_eq!(converted[1].taken_on, records[1].taken_on);
+    assert_eq!(converted[1].value, 1.25);
+    assert_eq!(converted[1].label, "ok");
+}
+
+#[test]
+fn test_optional_floats_keep_null_and_nan_apart() {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Sample {
+        id: i64,
+        value: Option<f64>,
+        small: Option<f32>,
+    }
+
+    let records = vec![
+        Sample { id: 1, value: None, small: None },
+        Sample { id: 2, value: Some(f64::NAN), small: Some(f32::NAN) },
+        Sample { id: 3, value: Some(1.0), small: Some(1.0) },
+    ];
+
+    // Rewriting infinities must not touch NaN or null entries either
+    for options in [ConversionOptions::new(), ConversionOptions::new().infinity_as_null(true)] {
+        let df = to_dataframe_with(&records, &options).unwrap();
+        assert_eq!(df.column("value").unwrap().null_count(), 1);
+        assert_eq!(df.column("small").unwrap().null_count(), 1);
+
+        let values: Vec<Option<f64>> = df.column("value").unwrap().f64().unwrap().into_iter().collect();
+        assert_eq!(values[0], None);
+        assert!(values[1].unwrap().is_nan());
+        assert_eq!(values[2], Some(1.0));
+        let small: Vec<Option<f32>> = df.column("small").unwrap().f32().unwrap().into_iter().collect();
+        assert_eq!(small[0], None);
+        assert!(small[1].unwrap().is_nan());
+        assert_eq!(small[2], Some(1.0));
+
+        let converted: Vec<Sample> = from_dataframe(df).unwrap();
+        assert_eq!(converted[0].value, None);
+        assert!(converted[1].value.unwrap().is_nan());
+        assert!(converted[1].small.unwrap().is_nan());
+        assert_eq!(converted[2].value, Some(1.0));
+    }
+}
+
+#[test]
+fn test_edge_case_special_column_names() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct OddlyNamed {
+        #[serde(rename = "a.b.c")]
+        dotted: i64,
+        #[serde(rename = "1col")]
+        leading_digit: String,
+        #[serde(rename = "event.date")]
+        event_date: NaiveDate,
+        #[serde(rename = "created at (UTC)")]
+        created_at: Option<DateTime<Utc>>,
+    }
+
+    let records = vec![
+        OddlyNamed {
+            dotted: 1,
+            leading_digit: "first".to_string(),
+            event_date: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+            created_at: Some(DateTime::from_timestamp(1_714_521_600, 0).unwrap()),
+        },
+        OddlyNamed {
+            dotted: 2,
+            leading_digit: "second".to_string(),
+            event_date: NaiveDate::from_ymd_opt(2024, 5, 2).unwrap(),
+            created_at: None,
+        },
+    ];
+
+    let df = to_dataframe(&records).unwrap();
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["a.b.c", "1col", "event.date", "created at (UTC)"]);
+    assert_eq!(df.column("event.date").unwrap().dtype(), &DataType::Date);
+    assert!(matches!(df.column("created at (UTC)").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+
+    let converted: Vec<OddlyNamed> = from_dataframe(df).unwrap();
+    assert_eq!(converted, records);
+}
+
+#[test]
+fn test_skip_serializing_if_fields_are_filled_with_nulls() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Tagged {
+        id: i64,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        tags: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        note: Option<String>,
+    }
+
+    // Records that skip a non-optional field get a null in its column
+    let records = vec![
+        Tagged { id: 1, tags: vec![], note: None },
+        Tagged { id: 2, tags: vec!["a".to_string(), "b".to_string()], note: Some("kept".to_string()) },
+    ];
+    let df = to_dataframe(&records).unwrap();
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["id", "tags", "note"]);
+    assert_eq!(df.column("tags").unwrap().null_count(), 1);
+    assert_eq!(df.column("note").unwrap().null_count(), 1);
+
+    // The skipped field reads back as its `Default` value
+    let converted: Vec<Tagged> = from_dataframe(df).unwrap();
+    assert_eq!(converted, records);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Visit {
+        day: NaiveDate,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        referrer: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        score: Option<f64>,
+    }
+
+    // Traced from the records (for the chrono field), a field skipped in every
+    // record still gets a column, typed `Null` as no value shows its type
+    let day = NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    let visits = vec![
+        Visit { day, referrer: None, score: None },
+        Visit { day, referrer: None, score: Some(0.5) },
+    ];
+    let df = to_dataframe(&visits).unwrap();
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["day", "referrer", "score"]);
+    assert_eq!(df.column("referrer").unwrap().dtype(), &DataType::Null);
+    assert_eq!(df.column("score").unwrap().null_count(), 1);
+
+    let converted: Vec<Visit> = from_dataframe(df).unwrap();
+    assert_eq!(converted, visits);
+}
+
+#[test]
+fn test_infinity_as_null() {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Ratio {
+        id: i64,
+        value: f64,
+        small: f32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct MaybeRatio {
+        id: i64,
+        value: Option<f64>,
+        small: Option<f32>,
+    }
+
+    let records = vec![
+        Ratio { id: 1, value: f64::INFINITY, small: 0.5 },
+        Ratio { id: 2, value: f64::NAN, small: f32::NEG_INFINITY },
+        Ratio { id: 3, value: 2.5, small: 1.5 },
+    ];
+
+    // By default infinities are stored as they are
+    let df = to_dataframe(&records).unwrap();
+    assert_eq!(df.column("value").unwrap().null_count(), 0);
+
+    let options = ConversionOptions::new().infinity_as_null(true);
+    let df = to_dataframe_with(&records, &options).unwrap();
+    assert_eq!(df.column("value").unwrap().null_count(), 1);
+    assert_eq!(df.column("small").unwrap().null_count(), 1);
+
+    // NaN stays a value; only infinities become missing
+    let converted: Vec<MaybeRatio> = from_dataframe(df).unwrap();
+    assert_eq!(converted[0].value, None);
+    assert!(converted[1].value.unwrap().is_nan());
+    assert_eq!(converted[1].small, None);
+    assert_eq!(converted[2], MaybeRatio { id: 3, value: Some(2.5), small: Some(1.5) });
+}
+
+#[test]
+fn test_null_float_as_nan() {
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    struct Measurement {
+        id: i64,
+        value: f64,
+        small: f32,
+        offset: Option<f64>,
+    }
+
+    let df = df! {
+        "id" => [1i64, 2, 3],
+        "value" => [Some(1.0f64), None, Some(3.0)],
+        "small" => [None, Some(0.5f32), Some(1.5)],
+        "offset" => [Some(0.1f64), None, None],
+    }
+    .unwrap();
+
+    // By default a null cannot be read into a plain float field
+    assert!(from_dataframe::<Measurement>(df.clone()).is_err());
+
+    let options = ConversionOptions::new().null_float_as_nan(true);
+    let converted: Vec<Measurement> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(converted[0].value, 1.0);
+    assert!(converted[1].value.is_nan());
+    assert_eq!(converted[2].value, 3.0);
+    assert!(converted[0].small.is_nan());
+    assert_eq!(converted[1].small, 0.5);
+
+    // `Option` fields still read nulls as `None`
+    assert_eq!(converted[0].offset, Some(0.1));
+    assert_eq!(converted[1].offset, None);
+}
+
+#[test]
+fn test_fields_by_position_only_when_enabled() {
+    use serde::de::{self, MapAccess, SeqAccess, Visitor};
+
+    // Reads the fields of a sequence in the reverse of their declared order
+    #[derive(Debug, Clone, PartialEq, Serialize)]
+    struct Range {
+        low: i64,
+        high: i64,
+    }
+
+    impl<'de> Deserialize<'de> for Range {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            struct RangeVisitor;
+
+            impl<'de> Visitor<'de> for RangeVisitor {
+                type Value = Range;
+
+                fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    formatter.write_str("a range")
+                }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Range, A::Error> {
+                    let high = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                    let low = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                    Ok(Range { low, high })
+                }
+
+                fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Range, A::Error> {
+                    let (mut low, mut high) = (None, None);
+                    while let Some(key) = map.next_key::<String>()? {
+                        match key.as_str() {
+                            "low" => low = Some(map.next_value()?),
+                            "high" => high = Some(map.next_value()?),
+                            _ => {
+                                map.next_value::<de::IgnoredAny>()?;
+                            }
+                        }
+                    }
+                    Ok(Range {
+                        low: low.ok_or_else(|| de::Error::missing_field("low"))?,
+                        high: high.ok_or_else(|| de::Error::missing_field("high"))?,
+                    })
+                }
+            }
+
+            deserializer.deserialize_struct("Range", &["low", "high"], RangeVisitor)
+        }
+    }
+
+    let ranges = vec![Range { low: 1, high: 10 }, Range { low: -5, high: 5 }];
+    let df = to_dataframe(&ranges).unwrap();
+
+    // Fields are read by name unless positional reads are enabled
+    let converted: Vec<Range> = from_dataframe(df).unwrap();
+    assert_eq!(ranges, converted);
+
+    // Derived impls read a sequence in field order, whatever the column order
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Span {
+        low: i64,
+        high: i64,
+        label: Option<String>,
+    }
+
+    let spans = vec![
+        Span { low: 1, high: 10, label: Some("a".to_string()) },
+        Span { low: -5, high: 5, label: None },
+    ];
+    let df = to_dataframe(&spans).unwrap();
+    let df = df.select(["label", "high", "low"]).unwrap();
+    let options = ConversionOptions::new().read_fields_by_position(true);
+    let converted: Vec<Span> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(spans, converted);
+}
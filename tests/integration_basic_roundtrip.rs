@@ -1,5 +1,9 @@
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{
+    from_dataframe, from_dataframe_ref, from_series, to_dataframe, to_dataframe_iter, to_dataframe_refs, to_series,
+};
+#[cfg(feature = "ipc")]
+use serde_polars::{read_ipc, write_ipc};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct BasicRecord {
@@ -47,6 +51,29 @@ fn test_basic_roundtrip() {
     assert_eq!(original_records, converted_records);
 }
 
+#[test]
+fn test_borrowed_records_to_dataframe() {
+    let records: Vec<BasicRecord> = (0..6)
+        .map(|i| BasicRecord {
+            id: i,
+            name: format!("user_{}", i),
+            score: i as f64 * 10.0,
+            active: i % 2 == 0,
+        })
+        .collect();
+
+    let active: Vec<&BasicRecord> = records.iter().filter(|record| record.active).collect();
+    let df = to_dataframe_refs(&active).expect("Failed to convert borrowed records");
+    assert_eq!(df.height(), 3);
+
+    let converted: Vec<BasicRecord> = from_dataframe(df).unwrap();
+    let expected: Vec<BasicRecord> = active.into_iter().cloned().collect();
+    assert_eq!(converted, expected);
+
+    let none: Vec<&BasicRecord> = Vec::new();
+    assert!(to_dataframe_refs(&none).is_err());
+}
+
 #[test]
 fn test_large_dataset() {
     let large_records: Vec<BasicRecord> = (0..1000)
@@ -65,3 +92,474 @@ fn test_large_dataset() {
         from_dataframe(df).expect("Failed to convert large dataset back");
     assert_eq!(large_records, converted);
 }
+
+#[test]
+fn test_column_order_independence() {
+    #[cfg(feature = "polars_0_40")]
+    use polars_crate_0_40 as polars;
+
+    #[cfg(feature = "polars_0_41")]
+    use polars_crate_0_41 as polars;
+
+    #[cfg(feature = "polars_0_42")]
+    use polars_crate_0_42 as polars;
+
+    #[cfg(feature = "polars_0_43")]
+    use polars_crate_0_43 as polars;
+
+    #[cfg(feature = "polars_0_44")]
+    use polars_crate_0_44 as polars;
+
+    #[cfg(feature = "polars_0_45")]
+    use polars_crate_0_45 as polars;
+
+    #[cfg(feature = "polars_0_46")]
+    use polars_crate_0_46 as polars;
+
+    #[cfg(feature = "polars_0_47")]
+    use polars_crate_0_47 as polars;
+
+    #[cfg(feature = "polars_0_48")]
+    use polars_crate_0_48 as polars;
+
+    #[cfg(feature = "polars_0_49")]
+    use polars_crate_0_49 as polars;
+
+    #[cfg(feature = "polars_0_50")]
+    use polars_crate_0_50 as polars;
+
+    use polars::prelude::*;
+
+    let original_records = vec![
+        BasicRecord {
+            id: 1,
+            name: "Alice".to_string(),
+            score: 85.5,
+            active: true,
+        },
+        BasicRecord {
+            id: 2,
+            name: "Bob".to_string(),
+            score: 92.0,
+            active: false,
+        },
+    ];
+
+    // Reorder the columns the way a join might, so none match the struct's field order
+    let df = to_dataframe(&original_records).expect("Failed to convert to DataFrame");
+    let reordered = df
+        .select(["active", "name", "score", "id"])
+        .expect("Failed to reorder columns");
+
+    let converted_records: Vec<BasicRecord> =
+        from_dataframe(reordered).expect("Failed to convert reordered DataFrame");
+    assert_eq!(original_records, converted_records);
+
+    // A frame built in Polars with its own column order also works
+    let df = df! {
+        "name" => ["Carol"],
+        "active" => [true],
+        "id" => [3i64],
+        "score" => [70.25],
+    }
+    .unwrap();
+    let converted_records: Vec<BasicRecord> =
+        from_dataframe(df).expect("Failed to convert Polars-built DataFrame");
+    assert_eq!(
+        converted_records,
+        vec![BasicRecord {
+            id: 3,
+            name: "Carol".to_string(),
+            score: 70.25,
+            active: true,
+        }]
+    );
+}
+
+#[test]
+fn test_single_and_multi_chunk_frames_match() {
+    let records: Vec<BasicRecord> = (0..10)
+        .map(|i| BasicRecord {
+            id: i,
+            name: format!("User_{}", i),
+            score: (i as f64) * 1.5,
+            active: i % 3 == 0,
+        })
+        .collect();
+
+    // Stacking two frames leaves two chunks, which are merged before the export
+    let mut chunked = to_dataframe(&records[..4]).expect("Failed to convert first half");
+    chunked
+        .vstack_mut(&to_dataframe(&records[4..]).expect("Failed to convert second half"))
+        .expect("Failed to stack frames");
+
+    let single = to_dataframe(&records).expect("Failed to convert to DataFrame");
+
+    let from_chunked: Vec<BasicRecord> =
+        from_dataframe(chunked).expect("Failed to convert chunked DataFrame");
+    let from_single: Vec<BasicRecord> =
+        from_dataframe(single).expect("Failed to convert single-chunk DataFrame");
+    assert_eq!(from_single, from_chunked);
+    assert_eq!(from_single, records);
+}
+
+#[test]
+fn test_multi_chunk_frames_keep_row_order() {
+    let record = |i: i64| BasicRecord {
+        id: i,
+        name: format!("User_{}", i),
+        score: (i as f64) * 0.5,
+        active: i % 2 == 0,
+    };
+    // Uneven chunks whose ids run backwards, so sorting would change the order
+    let chunks: Vec<Vec<BasicRecord>> = vec![
+        (6..9).rev().map(record).collect(),
+        vec![record(5)],
+        (0..5).rev().map(record).collect(),
+    ];
+
+    let mut df = to_dataframe(&chunks[0]).expect("Failed to convert first chunk");
+    for chunk in &chunks[1..] {
+        df.vstack_mut(&to_dataframe(chunk).expect("Failed to convert chunk"))
+            .expect("Failed to stack frames");
+    }
+    assert_eq!(df.column("id").unwrap().n_chunks(), 3);
+
+    let frame_ids: Vec<i64> = df.column("id").unwrap().i64().unwrap().into_no_null_iter().collect();
+    let converted: Vec<BasicRecord> = from_dataframe(df).expect("Failed to convert multi-chunk DataFrame");
+    let converted_ids: Vec<i64> = converted.iter().map(|record| record.id).collect();
+    assert_eq!(converted_ids, frame_ids);
+    assert_eq!(converted, chunks.concat());
+}
+
+#[test]
+fn test_borrowed_frame_is_left_unchanged() {
+    let records: Vec<BasicRecord> = (0..6)
+        .map(|i| BasicRecord {
+            id: i,
+            name: format!("User_{}", i),
+            score: i as f64,
+            active: i % 3 == 0,
+        })
+        .collect();
+    let mut df = to_dataframe(&records[..2]).expect("Failed to convert first chunk");
+    df.vstack_mut(&to_dataframe(&records[2..]).expect("Failed to convert second chunk"))
+        .expect("Failed to stack frames");
+    let before = df.clone();
+
+    let borrowed: Vec<BasicRecord> = from_dataframe_ref(&df).expect("Failed to convert borrowed DataFrame");
+    assert_eq!(borrowed, records);
+
+    // The frame keeps its chunks and contents, and converts again by value
+    assert_eq!(df.column("id").unwrap().n_chunks(), 2);
+    assert_eq!(df, before);
+    assert_eq!(from_dataframe::<BasicRecord>(df).unwrap(), borrowed);
+}
+
+#[test]
+fn test_slices_and_iterators_convert_like_vecs() {
+    let records: Vec<BasicRecord> = (0..6)
+        .map(|i| BasicRecord {
+            id: i,
+            name: format!("User_{}", i),
+            score: i as f64,
+            active: i % 2 == 0,
+        })
+        .collect();
+
+    let from_slice = to_dataframe(&records[2..]).expect("Failed to convert a slice");
+    let from_iter = to_dataframe_iter(records.iter().skip(2).cloned()).expect("Failed to convert an iterator");
+    assert_eq!(from_slice, from_iter);
+    let tail: Vec<BasicRecord> = records[2..].to_vec();
+    assert_eq!(from_slice, to_dataframe(&tail).unwrap());
+
+    assert!(matches!(to_dataframe::<BasicRecord>(&[]), Err(serde_polars::PolarsSerdeError::EmptyInput)));
+    assert!(matches!(
+        to_dataframe_iter(std::iter::empty::<BasicRecord>()),
+        Err(serde_polars::PolarsSerdeError::EmptyInput)
+    ));
+}
+
+#[test]
+fn test_large_iterator_converts_in_chunks() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        id: i64,
+        taken_on: chrono::NaiveDate,
+        // `None` throughout the first chunk of records
+        adjusted: Option<f64>,
+    }
+
+    let reading = |i: i64| Reading {
+        id: i,
+        taken_on: chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() + chrono::Duration::days(i % 365),
+        adjusted: (i >= 10_000).then_some(i as f64 / 2.0),
+    };
+
+    let df = to_dataframe_iter((0..20_000).map(reading)).expect("Failed to convert an iterator");
+    let collected: Vec<Reading> = (0..20_000).map(reading).collect();
+    assert_eq!(df, to_dataframe(&collected).unwrap());
+    assert_eq!(df.column("adjusted").unwrap().null_count(), 10_000);
+    // Each chunk of records becomes a chunk of the frame, without being copied into one
+    assert_eq!(df.column("id").unwrap().n_chunks(), 3);
+
+    let converted: Vec<Reading> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(converted, collected);
+}
+
+#[test]
+fn test_single_field_series_roundtrip() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Score {
+        value: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Day {
+        date: chrono::NaiveDate,
+    }
+
+    let scores = vec![Score { value: 1.5 }, Score { value: 2.5 }, Score { value: 4.0 }];
+    let series = to_series("score", &scores).expect("Failed to convert to Series");
+    assert_eq!(series.name().to_string(), "score");
+    assert_eq!(series.len(), 3);
+
+    // The series name need not match the struct field
+    let converted: Vec<Score> = from_series(series).expect("Failed to convert from Series");
+    assert_eq!(converted, scores);
+
+    let days = vec![
+        Day { date: chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap() },
+        Day { date: chrono::NaiveDate::from_ymd_opt(2024, 3, 1).unwrap() },
+    ];
+    let series = to_series("day", &days).expect("Failed to convert chrono field to Series");
+    assert_eq!(series.dtype().to_string(), "date");
+    let converted: Vec<Day> = from_series(series).expect("Failed to convert chrono Series back");
+    assert_eq!(converted, days);
+
+    // Types with several fields are rejected in both directions
+    let records = vec![BasicRecord {
+        id: 1,
+        name: "Alice".to_string(),
+        score: 85.5,
+        active: true,
+    }];
+    assert!(to_series("record", &records).is_err());
+    let series = to_series("score", &scores).unwrap();
+    assert!(from_series::<BasicRecord>(series).is_err());
+}
+
+#[test]
+fn test_explicit_column_order() {
+    use serde_polars::{to_dataframe_with, ConversionOptions, PolarsSerdeError};
+
+    let records = vec![BasicRecord {
+        id: 1,
+        name: "Alice".to_string(),
+        score: 85.5,
+        active: true,
+    }];
+    let order = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+    let options = ConversionOptions::new().column_order(order(&["score", "id", "active", "name"]));
+    let df = to_dataframe_with(&records, &options).expect("Failed to reorder columns");
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["score", "id", "active", "name"]);
+
+    let converted: Vec<BasicRecord> = from_dataframe(df).expect("Failed to convert reordered DataFrame");
+    assert_eq!(converted, records);
+
+    // Every column must be listed, and only existing columns
+    let options = ConversionOptions::new().column_order(order(&["score", "id", "active", "name", "email"]));
+    match to_dataframe_with(&records, &options) {
+        Err(PolarsSerdeError::MissingColumn { column }) => assert_eq!(column, "email"),
+        other => panic!("expected MissingColumn, got {:?}", other),
+    }
+    let options = ConversionOptions::new().column_order(order(&["score", "id", "name"]));
+    match to_dataframe_with(&records, &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => assert!(message.contains("'active'"), "{}", message),
+        other => panic!("expected ConversionError, got {:?}", other),
+    }
+}
+
+#[cfg(any(feature = "ipc", feature = "parquet"))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Shipment {
+    id: i64,
+    destination: Option<String>,
+    shipped_on: chrono::NaiveDate,
+    weights: Vec<f64>,
+}
+
+#[cfg(feature = "ipc")]
+#[test]
+fn test_ipc_roundtrip() {
+    let shipped_on = |day| chrono::NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+    let records = vec![
+        Shipment { id: 1, destination: Some("Oslo".to_string()), shipped_on: shipped_on(4), weights: vec![1.5, 2.0] },
+        Shipment { id: 2, destination: None, shipped_on: shipped_on(11), weights: vec![] },
+        Shipment { id: 3, destination: Some("Lima".to_string()), shipped_on: shipped_on(29), weights: vec![7.25] },
+    ];
+
+    let mut buffer = Vec::new();
+    write_ipc(&records, &mut buffer).unwrap();
+    assert!(buffer.starts_with(b"ARROW1"));
+
+    let converted: Vec<Shipment> = read_ipc(buffer.as_slice()).unwrap();
+    assert_eq!(records, converted);
+
+    // The same batches in the IPC stream format
+    use serde_polars::arrow::ipc::{reader::FileReader, writer::StreamWriter};
+    let file = FileReader::try_new(std::io::Cursor::new(buffer), None).unwrap();
+    let mut stream = StreamWriter::try_new(Vec::new(), &file.schema()).unwrap();
+    for batch in file {
+        stream.write(&batch.unwrap()).unwrap();
+    }
+    let stream = stream.into_inner().unwrap();
+    let converted: Vec<Shipment> = read_ipc(stream.as_slice()).unwrap();
+    assert_eq!(records, converted);
+
+    assert!(matches!(
+        write_ipc::<Shipment>(&[], Vec::new()),
+        Err(serde_polars::PolarsSerdeError::EmptyInput)
+    ));
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_roundtrip_with_zstd() {
+    use serde_polars::{read_parquet, write_parquet, write_parquet_with, ConversionOptions, ParquetCompression, ParquetEncoding};
+
+    let shipped_on = |day| chrono::NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+    let destinations = ["Oslo", "Bergen", "Tromsø"];
+    let records: Vec<Shipment> = (0..2_000)
+        .map(|i| Shipment {
+            id: i,
+            destination: (i % 7 != 0).then(|| destinations[i as usize % 3].to_string()),
+            shipped_on: shipped_on(1 + (i % 28) as u32),
+            weights: vec![1.5; (i % 4) as usize],
+        })
+        .collect();
+
+    let mut plain = Vec::new();
+    write_parquet(&records, &mut plain).unwrap();
+    assert!(plain.starts_with(b"PAR1"));
+    let converted: Vec<Shipment> = read_parquet(plain.as_slice()).unwrap();
+    assert_eq!(records, converted);
+
+    let options = ConversionOptions::new()
+        .parquet_compression(ParquetCompression::Zstd)
+        .parquet_column_encoding("destination".to_string(), ParquetEncoding::Dictionary)
+        .parquet_column_encoding("id".to_string(), ParquetEncoding::Plain);
+    let mut compressed = Vec::new();
+    write_parquet_with(&records, &mut compressed, &options).unwrap();
+    assert!(compressed.len() < plain.len(), "{} >= {}", compressed.len(), plain.len());
+    let converted: Vec<Shipment> = read_parquet(compressed.as_slice()).unwrap();
+    assert_eq!(records, converted);
+
+    let unknown = ConversionOptions::new().parquet_column_encoding("weight".to_string(), ParquetEncoding::Plain);
+    assert!(matches!(
+        write_parquet_with(&records, Vec::new(), &unknown),
+        Err(serde_polars::PolarsSerdeError::MissingColumn { .. })
+    ));
+    // List and struct columns are stored as nested leaf columns that a hint cannot name
+    let nested = ConversionOptions::new().parquet_column_encoding("weights".to_string(), ParquetEncoding::Plain);
+    assert!(matches!(
+        write_parquet_with(&records, Vec::new(), &nested),
+        Err(serde_polars::PolarsSerdeError::ConversionError { .. })
+    ));
+    assert!(matches!(
+        write_parquet::<Shipment>(&[], Vec::new()),
+        Err(serde_polars::PolarsSerdeError::EmptyInput)
+    ));
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_ipc_files_async() {
+    let shipped_on = |day| chrono::NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+    let records = vec![
+        Shipment { id: 1, destination: Some("Oslo".to_string()), shipped_on: shipped_on(4), weights: vec![1.5, 2.0] },
+        Shipment { id: 2, destination: None, shipped_on: shipped_on(11), weights: vec![] },
+    ];
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shipments.arrow");
+    serde_polars::write_ipc_async(records.clone(), &path).await.unwrap();
+
+    let converted: Vec<Shipment> = serde_polars::read_ipc_async(&path).await.unwrap();
+    assert_eq!(records, converted);
+
+    let missing = serde_polars::read_ipc_async::<Shipment>(dir.path().join("missing.arrow")).await;
+    assert!(matches!(missing, Err(serde_polars::PolarsSerdeError::ConversionError { .. })));
+}
+
+#[cfg(all(feature = "tokio", feature = "parquet"))]
+#[tokio::test]
+async fn test_parquet_files_async() {
+    let shipped_on = |day| chrono::NaiveDate::from_ymd_opt(2024, 3, day).unwrap();
+    let records = vec![
+        Shipment { id: 1, destination: Some("Oslo".to_string()), shipped_on: shipped_on(4), weights: vec![1.5, 2.0] },
+        Shipment { id: 2, destination: None, shipped_on: shipped_on(11), weights: vec![] },
+    ];
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("shipments.parquet");
+    serde_polars::write_parquet_async(records.clone(), &path).await.unwrap();
+
+    let converted: Vec<Shipment> = serde_polars::read_parquet_async(&path).await.unwrap();
+    assert_eq!(records, converted);
+
+    let missing = serde_polars::read_parquet_async::<Shipment>(dir.path().join("missing.parquet")).await;
+    assert!(matches!(missing, Err(serde_polars::PolarsSerdeError::ConversionError { .. })));
+}
+
+#[cfg(feature = "ipc")]
+#[test]
+fn test_errors_convert_into_io_errors() {
+    fn export(shipments: &[Shipment]) -> std::io::Result<Vec<u8>> {
+        let mut buffer = Vec::new();
+        write_ipc(shipments, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    let error = export(&[]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(error.to_string(), serde_polars::PolarsSerdeError::EmptyInput.to_string());
+
+    let source = error.into_inner().unwrap().downcast::<serde_polars::PolarsSerdeError>().unwrap();
+    assert!(matches!(*source, serde_polars::PolarsSerdeError::EmptyInput));
+}
+
+#[cfg(feature = "ipc")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Parcel {
+    id: i64,
+    insured_value: Option<i64>,
+    sent_on: chrono::NaiveDate,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+}
+
+#[cfg(feature = "ipc")]
+#[test]
+fn test_column_nullability_follows_field_types() {
+    let sent_on = chrono::NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+    let records = vec![
+        Parcel { id: 1, insured_value: Some(100), sent_on, labels: vec!["fragile".to_string()] },
+        Parcel { id: 2, insured_value: Some(250), sent_on, labels: vec![] },
+    ];
+
+    let mut buffer = Vec::new();
+    write_ipc(&records, &mut buffer).unwrap();
+    let file = serde_polars::arrow::ipc::reader::FileReader::try_new(std::io::Cursor::new(buffer), None).unwrap();
+    let schema = file.schema();
+    let nullable = |name: &str| schema.field_with_name(name).unwrap().is_nullable();
+
+    assert!(!nullable("id"));
+    assert!(!nullable("sent_on"));
+    // `Option` fields stay nullable even when every record has a value
+    assert!(nullable("insured_value"));
+    // A skipped field is null where it was skipped
+    assert!(nullable("labels"));
+}
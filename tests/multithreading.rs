@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{
+    from_dataframe, from_dataframe_ref, to_dataframe, ConversionOptions, ConversionStats,
+    DataFrameBuilder, PolarsSerdeError,
+};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct ThreadTestRecord {
@@ -139,7 +142,7 @@ fn test_concurrent_dataframe_operations() {
             let base_records_clone = Arc::clone(&base_records);
             thread::spawn(move || {
                 // Each thread converts the same DataFrame
-                let converted: Vec<ThreadTestRecord> = from_dataframe((*df_clone).clone())
+                let converted: Vec<ThreadTestRecord> = from_dataframe_ref(&df_clone)
                     .expect(&format!("Thread {} failed to convert DataFrame", thread_id));
 
                 assert_eq!(converted.len(), 1000);
@@ -184,7 +187,7 @@ fn test_thread_local_operations() {
                     }
 
                     // Convert to DataFrame and back
-                    let df = to_dataframe(&*records).expect(&format!(
+                    let df = to_dataframe(&records).expect(&format!(
                         "Thread {} failed to convert thread-local data",
                         thread_id
                     ));
@@ -212,3 +215,19 @@ fn test_thread_local_operations() {
 
     println!("✓ Thread-local operations test completed");
 }
+
+fn _assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_public_types_are_send_sync() {
+    // Compile-time check: errors and options must cross thread and async task boundaries
+    _assert_send_sync::<PolarsSerdeError>();
+    _assert_send_sync::<ConversionOptions>();
+    _assert_send_sync::<ConversionStats>();
+    _assert_send_sync::<DataFrameBuilder<ThreadTestRecord>>();
+
+    // An error produced on a worker thread can be returned through `join`
+    let handle = std::thread::spawn(|| to_dataframe::<ThreadTestRecord>(&Vec::new()).map(|_| ()));
+    let result = handle.join().expect("Thread panicked");
+    assert!(matches!(result, Err(PolarsSerdeError::EmptyInput)));
+}
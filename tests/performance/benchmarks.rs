@@ -1,40 +1,40 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{from_dataframe, from_dataframe_ref, to_dataframe, Converter};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-#[cfg(feature = "polars_0_40")]
-use polars_crate_0_40 as polars;
+/// The system allocator, counting allocations so benchmarks can report them
+struct CountingAllocator;
 
-#[cfg(feature = "polars_0_41")]
-use polars_crate_0_41 as polars;
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
 
-#[cfg(feature = "polars_0_42")]
-use polars_crate_0_42 as polars;
-
-#[cfg(feature = "polars_0_43")]
-use polars_crate_0_43 as polars;
-
-#[cfg(feature = "polars_0_44")]
-use polars_crate_0_44 as polars;
-
-#[cfg(feature = "polars_0_45")]
-use polars_crate_0_45 as polars;
-
-#[cfg(feature = "polars_0_46")]
-use polars_crate_0_46 as polars;
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
 
-#[cfg(feature = "polars_0_47")]
-use polars_crate_0_47 as polars;
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
 
-#[cfg(feature = "polars_0_48")]
-use polars_crate_0_48 as polars;
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
 
-#[cfg(feature = "polars_0_49")]
-use polars_crate_0_49 as polars;
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
 
-#[cfg(feature = "polars_0_50")]
-use polars_crate_0_50 as polars;
-use polars::prelude::*;
+/// Allocations made by `f`
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 struct BenchRecord {
@@ -83,7 +83,7 @@ fn bench_from_dataframe(c: &mut Criterion) {
         group.bench_with_input(BenchmarkId::new("records", size), size, |b, &_size| {
             b.iter(|| {
                 let _records: Vec<BenchRecord> =
-                    from_dataframe(df.clone()).expect("Failed to convert from DataFrame");
+                    from_dataframe_ref(&df).expect("Failed to convert from DataFrame");
             });
         });
     }
@@ -109,13 +109,261 @@ fn bench_roundtrip(c: &mut Criterion) {
     group.finish();
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ChronoBenchRecord {
+    id: i64,
+    birth_date: NaiveDate,
+    last_login: NaiveDateTime,
+    created_at: DateTime<Utc>,
+    score: f64,
+}
+
+impl ChronoBenchRecord {
+    fn generate(id: i64) -> Self {
+        let created_at = DateTime::from_timestamp(1609459200 + id, 0).unwrap();
+        Self {
+            id,
+            birth_date: NaiveDate::from_ymd_opt(1970, 1, 1).unwrap() + chrono::Duration::days(id % 20_000),
+            last_login: created_at.naive_utc(),
+            created_at,
+            score: (id as f64) * 0.1,
+        }
+    }
+}
+
+fn bench_chrono_roundtrip(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chrono_roundtrip");
+
+    for size in [100, 1_000, 10_000].iter() {
+        let records: Vec<ChronoBenchRecord> = (0..*size).map(ChronoBenchRecord::generate).collect();
+
+        group.bench_with_input(BenchmarkId::new("records", size), size, |b, &_size| {
+            b.iter(|| {
+                let df = to_dataframe(&records).expect("Failed to convert to DataFrame");
+                let _converted: Vec<ChronoBenchRecord> =
+                    from_dataframe(df).expect("Failed to convert back");
+            });
+        });
+    }
+
+    group.finish();
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DateOnlyRecord {
+    date: NaiveDate,
+}
+
+fn bench_date_parsing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("date_parsing");
+    group.sample_size(10);
+
+    let size = 1_000_000;
+    let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+    let records: Vec<DateOnlyRecord> = (0..size)
+        .map(|i| DateOnlyRecord {
+            date: epoch + chrono::Duration::days(i % 40_000),
+        })
+        .collect();
+    let strings: Vec<String> = records.iter().map(|r| r.date.to_string()).collect();
+
+    // Baseline: what parsing the column costs through chrono's format interpreter
+    group.bench_with_input(BenchmarkId::new("chrono_parse_from_str", size), &strings, |b, strings| {
+        b.iter(|| {
+            for s in strings {
+                let _date = NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+            }
+        });
+    });
+
+    // The conversion path, which parses the serialized dates with the fast YYYY-MM-DD parser
+    group.bench_with_input(BenchmarkId::new("to_dataframe_date_column", size), &records, |b, records| {
+        b.iter(|| {
+            let _df = to_dataframe(records).expect("Failed to convert to DataFrame");
+        });
+    });
+
+    group.finish();
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LargeFieldRecord {
+    id: i64,
+    body: String,
+    tags: Vec<String>,
+    items: Vec<LineItem>,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct LineItem {
+    sku: String,
+    quantity: i32,
+    price: f64,
+}
+
+impl LargeFieldRecord {
+    fn generate(id: i64) -> Self {
+        Self {
+            id,
+            body: "lorem ipsum ".repeat(10_000),
+            tags: (0..10_000).map(|tag| format!("tag_{}", tag)).collect(),
+            items: (0..1_000)
+                .map(|item| LineItem { sku: format!("SKU-{}", item), quantity: item, price: item as f64 * 0.5 })
+                .collect(),
+            created_at: DateTime::from_timestamp(1609459200 + id, 0).unwrap(),
+        }
+    }
+}
+
+fn bench_large_field_to_dataframe(c: &mut Criterion) {
+    let mut group = c.benchmark_group("large_field_to_dataframe");
+
+    let sample = LargeFieldRecord::generate(0);
+    group.bench_function("detect_chrono_types", |b| {
+        b.iter(|| {
+            let _types = serde_polars::detect_chrono_types(&sample).expect("Failed to detect types");
+        });
+    });
+
+    for size in [1, 10, 100].iter() {
+        let records: Vec<LargeFieldRecord> = (0..*size).map(LargeFieldRecord::generate).collect();
+
+        group.bench_with_input(BenchmarkId::new("records", size), size, |b, &_size| {
+            b.iter(|| {
+                let _df = to_dataframe(&records).expect("Failed to convert to DataFrame");
+            });
+        });
+    }
+
+    group.finish();
+}
+
+/// Declare a record with one `f64` field per identifier
+macro_rules! wide_record {
+    ($name:ident { $($field:ident)+ }) => {
+        #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+        struct $name {
+            $($field: f64,)+
+        }
+
+        impl $name {
+            fn generate(id: i64) -> Self {
+                let mut value = id as f64;
+                $(
+                    value += 0.5;
+                    let $field = value;
+                )+
+                Self { $($field,)+ }
+            }
+        }
+    };
+}
+
+wide_record!(WideRecord {
+    c0 c1 c2 c3 c4 c5 c6 c7 c8 c9 c10 c11 c12 c13 c14 c15 c16 c17 c18 c19
+    c20 c21 c22 c23 c24 c25 c26 c27 c28 c29 c30 c31 c32 c33 c34 c35 c36 c37 c38 c39
+    c40 c41 c42 c43 c44 c45 c46 c47 c48 c49 c50 c51 c52 c53 c54 c55 c56 c57 c58 c59
+    c60 c61 c62 c63 c64 c65 c66 c67 c68 c69 c70 c71 c72 c73 c74 c75 c76 c77 c78 c79
+    c80 c81 c82 c83 c84 c85 c86 c87 c88 c89 c90 c91 c92 c93 c94 c95 c96 c97 c98 c99
+    c100 c101 c102 c103 c104 c105 c106 c107 c108 c109 c110 c111 c112 c113 c114 c115 c116 c117 c118 c119
+    c120 c121 c122 c123 c124 c125 c126 c127 c128 c129 c130 c131 c132 c133 c134 c135 c136 c137 c138 c139
+    c140 c141 c142 c143 c144 c145 c146 c147 c148 c149 c150 c151 c152 c153 c154 c155 c156 c157 c158 c159
+    c160 c161 c162 c163 c164 c165 c166 c167 c168 c169 c170 c171 c172 c173 c174 c175 c176 c177 c178 c179
+    c180 c181 c182 c183 c184 c185 c186 c187 c188 c189 c190 c191 c192 c193 c194 c195 c196 c197 c198 c199
+});
+
+fn bench_wide_from_dataframe(c: &mut Criterion) {
+    let mut group = c.benchmark_group("wide_from_dataframe");
+    group.sample_size(20);
+
+    let size = 10_000;
+    let records: Vec<WideRecord> = (0..size).map(WideRecord::generate).collect();
+    let df = to_dataframe(&records).expect("Failed to create test DataFrame");
+    let batches = serde_polars::to_record_batches(&records).expect("Failed to create record batches");
+
+    // Baseline: serde_arrow looking up each field of each record by name
+    group.bench_with_input(BenchmarkId::new("serde_arrow_by_name", size), &batches[0], |b, batch| {
+        b.iter(|| {
+            let _records: Vec<WideRecord> = serde_arrow::from_record_batch(batch).expect("Failed to deserialize");
+        });
+    });
+
+    group.bench_with_input(BenchmarkId::new("from_record_batches", size), &batches, |b, batches| {
+        b.iter(|| {
+            let _records: Vec<WideRecord> =
+                serde_polars::from_record_batches(batches).expect("Failed to deserialize");
+        });
+    });
+
+    group.bench_with_input(BenchmarkId::new("from_dataframe", size), &df, |b, df| {
+        b.iter(|| {
+            let _records: Vec<WideRecord> = from_dataframe_ref(df).expect("Failed to convert from DataFrame");
+        });
+    });
+
+    let by_position = serde_polars::ConversionOptions::new().read_fields_by_position(true);
+    group.bench_with_input(BenchmarkId::new("from_dataframe_by_position", size), &df, |b, df| {
+        b.iter(|| {
+            let _records: Vec<WideRecord> = serde_polars::from_dataframe_with(df.clone(), &by_position)
+                .expect("Failed to convert from DataFrame");
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_repeated_small_conversions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("repeated_small_conversions");
+    group.sample_size(10);
+
+    // 10,000 conversions of 10 records each, as a service converting per request would
+    let conversions = 10_000;
+    let plain: Vec<Vec<BenchRecord>> = (0..conversions)
+        .map(|batch| (batch * 10..batch * 10 + 10).map(BenchRecord::generate).collect())
+        .collect();
+    let chrono: Vec<Vec<ChronoBenchRecord>> = (0..conversions)
+        .map(|batch| (batch * 10..batch * 10 + 10).map(ChronoBenchRecord::generate).collect())
+        .collect();
+
+    let plain_converter = Converter::<BenchRecord>::new();
+    let chrono_converter = Converter::<ChronoBenchRecord>::new();
+    let allocations = [
+        ("to_dataframe/plain", count_allocations(|| plain.iter().for_each(|rows| drop(to_dataframe(rows).unwrap())))),
+        ("converter/plain", count_allocations(|| plain.iter().for_each(|rows| drop(plain_converter.convert(rows).unwrap())))),
+        ("to_dataframe/chrono", count_allocations(|| chrono.iter().for_each(|rows| drop(to_dataframe(rows).unwrap())))),
+        ("converter/chrono", count_allocations(|| chrono.iter().for_each(|rows| drop(chrono_converter.convert(rows).unwrap())))),
+    ];
+    for (name, count) in allocations {
+        println!("repeated_small_conversions/{}: {} allocations per conversion", name, count / conversions as usize);
+    }
+
+    group.bench_with_input(BenchmarkId::new("to_dataframe", "plain"), &plain, |b, batches| {
+        b.iter(|| batches.iter().for_each(|rows| drop(to_dataframe(rows).expect("Failed to convert to DataFrame"))));
+    });
+    group.bench_with_input(BenchmarkId::new("converter", "plain"), &plain, |b, batches| {
+        b.iter(|| batches.iter().for_each(|rows| drop(plain_converter.convert(rows).expect("Failed to convert to DataFrame"))));
+    });
+    group.bench_with_input(BenchmarkId::new("to_dataframe", "chrono"), &chrono, |b, batches| {
+        b.iter(|| batches.iter().for_each(|rows| drop(to_dataframe(rows).expect("Failed to convert to DataFrame"))));
+    });
+    group.bench_with_input(BenchmarkId::new("converter", "chrono"), &chrono, |b, batches| {
+        b.iter(|| batches.iter().for_each(|rows| drop(chrono_converter.convert(rows).expect("Failed to convert to DataFrame"))));
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_to_dataframe,
+    bench_large_field_to_dataframe,
     bench_from_dataframe,
-    bench_roundtrip
+    bench_wide_from_dataframe,
+    bench_roundtrip,
+    bench_chrono_roundtrip,
+    bench_date_parsing,
+    bench_repeated_small_conversions
 );
 
-criterion_group!(benches);
-
 criterion_main!(benches);
@@ -0,0 +1,175 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_polars::version_compat::frame_schema;
+use serde_polars::{to_dataframe, PolarsFrame, PolarsSerdeError, ToDataFrame};
+
+#[cfg(feature = "polars_0_40")]
+use polars_crate_0_40 as polars;
+
+#[cfg(feature = "polars_0_41")]
+use polars_crate_0_41 as polars;
+
+#[cfg(feature = "polars_0_42")]
+use polars_crate_0_42 as polars;
+
+#[cfg(feature = "polars_0_43")]
+use polars_crate_0_43 as polars;
+
+#[cfg(feature = "polars_0_44")]
+use polars_crate_0_44 as polars;
+
+#[cfg(feature = "polars_0_45")]
+use polars_crate_0_45 as polars;
+
+#[cfg(feature = "polars_0_46")]
+use polars_crate_0_46 as polars;
+
+#[cfg(feature = "polars_0_47")]
+use polars_crate_0_47 as polars;
+
+#[cfg(feature = "polars_0_48")]
+use polars_crate_0_48 as polars;
+
+#[cfg(feature = "polars_0_49")]
+use polars_crate_0_49 as polars;
+
+#[cfg(feature = "polars_0_50")]
+use polars_crate_0_50 as polars;
+
+use polars::prelude::DataType;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+enum Side {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Venue {
+    code: String,
+    opened_on: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PolarsFrame)]
+struct Trade {
+    id: i64,
+    symbol: String,
+    price: f64,
+    quantity: u32,
+    side: Side,
+    traded_on: NaiveDate,
+    settled_at: Option<NaiveDateTime>,
+    booked_at: DateTime<Utc>,
+    fills: Vec<i32>,
+    venue: Venue,
+    #[serde(rename = "isActive")]
+    active: bool,
+}
+
+fn trades() -> Vec<Trade> {
+    let day = |d| NaiveDate::from_ymd_opt(2024, 6, d).unwrap();
+    (1..=3)
+        .map(|i| Trade {
+            id: i,
+            symbol: format!("SYM{}", i),
+            price: 10.0 * i as f64,
+            quantity: i as u32,
+            side: if i % 2 == 0 { Side::Sell } else { Side::Buy },
+            traded_on: day(i as u32),
+            settled_at: Some(day(i as u32 + 2).and_hms_opt(17, 0, 0).unwrap()),
+            booked_at: day(i as u32).and_hms_opt(9, 30, 0).unwrap().and_utc(),
+            fills: vec![i as i32; i as usize],
+            venue: Venue { code: "XLON".to_string(), opened_on: day(1) },
+            active: i != 2,
+        })
+        .collect()
+}
+
+#[test]
+fn test_derived_schema_matches_traced_schema() {
+    let schema = Trade::polars_schema().expect("Failed to trace the schema of Trade");
+    let df = to_dataframe(&trades()).expect("Failed to convert trades");
+    assert_eq!(schema, &frame_schema(&df));
+
+    assert_eq!(schema.get("traded_on"), Some(&DataType::Date));
+    assert_eq!(schema.get("side"), Some(&DataType::String));
+    assert!(schema.get("isActive").is_some());
+
+    // Kept after the first call
+    assert!(std::ptr::eq(schema, Trade::polars_schema().unwrap()));
+}
+
+#[test]
+fn test_derived_conversions_roundtrip() {
+    let trades = trades();
+
+    let df = trades.to_dataframe().expect("Failed to convert trades");
+    assert_eq!(df.height(), 3);
+    assert_eq!(df, Trade::to_dataframe(&trades).unwrap());
+
+    let converted = Trade::from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(converted, trades);
+}
+
+#[test]
+fn test_derived_schema_of_untraceable_type_is_an_error() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TradeDate(NaiveDate);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PolarsFrame)]
+    struct Settlement {
+        id: i64,
+        settled_on: TradeDate,
+    }
+
+    match Settlement::polars_schema() {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("Settlement"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other),
+    }
+
+    // Converting records still works
+    let settlements = [Settlement { id: 1, settled_on: TradeDate(NaiveDate::from_ymd_opt(2024, 6, 3).unwrap()) }];
+    let df = settlements.to_dataframe().unwrap();
+    assert_eq!(df.column("settled_on").unwrap().dtype(), &DataType::Date);
+}
+
+// The derive warns about the map field at compile time
+#[test]
+#[allow(deprecated)]
+fn test_derived_schema_of_map_fields_is_an_error() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PolarsFrame)]
+    struct Quote {
+        id: i64,
+        prices: std::collections::BTreeMap<String, f64>,
+    }
+
+    match Quote::polars_schema() {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("BTreeMap"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_derived_untagged_enum_records() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PolarsFrame)]
+    #[serde(untagged)]
+    enum Order {
+        // Untagged variants are tried in order, so the one with more fields comes first
+        Limit { id: i64, quantity: u32, limit: f64 },
+        Market { id: i64, quantity: u32 },
+    }
+
+    let orders = vec![
+        Order::Market { id: 1, quantity: 5 },
+        Order::Limit { id: 2, quantity: 3, limit: 9.5 },
+    ];
+    let df = orders.to_dataframe().expect("Failed to convert orders");
+    assert_eq!(df.column("limit").unwrap().null_count(), 1);
+
+    let converted = Order::from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(converted, orders);
+}
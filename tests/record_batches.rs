@@ -0,0 +1,113 @@
+//! The Arrow API, which builds without any Polars version feature:
+//! `cargo test --no-default-features --test record_batches`
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_polars::arrow::datatypes::{DataType, TimeUnit};
+use serde_polars::{from_record_batches, to_record_batches, PolarsSerdeError};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Visit {
+    id: i64,
+    page: String,
+    day: NaiveDate,
+    seen_at: DateTime<Utc>,
+    duration_ms: Option<u32>,
+}
+
+#[test]
+fn test_record_batches_roundtrip() {
+    let day = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+    let seen_at = |secs: i64| DateTime::from_timestamp(1_709_200_000 + secs, 0).unwrap();
+    let visits = vec![
+        Visit { id: 1, page: "/".to_string(), day, seen_at: seen_at(0), duration_ms: Some(1200) },
+        Visit { id: 2, page: "/about".to_string(), day, seen_at: seen_at(95), duration_ms: None },
+    ];
+
+    let batches = to_record_batches(&visits).unwrap();
+    let schema = batches[0].schema();
+    assert_eq!(schema.field_with_name("day").unwrap().data_type(), &DataType::Date32);
+    assert_eq!(
+        schema.field_with_name("seen_at").unwrap().data_type(),
+        &DataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+
+    let converted: Vec<Visit> = from_record_batches(&batches).unwrap();
+    assert_eq!(visits, converted);
+
+    assert!(matches!(to_record_batches::<Visit>(&[]), Err(PolarsSerdeError::EmptyInput)));
+}
+
+#[test]
+fn test_several_record_batches_read_in_order() {
+    let day = NaiveDate::from_ymd_opt(2024, 3, 1).unwrap();
+    let visit = |id: i64| Visit {
+        id,
+        page: format!("/page/{}", id),
+        day: day + chrono::Duration::days(id),
+        seen_at: DateTime::from_timestamp(1_709_300_000 + id * 60, 0).unwrap(),
+        duration_ms: (id % 2 == 0).then_some(id as u32 * 100),
+    };
+    let visits: Vec<Visit> = (0..7).map(visit).collect();
+
+    // Uneven slices of one batch, then a batch converted on its own
+    let batch = to_record_batches(&visits[..5]).unwrap().remove(0);
+    let mut batches = vec![batch.slice(0, 2), batch.slice(2, 1), batch.slice(3, 2)];
+    batches.extend(to_record_batches(&visits[5..]).unwrap());
+    assert_eq!(batches.len(), 4);
+
+    let converted: Vec<Visit> = from_record_batches(&batches).unwrap();
+    assert_eq!(visits, converted);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Playlist {
+    id: i64,
+    track_ids: Vec<i64>,
+    genres: Option<Vec<String>>,
+}
+
+#[test]
+fn test_large_list_columns_into_vec_fields() {
+    use serde_polars::arrow::array::{
+        ArrayRef, Int64Array, Int64Builder, LargeListBuilder, ListBuilder, StringBuilder,
+    };
+    use serde_polars::arrow::datatypes::{Field, Schema};
+    use serde_polars::arrow::record_batch::RecordBatch;
+    use std::sync::Arc;
+
+    let playlists = vec![
+        Playlist { id: 1, track_ids: vec![10, 11, 12], genres: Some(vec!["jazz".to_string(), "soul".to_string()]) },
+        Playlist { id: 2, track_ids: vec![], genres: None },
+        Playlist { id: 3, track_ids: vec![7], genres: Some(vec![]) },
+    ];
+
+    // Lists with both offset widths, as written by different Arrow producers
+    let mut large_tracks = LargeListBuilder::new(Int64Builder::new());
+    let mut tracks = ListBuilder::new(Int64Builder::new());
+    let mut large_genres = LargeListBuilder::new(StringBuilder::new());
+    for playlist in &playlists {
+        large_tracks.append_value(playlist.track_ids.iter().map(|id| Some(*id)));
+        tracks.append_value(playlist.track_ids.iter().map(|id| Some(*id)));
+        large_genres.append_option(playlist.genres.as_ref().map(|genres| genres.iter().map(Some)));
+    }
+    let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(playlists.iter().map(|playlist| playlist.id)));
+    let large_genres: ArrayRef = Arc::new(large_genres.finish());
+
+    for track_ids in [Arc::new(large_tracks.finish()) as ArrayRef, Arc::new(tracks.finish())] {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("track_ids", track_ids.data_type().clone(), false),
+            Field::new("genres", large_genres.data_type().clone(), true),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![ids.clone(), track_ids, large_genres.clone()]).unwrap();
+
+        let converted: Vec<Playlist> = from_record_batches(std::slice::from_ref(&batch)).unwrap();
+        assert_eq!(playlists, converted);
+
+        // A slice starts at a non-zero list offset
+        let converted: Vec<Playlist> = from_record_batches(&[batch.slice(1, 2)]).unwrap();
+        assert_eq!(&playlists[1..], converted.as_slice());
+    }
+}
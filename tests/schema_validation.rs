@@ -0,0 +1,754 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use serde_polars::{
+    can_deserialize, from_dataframe, from_dataframe_with, to_dataframe_as, to_dataframe_with, ConversionOptions,
+    PolarsSerdeError, SchemaSource,
+};
+
+#[cfg(feature = "polars_0_40")]
+use polars_crate_0_40 as polars;
+
+#[cfg(feature = "polars_0_41")]
+use polars_crate_0_41 as polars;
+
+#[cfg(feature = "polars_0_42")]
+use polars_crate_0_42 as polars;
+
+#[cfg(feature = "polars_0_43")]
+use polars_crate_0_43 as polars;
+
+#[cfg(feature = "polars_0_44")]
+use polars_crate_0_44 as polars;
+
+#[cfg(feature = "polars_0_45")]
+use polars_crate_0_45 as polars;
+
+#[cfg(feature = "polars_0_46")]
+use polars_crate_0_46 as polars;
+
+#[cfg(feature = "polars_0_47")]
+use polars_crate_0_47 as polars;
+
+#[cfg(feature = "polars_0_48")]
+use polars_crate_0_48 as polars;
+
+#[cfg(feature = "polars_0_49")]
+use polars_crate_0_49 as polars;
+
+#[cfg(feature = "polars_0_50")]
+use polars_crate_0_50 as polars;
+
+use polars::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Account {
+    id: i64,
+    name: String,
+    active: bool,
+    nickname: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DatedAccount {
+    id: i64,
+    opened: NaiveDate,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Reading {
+    sensor: i32,
+    level: Option<u8>,
+}
+
+#[test]
+fn test_can_deserialize_compatible_frame() {
+    let df = df! {
+        "id" => [1i64, 2i64],
+        "name" => ["Alice", "Bob"],
+        "active" => [true, false],
+        "nickname" => [Some("Al"), None],
+    }.unwrap();
+
+    can_deserialize::<Account>(&df).unwrap();
+
+    // The check must agree with the real conversion
+    let accounts: Vec<Account> = from_dataframe(df).unwrap();
+    assert_eq!(accounts.len(), 2);
+}
+
+#[test]
+fn test_can_deserialize_allows_missing_optional_column() {
+    let df = df! {
+        "id" => [1i64],
+        "name" => ["Alice"],
+        "active" => [true],
+    }.unwrap();
+
+    can_deserialize::<Account>(&df).unwrap();
+}
+
+#[test]
+fn test_can_deserialize_reports_missing_column() {
+    let df = df! {
+        "id" => [1i64],
+        "active" => [true],
+    }.unwrap();
+
+    match can_deserialize::<Account>(&df) {
+        Err(PolarsSerdeError::MissingColumn { column }) => assert_eq!(column, "name"),
+        other => panic!("expected MissingColumn, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_can_deserialize_reports_schema_mismatch() {
+    let df = df! {
+        "id" => [1i64],
+        "name" => ["Alice"],
+        "active" => [NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()],
+    }.unwrap();
+
+    match can_deserialize::<Account>(&df) {
+        Err(PolarsSerdeError::SchemaMismatch { column, expected, actual }) => {
+            assert_eq!(column, "active");
+            assert_eq!(expected, "Boolean");
+            assert_eq!(actual, "Date32");
+        }
+        other => panic!("expected SchemaMismatch, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_can_deserialize_checks_names_for_untraceable_types() {
+    let df = df! {
+        "id" => [1i64],
+        "opened" => [NaiveDate::from_ymd_opt(2023, 1, 1).unwrap()],
+    }.unwrap();
+    can_deserialize::<DatedAccount>(&df).unwrap();
+
+    let df = df! {
+        "id" => [1i64],
+    }.unwrap();
+    match can_deserialize::<DatedAccount>(&df) {
+        Err(PolarsSerdeError::MissingColumn { column }) => assert_eq!(column, "opened"),
+        other => panic!("expected MissingColumn, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_tracing_options_closure_customizes_schema() {
+    let accounts = vec![
+        Account { id: 1, name: "Alice".to_string(), active: true, nickname: None },
+        Account { id: 2, name: "Bob".to_string(), active: false, nickname: Some("Bobby".to_string()) },
+    ];
+
+    // Overwrites are not exposed by ConversionOptions; the closure reaches them anyway
+    let options = ConversionOptions::new().tracing(|tracing| {
+        tracing
+            .overwrite("id", serde_json::json!({"name": "id", "data_type": "I32"}))
+            .unwrap()
+    });
+    let df = to_dataframe_with(&accounts, &options).unwrap();
+    assert_eq!(df.column("id").unwrap().dtype(), &DataType::Int32);
+    assert_eq!(df.column("name").unwrap().dtype(), &DataType::String);
+
+    let roundtripped: Vec<Account> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, accounts);
+}
+
+#[test]
+fn test_downcast_integers_when_values_fit() {
+    let df = df! {
+        "sensor" => [1i64, -2i64, 2_147_483_647i64],
+        "level" => [Some(0i64), None, Some(255i64)],
+    }.unwrap();
+
+    let options = ConversionOptions::new().downcast_integers(true);
+    let readings: Vec<Reading> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(readings, vec![
+        Reading { sensor: 1, level: Some(0) },
+        Reading { sensor: -2, level: None },
+        Reading { sensor: 2_147_483_647, level: Some(255) },
+    ]);
+}
+
+#[test]
+fn test_downcast_integers_reports_out_of_range_value() {
+    let df = df! {
+        "sensor" => [1i64, 3_000_000_000i64],
+        "level" => [Some(1i64), Some(2i64)],
+    }.unwrap();
+
+    let options = ConversionOptions::new().downcast_integers(true);
+    match from_dataframe_with::<Reading>(df, &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("'sensor'"), "unexpected message: {}", message);
+            assert!(message.contains("3000000000"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other),
+    }
+
+    // Negative values cannot narrow into unsigned fields
+    let df = df! {
+        "sensor" => [1i64],
+        "level" => [Some(-1i64)],
+    }.unwrap();
+    match from_dataframe_with::<Reading>(df, &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("'level'"), "unexpected message: {}", message);
+            assert!(message.contains("-1"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct DatedReading {
+    sensor: i16,
+    taken_on: NaiveDate,
+    level: Option<u8>,
+    total: i64,
+}
+
+#[test]
+fn test_downcast_integers_with_chrono_fields() {
+    let taken_on = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
+    let df = df! {
+        "sensor" => [7i64, -300i64],
+        "taken_on" => [taken_on, taken_on],
+        "level" => [Some(3i64), None],
+        // Narrower than the field, so it is left for serde to widen
+        "total" => [1i32, 2i32],
+    }.unwrap();
+
+    let options = ConversionOptions::new().downcast_integers(true);
+    let readings: Vec<DatedReading> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(readings, vec![
+        DatedReading { sensor: 7, taken_on, level: Some(3), total: 1 },
+        DatedReading { sensor: -300, taken_on, level: None, total: 2 },
+    ]);
+
+    let df = df! {
+        "sensor" => [40_000i64],
+        "taken_on" => [taken_on],
+        "level" => [Some(3i64)],
+        "total" => [1i64],
+    }.unwrap();
+    match from_dataframe_with::<DatedReading>(df, &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("'sensor'"), "unexpected message: {}", message);
+            assert!(message.contains("40000"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_schema_source_modes() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Ticket {
+        id: i64,
+        assignee_id: Option<i64>,
+    }
+
+    // No ticket is assigned yet, so only the type knows what `assignee_id` holds
+    let tickets = vec![
+        Ticket { id: 1, assignee_id: None },
+        Ticket { id: 2, assignee_id: None },
+    ];
+    let dtype_with = |source: SchemaSource| {
+        let options = ConversionOptions::new().schema_source(source);
+        let df = to_dataframe_with(&tickets, &options).unwrap();
+        df.column("assignee_id").unwrap().dtype().clone()
+    };
+    assert_eq!(dtype_with(SchemaSource::Auto), DataType::Int64);
+    assert_eq!(dtype_with(SchemaSource::Type), DataType::Int64);
+    assert_eq!(dtype_with(SchemaSource::Samples), DataType::Null);
+
+    // Samples-traced frames still read back into the record type
+    let options = ConversionOptions::new().schema_source(SchemaSource::Samples);
+    let df = to_dataframe_with(&tickets, &options).unwrap();
+    let roundtripped: Vec<Ticket> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, tickets);
+
+    // Chrono fields cannot be traced from the type alone
+    let accounts = vec![DatedAccount { id: 1, opened: NaiveDate::from_ymd_opt(2023, 1, 1).unwrap() }];
+    let options = ConversionOptions::new().schema_source(SchemaSource::Type);
+    assert!(matches!(
+        to_dataframe_with(&accounts, &options),
+        Err(PolarsSerdeError::SerdeArrowError(_))
+    ));
+    let options = ConversionOptions::new().schema_source(SchemaSource::Samples);
+    assert!(to_dataframe_with(&accounts, &options).is_ok());
+}
+
+#[test]
+fn test_merge_sample_schema_covers_every_field() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Visit {
+        id: i64,
+        visited_on: NaiveDate,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        referrer: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        duration_secs: Option<i64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        campaign: Option<String>,
+    }
+
+    // Only the last visit has a duration, and no visit has a campaign at all
+    let day = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+    let visits = vec![
+        Visit { id: 1, visited_on: day, referrer: Some("search".to_string()), duration_secs: None, campaign: None },
+        Visit { id: 2, visited_on: day, referrer: None, duration_secs: None, campaign: None },
+        Visit { id: 3, visited_on: day, referrer: None, duration_secs: Some(42), campaign: None },
+    ];
+
+    // Even without merging, a field no record serialized gets an all-null column
+    let df = to_dataframe_with(&visits, &ConversionOptions::new()).unwrap();
+    assert_eq!(df.column("campaign").unwrap().null_count(), 3);
+
+    let options = ConversionOptions::new().merge_sample_schema(true);
+    let df = to_dataframe_with(&visits, &options).unwrap();
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["id", "visited_on", "referrer", "duration_secs", "campaign"]);
+    assert_eq!(df.column("referrer").unwrap().dtype(), &DataType::String);
+    assert_eq!(df.column("duration_secs").unwrap().dtype(), &DataType::Int64);
+    assert_eq!(df.column("campaign").unwrap().null_count(), 3);
+
+    let roundtripped: Vec<Visit> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, visits);
+
+    // With sample tracing, fields that are always `None` take their declared type
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Ticket {
+        id: i64,
+        assignee_id: Option<i64>,
+    }
+    let tickets = vec![Ticket { id: 1, assignee_id: None }];
+    let options = ConversionOptions::new()
+        .schema_source(SchemaSource::Samples)
+        .merge_sample_schema(true);
+    let df = to_dataframe_with(&tickets, &options).unwrap();
+    assert_eq!(df.column("assignee_id").unwrap().dtype(), &DataType::Int64);
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct NarrowIntegers {
+    tiny: i8,
+    small: i16,
+    byte: u8,
+    word: u16,
+}
+
+#[test]
+fn test_narrow_integers_keep_their_type() {
+    let records = vec![
+        NarrowIntegers { tiny: i8::MIN, small: i16::MIN, byte: u8::MIN, word: u16::MIN },
+        NarrowIntegers { tiny: i8::MAX, small: i16::MAX, byte: u8::MAX, word: u16::MAX },
+        NarrowIntegers { tiny: -1, small: -1, byte: 1, word: 1 },
+    ];
+
+    // The interchange must not widen narrow integers to Int64/UInt64
+    let df = to_dataframe_with(&records, &ConversionOptions::new()).unwrap();
+    assert_eq!(df.column("tiny").unwrap().dtype(), &DataType::Int8);
+    assert_eq!(df.column("small").unwrap().dtype(), &DataType::Int16);
+    assert_eq!(df.column("byte").unwrap().dtype(), &DataType::UInt8);
+    assert_eq!(df.column("word").unwrap().dtype(), &DataType::UInt16);
+
+    let converted: Vec<NarrowIntegers> = from_dataframe(df).unwrap();
+    assert_eq!(converted, records);
+
+    // Frames whose integers were widened still read back into the narrow fields
+    let widened = df! {
+        "tiny" => [i8::MIN as i64, i8::MAX as i64, -1],
+        "small" => [i16::MIN as i64, i16::MAX as i64, -1],
+        "byte" => [u8::MIN as u64, u8::MAX as u64, 1],
+        "word" => [u16::MIN as u64, u16::MAX as u64, 1],
+    }
+    .unwrap();
+    let converted: Vec<NarrowIntegers> = from_dataframe(widened).unwrap();
+    assert_eq!(converted, records);
+}
+
+#[test]
+fn test_max_rows_limits_frame_height() {
+    let df = df! {
+        "sensor" => [1i32, 2i32, 3i32],
+        "level" => [Some(1u8), None, Some(3u8)],
+    }.unwrap();
+
+    let options = ConversionOptions::new().max_rows(2);
+    match from_dataframe_with::<Reading>(df.clone(), &options) {
+        Err(PolarsSerdeError::InvalidRowCount { expected, actual }) => {
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+        }
+        other => panic!("expected InvalidRowCount, got {:?}", other),
+    }
+
+    let options = ConversionOptions::new().max_rows(3);
+    let readings: Vec<Reading> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(readings.len(), 3);
+}
+
+#[test]
+fn test_rows_lost_in_the_export_are_reported() {
+    #[derive(Debug, Deserialize)]
+    struct NoFields {}
+
+    let df = df! {
+        "sensor" => [1i32, 2i32, 3i32],
+    }.unwrap();
+
+    // A frame without columns exports no arrays, and so none of its rows. Polars
+    // versions that keep the height of such a frame report the loss rather than
+    // returning fewer records
+    let columnless = df.select(Vec::<String>::new()).unwrap();
+    let height = columnless.height();
+    match from_dataframe::<NoFields>(columnless) {
+        Ok(records) => assert_eq!(records.len(), height),
+        Err(PolarsSerdeError::InvalidRowCount { expected, actual }) => {
+            assert_eq!(expected, 3);
+            assert_eq!(actual, 0);
+        }
+        Err(other) => panic!("expected InvalidRowCount, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_keep_string_dictionary_writes_categorical_columns() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Shipment {
+        id: i64,
+        country: String,
+        carrier: Option<String>,
+    }
+
+    let shipments: Vec<Shipment> = (0..20)
+        .map(|i| Shipment {
+            id: i,
+            country: ["DE", "FR", "US"][i as usize % 3].to_string(),
+            carrier: if i % 4 == 0 { None } else { Some("DHL".to_string()) },
+        })
+        .collect();
+
+    // Strings stay plain by default
+    let df = to_dataframe_with(&shipments, &ConversionOptions::new()).unwrap();
+    assert_eq!(df.column("country").unwrap().dtype(), &DataType::String);
+
+    let options = ConversionOptions::new().keep_string_dictionary(true);
+    let df = to_dataframe_with(&shipments, &options).unwrap();
+    assert!(matches!(df.column("country").unwrap().dtype(), DataType::Categorical(_, _)));
+    assert!(matches!(df.column("carrier").unwrap().dtype(), DataType::Categorical(_, _)));
+    assert_eq!(df.column("carrier").unwrap().null_count(), 5);
+
+    let roundtripped: Vec<Shipment> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, shipments);
+}
+
+#[test]
+fn test_keep_string_dictionary_with_chrono_fields() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Delivery {
+        country: String,
+        delivered_on: NaiveDate,
+        attempts: Vec<NaiveDate>,
+    }
+
+    let day = |d| NaiveDate::from_ymd_opt(2024, 6, d).unwrap();
+    let deliveries: Vec<Delivery> = (0..6)
+        .map(|i| Delivery {
+            country: ["DE", "FR"][i % 2].to_string(),
+            delivered_on: day(1 + i as u32 % 3),
+            attempts: vec![day(1); i % 3],
+        })
+        .collect();
+
+    // Chrono fields are traced as strings, so they arrive dictionary-encoded too
+    let options = ConversionOptions::new().keep_string_dictionary(true);
+    let df = to_dataframe_with(&deliveries, &options).unwrap();
+    assert!(matches!(df.column("country").unwrap().dtype(), DataType::Categorical(_, _)));
+    assert_eq!(df.column("delivered_on").unwrap().dtype(), &DataType::Date);
+    assert_eq!(df.column("attempts").unwrap().dtype(), &DataType::List(Box::new(DataType::Date)));
+
+    let roundtripped: Vec<Delivery> = from_dataframe(df).unwrap();
+    assert_eq!(roundtripped, deliveries);
+}
+
+#[test]
+fn test_categorical_frames_under_string_cache() {
+    use serde_polars::with_string_cache;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Warehouse {
+        id: i64,
+        country: String,
+    }
+
+    let options = ConversionOptions::new().keep_string_dictionary(true);
+    // The second frame sees the countries in another order, so its local
+    // categories would number them differently
+    let north = vec![
+        Warehouse { id: 1, country: "NO".to_string() },
+        Warehouse { id: 2, country: "SE".to_string() },
+        Warehouse { id: 3, country: "FI".to_string() },
+    ];
+    let south = vec![
+        Warehouse { id: 4, country: "FI".to_string() },
+        Warehouse { id: 5, country: "SE".to_string() },
+        Warehouse { id: 6, country: "ES".to_string() },
+    ];
+
+    let matches: Vec<Option<bool>> = with_string_cache(|| {
+        let north = to_dataframe_with(&north, &options).unwrap();
+        let south = to_dataframe_with(&south, &options).unwrap();
+        assert!(matches!(south.column("country").unwrap().dtype(), DataType::Categorical(_, _)));
+
+        let north = north.column("country").unwrap();
+        let south = south.column("country").unwrap();
+        north.equal(south).unwrap().into_iter().collect()
+    });
+    assert_eq!(matches, vec![Some(false), Some(true), Some(false)]);
+}
+
+#[test]
+fn test_column_dtypes() {
+    use serde_polars::version_compat::column_dtypes;
+
+    let records = vec![DatedAccount { id: 1, opened: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap() }];
+    let df = to_dataframe_with(&records, &ConversionOptions::new()).unwrap();
+
+    assert_eq!(column_dtypes(&df), vec![("id".to_string(), DataType::Int64), ("opened".to_string(), DataType::Date)]);
+}
+
+#[test]
+fn test_empty_record_batches_to_dataframe() {
+    use serde_polars::arrow::array::{new_empty_array, ArrayRef, Int64Array, StringArray};
+    use serde_polars::arrow::datatypes::{DataType as ArrowType, Field, Schema};
+    use serde_polars::arrow::record_batch::RecordBatch;
+    use serde_polars::version_compat::{arrow_to_dataframe, column_dtypes};
+    use std::sync::Arc;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", ArrowType::Int64, false),
+        Field::new("name", ArrowType::Utf8, true),
+    ]));
+    let columns: Vec<ArrayRef> = schema.fields().iter().map(|field| new_empty_array(field.data_type())).collect();
+    let empty = RecordBatch::try_new(schema, columns).unwrap();
+
+    // A zero-row batch keeps its schema
+    let df = arrow_to_dataframe(vec![empty.clone()]).unwrap();
+    assert_eq!(df.height(), 0);
+    assert_eq!(column_dtypes(&df), vec![("id".to_string(), DataType::Int64), ("name".to_string(), DataType::String)]);
+
+    // Empty chunks among others are dropped
+    let filled = RecordBatch::try_new(empty.schema(), vec![
+        Arc::new(Int64Array::from(vec![1])) as ArrayRef,
+        Arc::new(StringArray::from(vec![Some("Alice")])),
+    ])
+    .unwrap();
+    let df = arrow_to_dataframe(vec![empty.clone(), filled, empty]).unwrap();
+    assert_eq!(df.height(), 1);
+    assert_eq!(df.width(), 2);
+
+    // No batches at all give a frame without columns
+    let df = arrow_to_dataframe(Vec::new()).unwrap();
+    assert_eq!(df.shape(), (0, 0));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_dataframe_to_json_rows() {
+    use serde_json::json;
+    use serde_polars::from_dataframe_dynamic;
+
+    let records = vec![
+        DatedAccount { id: 1, opened: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap() },
+        DatedAccount { id: 2, opened: NaiveDate::from_ymd_opt(1999, 12, 31).unwrap() },
+    ];
+    let mut df = to_dataframe_with(&records, &ConversionOptions::new()).unwrap();
+    df.with_column(Series::new("score".into(), [Some(1.5), None])).unwrap();
+    df.with_column(Series::new("name".into(), ["Alice", "Bob"])).unwrap();
+    df.with_column(Series::new("active".into(), [true, false])).unwrap();
+
+    let rows = from_dataframe_dynamic(df).unwrap();
+    assert_eq!(rows, vec![
+        json!({"id": 1, "opened": "2024-05-01", "score": 1.5, "name": "Alice", "active": true}),
+        json!({"id": 2, "opened": "1999-12-31", "score": null, "name": "Bob", "active": false}),
+    ]);
+}
+
+/// Temperature that refuses to serialize below absolute zero
+#[derive(Debug, Clone, Copy, Deserialize)]
+struct Kelvin(f64);
+
+impl Serialize for Kelvin {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.0 < 0.0 {
+            return Err(serde::ser::Error::custom(format!("{} K is below absolute zero", self.0)));
+        }
+        serializer.serialize_f64(self.0)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct ThermometerReading {
+    sensor: String,
+    temperature: Kelvin,
+}
+
+#[test]
+fn test_locate_failed_rows() {
+    let readings = vec![
+        ThermometerReading { sensor: "a".to_string(), temperature: Kelvin(293.0) },
+        ThermometerReading { sensor: "b".to_string(), temperature: Kelvin(301.5) },
+        ThermometerReading { sensor: "c".to_string(), temperature: Kelvin(-4.0) },
+        ThermometerReading { sensor: "d".to_string(), temperature: Kelvin(-8.0) },
+    ];
+
+    // Without the option, the error does not say which record failed
+    let result = to_dataframe_with(&readings, &ConversionOptions::new());
+    assert!(matches!(result, Err(PolarsSerdeError::SerdeArrowError(_))));
+
+    let options = ConversionOptions::new().locate_failed_rows(true);
+    match to_dataframe_with(&readings, &options) {
+        Err(PolarsSerdeError::RowError { index, source }) => {
+            assert_eq!(index, 2);
+            assert!(source.to_string().contains("below absolute zero"));
+        }
+        other => panic!("expected RowError, got {:?}", other),
+    }
+
+    // Records that all convert are unaffected
+    let df = to_dataframe_with(&readings[..2], &options).unwrap();
+    assert_eq!(df.height(), 2);
+}
+
+fn reading_schema(sensor: DataType) -> Schema {
+    Schema::from_iter([
+        Field::new("sensor".into(), sensor),
+        Field::new("level".into(), DataType::UInt8),
+    ])
+}
+
+#[test]
+fn test_to_dataframe_as_gives_every_batch_the_same_schema() {
+    let schema = reading_schema(DataType::Int64);
+
+    // Alone, the first batch would give an all-null column a Null dtype
+    let quiet = vec![
+        Reading { sensor: 1, level: None },
+        Reading { sensor: 2, level: None },
+    ];
+    let busy = vec![Reading { sensor: 3, level: Some(7) }];
+
+    let mut first = to_dataframe_as(&quiet, &schema).unwrap();
+    let second = to_dataframe_as(&busy, &schema).unwrap();
+    assert_eq!(first.schema(), second.schema());
+    assert_eq!(format!("{:?}", first.schema()), format!("{:?}", second.schema()));
+    assert_eq!(first.column("sensor").unwrap().dtype(), &DataType::Int64);
+
+    first.vstack_mut(&second).unwrap();
+    assert_eq!(first.height(), 3);
+
+    let empty = to_dataframe_as::<Reading>(&[], &schema).unwrap();
+    assert_eq!(empty.height(), 0);
+    assert_eq!(empty.schema(), second.schema());
+
+    // The cast columns still read back into the records
+    let readings: Vec<Reading> = from_dataframe(first).unwrap();
+    assert_eq!(readings, [quiet, busy].concat());
+}
+
+#[test]
+fn test_to_dataframe_as_rejects_incompatible_columns() {
+    let readings = vec![Reading { sensor: 300, level: Some(1) }];
+
+    match to_dataframe_as(&readings, &reading_schema(DataType::Int8)) {
+        Err(PolarsSerdeError::SchemaMismatch { column, expected, actual }) => {
+            assert_eq!(column, "sensor");
+            assert_eq!(expected, "i8");
+            assert_eq!(actual, "i32");
+        }
+        other => panic!("expected SchemaMismatch, got {:?}", other),
+    }
+
+    let narrow = Schema::from_iter([Field::new("sensor".into(), DataType::Int32)]);
+    match to_dataframe_as(&readings, &narrow) {
+        Err(PolarsSerdeError::SchemaMismatch { column, .. }) => assert_eq!(column, "level"),
+        other => panic!("expected SchemaMismatch, got {:?}", other),
+    }
+
+    let wide = Schema::from_iter([
+        Field::new("sensor".into(), DataType::Int32),
+        Field::new("level".into(), DataType::UInt8),
+        Field::new("site".into(), DataType::String),
+    ]);
+    match to_dataframe_as(&readings, &wide) {
+        Err(PolarsSerdeError::MissingColumn { column }) => assert_eq!(column, "site"),
+        other => panic!("expected MissingColumn, got {:?}", other),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum SensorReading {
+    Calibrated { sensor: String, value: f64, offset: f64 },
+    Raw { sensor: String, value: f64 },
+    Labelled { sensor: String, value: String },
+}
+
+#[test]
+fn test_records_missing_fields_share_columns() {
+    let readings = vec![
+        SensorReading::Raw { sensor: "a".to_string(), value: 1.5 },
+        SensorReading::Calibrated { sensor: "b".to_string(), value: 2.5, offset: 0.25 },
+    ];
+
+    let df = to_dataframe_with(&readings, &ConversionOptions::new()).expect("Failed to convert records of two shapes");
+    assert_eq!(df.column("offset").unwrap().null_count(), 1);
+
+    let converted: Vec<SensorReading> = from_dataframe(df).expect("Failed to convert back");
+    assert_eq!(converted, readings);
+}
+
+#[test]
+fn test_sample_traced_records_must_share_fields() {
+    let raw = SensorReading::Raw { sensor: "a".to_string(), value: 1.5 };
+    let calibrated = SensorReading::Calibrated { sensor: "b".to_string(), value: 2.5, offset: 0.25 };
+    let options = ConversionOptions::new().schema_source(SchemaSource::Samples);
+
+    match to_dataframe_with(&[raw.clone(), calibrated.clone()], &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.starts_with("record 1 "), "unexpected message: {}", message);
+            assert!(message.contains("field `offset` is not in the records before it"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other.map(|df| df.shape())),
+    }
+
+    match to_dataframe_with(&[calibrated, raw.clone(), raw], &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.starts_with("record 1 "), "unexpected message: {}", message);
+            assert!(message.contains("field `offset` is missing there"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other.map(|df| df.shape())),
+    }
+}
+
+#[test]
+fn test_divergent_record_is_named() {
+    let readings = vec![
+        SensorReading::Raw { sensor: "a".to_string(), value: 1.5 },
+        SensorReading::Calibrated { sensor: "b".to_string(), value: 2.5, offset: 0.25 },
+        SensorReading::Labelled { sensor: "c".to_string(), value: "high".to_string() },
+    ];
+
+    match to_dataframe_with(&readings, &ConversionOptions::new()) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.starts_with("record 2 "), "unexpected message: {}", message);
+            assert!(message.contains("field `value`"), "unexpected message: {}", message);
+        }
+        other => panic!("expected a conversion error, got {:?}", other.map(|df| df.shape())),
+    }
+}
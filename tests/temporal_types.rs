@@ -1,6 +1,11 @@
 use chrono::{NaiveDate, NaiveDateTime, DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_polars::{from_dataframe, to_dataframe};
+use serde_polars::{
+    denormalize_temporal_columns, from_dataframe, from_dataframe_with, from_dataframe_with_stats, merge_to_dataframe,
+    normalize_temporal_columns, to_dataframe, to_dataframe_with, to_dataframe_with_stats, ChronoKind,
+    ConversionOptions, OnParseError, PolarsSerdeError, ScalarValue,
+};
+use std::collections::HashMap;
 
 #[cfg(feature = "polars_0_40")]
 use polars_crate_0_40 as polars;
@@ -334,4 +339,1269 @@ fn test_single_date_record() {
     
     // Verify round-trip conversion
     assert_eq!(records, converted_back);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_pre_epoch_dates_roundtrip() {
+    let records = vec![
+        PersonWithDate {
+            name: "Turn of the century".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
+            age: 0,
+        },
+        PersonWithDate {
+            name: "Day before epoch".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            age: 0,
+        },
+        PersonWithDate {
+            name: "Epoch".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
+            age: 0,
+        },
+    ];
+
+    let df = to_dataframe(&records).unwrap();
+
+    let birth_date_column = df.column("birth_date").unwrap();
+    assert_eq!(birth_date_column.dtype(), &DataType::Date);
+
+    // Date32 stores signed days since the Unix epoch, so pre-epoch dates are negative
+    let days = birth_date_column.cast(&DataType::Int32).unwrap();
+    let days = days.i32().unwrap();
+    assert_eq!(days.get(0), Some(-25567));
+    assert_eq!(days.get(1), Some(-1));
+    assert_eq!(days.get(2), Some(0));
+
+    let converted_back: Vec<PersonWithDate> = from_dataframe(df).unwrap();
+    assert_eq!(records, converted_back);
+}
+
+#[test]
+fn test_list_of_dates_roundtrip() {
+    use polars::prelude::TimeUnit;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Office {
+        city: String,
+        holidays: Vec<NaiveDate>,
+        closures: Option<Vec<NaiveDate>>,
+        inspections: Vec<NaiveDateTime>,
+    }
+
+    let day = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+    let offices = vec![
+        Office {
+            city: "Lyon".to_string(),
+            holidays: vec![day(1, 1), day(5, 1), day(7, 14)],
+            closures: Some(vec![day(8, 15)]),
+            inspections: vec![day(3, 4).and_hms_opt(9, 0, 0).unwrap()],
+        },
+        Office {
+            city: "Oslo".to_string(),
+            holidays: vec![],
+            closures: None,
+            inspections: vec![],
+        },
+        Office {
+            city: "Kyoto".to_string(),
+            holidays: vec![day(1, 2)],
+            closures: Some(vec![]),
+            inspections: vec![day(6, 1).and_hms_opt(14, 30, 0).unwrap(), day(6, 2).and_hms_opt(8, 0, 0).unwrap()],
+        },
+    ];
+
+    let df = to_dataframe(&offices).unwrap();
+    assert_eq!(df.column("holidays").unwrap().dtype(), &DataType::List(Box::new(DataType::Date)));
+    assert_eq!(df.column("closures").unwrap().dtype(), &DataType::List(Box::new(DataType::Date)));
+    assert_eq!(
+        df.column("inspections").unwrap().dtype(),
+        &DataType::List(Box::new(DataType::Datetime(TimeUnit::Nanoseconds, None)))
+    );
+    assert_eq!(df.column("closures").unwrap().null_count(), 1);
+
+    let converted: Vec<Office> = from_dataframe(df).unwrap();
+    assert_eq!(converted, offices);
+}
+
+#[test]
+fn test_historical_dates_roundtrip() {
+    let records = vec![
+        PersonWithDate {
+            name: "Leif Erikson".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1000, 1, 1).unwrap(),
+            age: 0,
+        },
+        PersonWithDate {
+            name: "Before the nanosecond range".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(1677, 9, 20).unwrap(),
+            age: 0,
+        },
+        PersonWithDate {
+            name: "Before the common era".to_string(),
+            birth_date: NaiveDate::from_ymd_opt(-500, 3, 15).unwrap(),
+            age: 0,
+        },
+    ];
+
+    // Date32 counts days in an i32, which reaches millions of years either way
+    let df = to_dataframe(&records).unwrap();
+    assert_eq!(df.column("birth_date").unwrap().dtype(), &DataType::Date);
+    let days = df.column("birth_date").unwrap().cast(&DataType::Int32).unwrap();
+    assert_eq!(days.i32().unwrap().get(0), Some(-354_285));
+
+    let converted_back: Vec<PersonWithDate> = from_dataframe(df).unwrap();
+    assert_eq!(records, converted_back);
+}
+
+#[test]
+fn test_out_of_range_datetime_errors_instead_of_zeroing() {
+    let far_future = NaiveDate::from_ymd_opt(9999, 12, 31).unwrap()
+        .and_hms_opt(23, 59, 59).unwrap();
+
+    let naive_records = vec![
+        EventRecord {
+            event_name: "Far future".to_string(),
+            event_datetime: far_future,
+            description: "Beyond the nanosecond range".to_string(),
+        },
+    ];
+
+    match to_dataframe(&naive_records) {
+        Err(serde_polars::PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("9999-12-31"), "unexpected message: {}", message);
+            assert!(message.contains("timestamp range"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other.map(|df| df.height())),
+    }
+
+    // Dates this old are fine, but not as datetimes
+    let far_past = vec![EventRecord {
+        event_name: "Far past".to_string(),
+        event_datetime: NaiveDate::from_ymd_opt(1000, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        description: "Before the nanosecond range".to_string(),
+    }];
+    match to_dataframe(&far_past) {
+        Err(serde_polars::PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("1000-01-01"), "unexpected message: {}", message);
+            assert!(message.contains("timestamp range"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other.map(|df| df.height())),
+    }
+
+    let utc_records = vec![
+        TimestampRecord {
+            id: 1,
+            created_at: far_future.and_utc(),
+            value: 0.0,
+        },
+    ];
+
+    match to_dataframe(&utc_records) {
+        Err(serde_polars::PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("timestamp range"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other.map(|df| df.height())),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct FlightRecord {
+    flight: String,
+    departs_at: DateTime<Utc>,
+    arrives_at: DateTime<Utc>,
+}
+
+#[test]
+fn test_per_column_timezone_override() {
+    let records = vec![
+        FlightRecord {
+            flight: "JL5".to_string(),
+            departs_at: DateTime::parse_from_rfc3339("2023-06-15T14:30:00Z").unwrap().with_timezone(&Utc),
+            arrives_at: DateTime::parse_from_rfc3339("2023-06-16T02:45:00Z").unwrap().with_timezone(&Utc),
+        },
+    ];
+
+    let options = ConversionOptions::new().column_timezones(HashMap::from([
+        ("departs_at".to_string(), "America/New_York".to_string()),
+        ("arrives_at".to_string(), "Asia/Tokyo".to_string()),
+    ]));
+    let df = to_dataframe_with(&records, &options).unwrap();
+
+    let timezone_of = |name: &str| match df.column(name).unwrap().dtype() {
+        DataType::Datetime(_, Some(tz)) => tz.to_string(),
+        other => panic!("expected a zoned Datetime column for {}, got {:?}", name, other),
+    };
+    assert_eq!(timezone_of("departs_at"), "America/New_York");
+    assert_eq!(timezone_of("arrives_at"), "Asia/Tokyo");
+
+    // The stored instants are still UTC, so reading back restores the original values
+    let converted_back: Vec<FlightRecord> = from_dataframe(df).unwrap();
+    assert_eq!(records, converted_back);
+}
+
+#[test]
+fn test_dst_transition_instants_under_column_timezone() {
+    // Europe/Berlin skips 02:00-03:00 local on 2024-03-31 and repeats 02:00-03:00 on 2024-10-27
+    let instant = |rfc3339: &str| DateTime::parse_from_rfc3339(rfc3339).unwrap().with_timezone(&Utc);
+    let records = vec![
+        // Just before and after the spring-forward gap
+        FlightRecord {
+            flight: "LH1".to_string(),
+            departs_at: instant("2024-03-31T00:59:59Z"),
+            arrives_at: instant("2024-03-31T01:00:00Z"),
+        },
+        // Both passes through the ambiguous 02:30 local time
+        FlightRecord {
+            flight: "LH2".to_string(),
+            departs_at: instant("2024-10-27T00:30:00Z"),
+            arrives_at: instant("2024-10-27T01:30:00Z"),
+        },
+    ];
+
+    // Instants are stored as UTC and only tagged with the zone, so no local time
+    // is ever resolved and gaps or overlaps cannot make a conversion fail
+    let options = ConversionOptions::new().column_timezones(HashMap::from([
+        ("departs_at".to_string(), "Europe/Berlin".to_string()),
+        ("arrives_at".to_string(), "Europe/Berlin".to_string()),
+    ]));
+    let df = to_dataframe_with(&records, &options).unwrap();
+    let converted_back: Vec<FlightRecord> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(records, converted_back);
+}
+
+#[test]
+fn test_fixed_offset_datetime_roundtrip() {
+    use chrono::FixedOffset;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Shift {
+        id: i64,
+        starts_at: DateTime<FixedOffset>,
+        handed_over_at: Option<DateTime<FixedOffset>>,
+    }
+
+    let at = |rfc3339: &str| DateTime::parse_from_rfc3339(rfc3339).unwrap();
+    let records = vec![
+        Shift { id: 1, starts_at: at("2024-03-01T09:00:00+05:30"), handed_over_at: None },
+        Shift {
+            id: 2,
+            starts_at: at("2024-03-01T21:15:30.250+05:30"),
+            handed_over_at: Some(at("2024-03-02T06:00:00+05:30")),
+        },
+    ];
+
+    // The column is tagged with the offset, and holds the UTC instants
+    let df = to_dataframe(&records).expect("Failed to convert fixed-offset datetimes");
+    assert!(matches!(
+        df.column("starts_at").unwrap().dtype(),
+        DataType::Datetime(_, Some(tz)) if tz.as_str() == "+05:30"
+    ));
+    let instants: Vec<Option<i64>> =
+        df.column("starts_at").unwrap().cast(&DataType::Int64).unwrap().i64().unwrap().into_iter().collect();
+    assert_eq!(instants[0], at("2024-03-01T03:30:00Z").timestamp_nanos_opt());
+
+    // Values come back at the column's offset, not at UTC
+    let converted: Vec<Shift> = from_dataframe(df).expect("Failed to convert fixed-offset datetimes back");
+    assert_eq!(converted, records);
+    for (converted, record) in converted.iter().zip(&records) {
+        assert_eq!(converted.starts_at.offset(), record.starts_at.offset());
+        assert_eq!(converted.starts_at.to_rfc3339(), record.starts_at.to_rfc3339());
+    }
+    assert_eq!(converted[1].handed_over_at.unwrap().offset(), &FixedOffset::east_opt(19_800).unwrap());
+
+    // Records at another offset keep their instant, read back at the first record's offset
+    let mixed = vec![
+        Shift { id: 3, starts_at: at("2024-03-01T09:00:00-08:00"), handed_over_at: None },
+        Shift { id: 4, starts_at: at("2024-03-01T12:00:00+01:00"), handed_over_at: None },
+    ];
+    let converted: Vec<Shift> = from_dataframe(to_dataframe(&mixed).unwrap()).unwrap();
+    assert_eq!(converted, mixed);
+    assert_eq!(converted[1].starts_at.to_rfc3339(), "2024-03-01T03:00:00-08:00");
+
+    // Fields of other datetime types read the same instants
+    #[derive(Debug, Deserialize)]
+    struct UtcShift {
+        starts_at: DateTime<Utc>,
+    }
+    let utc: Vec<UtcShift> = from_dataframe(to_dataframe(&records).unwrap()).unwrap();
+    assert_eq!(utc[0].starts_at, at("2024-03-01T03:30:00Z"));
+}
+
+#[test]
+fn test_local_datetime_roundtrip() {
+    use chrono::Local;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reminder {
+        id: i64,
+        due: DateTime<Local>,
+    }
+
+    let due = DateTime::parse_from_rfc3339("2024-07-04T18:45:00.5Z").unwrap().with_timezone(&Local);
+    let records = vec![Reminder { id: 1, due }];
+
+    // Tagged with the system's offset at conversion time; the stored instant is UTC
+    let df = to_dataframe(&records).expect("Failed to convert local datetimes");
+    let system_offset = Local::now().offset().to_string();
+    assert!(matches!(
+        df.column("due").unwrap().dtype(),
+        DataType::Datetime(_, Some(tz)) if tz.as_str() == system_offset
+    ));
+
+    let converted: Vec<Reminder> = from_dataframe(df).expect("Failed to convert local datetimes back");
+    assert_eq!(converted, records);
+}
+
+#[test]
+fn test_conversion_stats_report_rows_and_chrono_columns() {
+    let records: Vec<MixedTemporalRecord> = (0..5)
+        .map(|i| MixedTemporalRecord {
+            id: i,
+            birth_date: NaiveDate::from_ymd_opt(1990, 1, 1 + i as u32).unwrap(),
+            last_login: NaiveDate::from_ymd_opt(2023, 6, 1).unwrap().and_hms_opt(8, i as u32, 0).unwrap(),
+            created_at: DateTime::from_timestamp(1_700_000_000 + i, 0).unwrap(),
+            score: i as f64 * 1.5,
+            name: format!("Person {}", i),
+            active: i % 2 == 0,
+        })
+        .collect();
+
+    let (df, stats) = to_dataframe_with_stats(&records, &ConversionOptions::new()).unwrap();
+    assert_eq!(df.height(), 5);
+    assert_eq!(stats.rows, 5);
+    assert_eq!(stats.chrono_columns, 3);
+    assert_eq!(stats.dictionary_columns, 0);
+
+    let (converted_back, stats) =
+        from_dataframe_with_stats::<MixedTemporalRecord>(df, &ConversionOptions::new()).unwrap();
+    assert_eq!(records, converted_back);
+    assert_eq!(stats.rows, 5);
+    // UTC and tz-naive columns are read as they are
+    assert_eq!(stats.chrono_columns, 0);
+
+    // Only columns tagged with a non-UTC zone need relabelling on the way back
+    let options = ConversionOptions::new().column_timezones(HashMap::from([(
+        "created_at".to_string(),
+        "Europe/Paris".to_string(),
+    )]));
+    let (df, _) = to_dataframe_with_stats(&records, &options).unwrap();
+    let (_, stats) = from_dataframe_with_stats::<MixedTemporalRecord>(df, &options).unwrap();
+    assert_eq!(stats.chrono_columns, 1);
+}
+
+#[test]
+fn test_naive_datetime_column_into_datetime_utc_field() {
+    use polars::prelude::{df, NamedFrom, Series, TimeUnit};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Observation {
+        id: i64,
+        observed_at: DateTime<Utc>,
+        recorded_at: NaiveDateTime,
+        confirmed_at: Option<DateTime<Utc>>,
+    }
+
+    // An external frame whose Datetime columns carry no timezone at all
+    let nanos = [1_686_839_400_000_000_000i64, 1_700_000_000_123_456_789i64];
+    let naive_datetime = |name: &str| {
+        Series::new(name.into(), nanos)
+            .cast(&DataType::Datetime(TimeUnit::Nanoseconds, None))
+            .unwrap()
+    };
+    let mut df = df! {
+        "id" => [1i64, 2i64],
+    }
+    .unwrap();
+    for name in ["observed_at", "recorded_at", "confirmed_at"] {
+        df.with_column(naive_datetime(name)).unwrap();
+    }
+
+    let observations: Vec<Observation> = from_dataframe(df).unwrap();
+
+    let expected: Vec<DateTime<Utc>> = nanos.iter().map(|&n| DateTime::from_timestamp_nanos(n)).collect();
+    for (observation, expected) in observations.iter().zip(&expected) {
+        assert_eq!(observation.observed_at, *expected);
+        assert_eq!(observation.recorded_at, expected.naive_utc());
+        assert_eq!(observation.confirmed_at, Some(*expected));
+    }
+}
+
+#[test]
+fn test_polars_built_microsecond_datetimes() {
+    use polars::prelude::{df, NamedFrom, Series, TimeUnit};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        id: i64,
+        taken_at: DateTime<Utc>,
+        logged_at: NaiveDateTime,
+        synced_at: Option<DateTime<Utc>>,
+    }
+
+    // Microseconds are Polars' default unit, as in frames read from Parquet or CSV
+    let micros = [1_686_839_400_123_456i64, -86_400_000_001i64];
+    let microsecond_column = |name: &str| {
+        Series::new(name.into(), micros)
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))
+            .unwrap()
+    };
+    let mut df = df! {
+        "id" => [1i64, 2i64],
+    }
+    .unwrap();
+    for name in ["taken_at", "logged_at"] {
+        df.with_column(microsecond_column(name)).unwrap();
+    }
+
+    // A UTC-tagged microsecond column, with the zone of a frame written by the crate
+    let instants: Vec<DateTime<Utc>> = micros.iter().map(|&n| DateTime::from_timestamp_micros(n).unwrap()).collect();
+    let written = to_dataframe(&[Reading {
+        id: 0,
+        taken_at: instants[0],
+        logged_at: instants[0].naive_utc(),
+        synced_at: None,
+    }])
+    .unwrap();
+    let DataType::Datetime(_, zone) = written.column("taken_at").unwrap().dtype() else {
+        panic!("expected a Datetime column");
+    };
+    let synced_at = microsecond_column("synced_at")
+        .cast(&DataType::Datetime(TimeUnit::Microseconds, zone.clone()))
+        .unwrap();
+    df.with_column(synced_at).unwrap();
+
+    let readings: Vec<Reading> = from_dataframe(df).unwrap();
+    let expected: Vec<Reading> = instants
+        .iter()
+        .zip(1..)
+        .map(|(instant, id)| Reading {
+            id,
+            taken_at: *instant,
+            logged_at: instant.naive_utc(),
+            synced_at: Some(*instant),
+        })
+        .collect();
+    assert_eq!(readings, expected);
+}
+
+#[test]
+fn test_merge_slices_with_chrono_fields() {
+    let event = |id: i64, day: u32| MixedTemporalRecord {
+        id,
+        name: format!("Person {}", id),
+        birth_date: NaiveDate::from_ymd_opt(1990, 2, day).unwrap(),
+        last_login: NaiveDate::from_ymd_opt(2024, 2, day).unwrap().and_hms_opt(9, 30, 0).unwrap(),
+        created_at: DateTime::from_timestamp(1_700_000_000 + id, 0).unwrap(),
+        score: id as f64 * 2.5,
+        active: id % 2 == 1,
+    };
+
+    let from_api = vec![event(1, 1), event(2, 2)];
+    let from_file = vec![event(3, 3)];
+    let from_queue = vec![event(4, 4), event(5, 5), event(6, 6)];
+    let no_events: Vec<MixedTemporalRecord> = Vec::new();
+
+    let df = merge_to_dataframe(&[&from_api, &no_events, &from_file, &from_queue]).unwrap();
+    assert_eq!(df.height(), 6);
+    assert_eq!(df.column("birth_date").unwrap().dtype(), &DataType::Date);
+    assert!(matches!(df.column("last_login").unwrap().dtype(), DataType::Datetime(_, None)));
+    assert!(matches!(df.column("created_at").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+
+    let merged: Vec<MixedTemporalRecord> = from_dataframe(df).unwrap();
+    let expected: Vec<MixedTemporalRecord> = [from_api, from_file, from_queue].concat();
+    assert_eq!(merged, expected);
+
+    // Only a merge of nothing but empty slices is empty input
+    match merge_to_dataframe::<MixedTemporalRecord>(&[&no_events, &[]]) {
+        Err(PolarsSerdeError::EmptyInput) => {}
+        other => panic!("expected EmptyInput, got {:?}", other.map(|df| df.height())),
+    }
+}
+
+#[test]
+fn test_chrono_columns_already_typed_by_tracing() {
+    let records = vec![PersonWithDate {
+        name: "Alice".to_string(),
+        birth_date: NaiveDate::from_ymd_opt(1990, 5, 15).unwrap(),
+        age: 33,
+    }];
+
+    // serde_arrow writes the date straight into a Date32 column, leaving nothing to convert
+    let options = ConversionOptions::new().tracing(|tracing| {
+        tracing
+            .overwrite("birth_date", serde_json::json!({"name": "birth_date", "data_type": "Date32"}))
+            .unwrap()
+    });
+    let df = to_dataframe_with(&records, &options).unwrap();
+    assert_eq!(df.column("birth_date").unwrap().dtype(), &DataType::Date);
+    let converted_back: Vec<PersonWithDate> = from_dataframe(df).unwrap();
+    assert_eq!(records, converted_back);
+
+    // A column type the chrono pass cannot handle is reported precisely
+    let options = ConversionOptions::new().tracing(|tracing| {
+        tracing
+            .overwrite("birth_date", serde_json::json!({"name": "birth_date", "data_type": "Date64"}))
+            .unwrap()
+    });
+    match to_dataframe_with(&records, &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => {
+            assert!(message.contains("'birth_date'"), "unexpected message: {}", message);
+            assert!(message.contains("Date64"), "unexpected message: {}", message);
+        }
+        other => panic!("expected ConversionError, got {:?}", other.map(|df| df.height())),
+    }
+}
+
+#[test]
+fn test_temporal_columns_of_hand_built_record_batch() {
+    use serde_polars::arrow::array::{
+        Array, ArrayRef, Date32Array, Int64Array, StringArray, TimestampNanosecondArray,
+    };
+    use serde_polars::arrow::datatypes::{DataType as ArrowDataType, Field, Schema, TimeUnit};
+    use serde_polars::arrow::record_batch::RecordBatch;
+    use std::sync::Arc;
+
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", ArrowDataType::Int64, false),
+        Field::new("day", ArrowDataType::Utf8, true),
+        Field::new("logged_at", ArrowDataType::Utf8, false),
+        Field::new("received_at", ArrowDataType::Utf8, false),
+    ]));
+    let columns: Vec<ArrayRef> = vec![
+        Arc::new(Int64Array::from(vec![1, 2])),
+        Arc::new(StringArray::from(vec![Some("2024-02-29"), None])),
+        Arc::new(StringArray::from(vec!["2024-02-29T12:00:00", "2024-03-01T00:00:00.5"])),
+        Arc::new(StringArray::from(vec!["2024-02-29T12:00:00Z", "2024-03-01T00:00:00+00:00"])),
+    ];
+    let batch = RecordBatch::try_new(schema, columns).unwrap();
+
+    let fields = HashMap::from([
+        ("day".to_string(), ChronoKind::NaiveDate),
+        ("logged_at".to_string(), ChronoKind::NaiveDateTime),
+        ("received_at".to_string(), ChronoKind::DateTimeUtc),
+    ]);
+    let normalized = normalize_temporal_columns(batch, &fields).unwrap();
+
+    let schema = normalized.schema();
+    assert_eq!(schema.field(0).data_type(), &ArrowDataType::Int64);
+    assert_eq!(schema.field(1).data_type(), &ArrowDataType::Date32);
+    assert_eq!(schema.field(2).data_type(), &ArrowDataType::Timestamp(TimeUnit::Nanosecond, None));
+    assert_eq!(
+        schema.field(3).data_type(),
+        &ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+
+    let days = normalized.column(1).as_any().downcast_ref::<Date32Array>().unwrap();
+    assert_eq!(days.value_as_date(0), NaiveDate::from_ymd_opt(2024, 2, 29));
+    assert!(days.is_null(1));
+    let logged = normalized.column(2).as_any().downcast_ref::<TimestampNanosecondArray>().unwrap();
+    assert_eq!(
+        logged.value_as_datetime(1),
+        NaiveDate::from_ymd_opt(2024, 3, 1).unwrap().and_hms_milli_opt(0, 0, 0, 500)
+    );
+
+    // Zones other than UTC are relabelled; the stored instants stay the same
+    let paris = Arc::new(Schema::new(vec![Field::new(
+        "received_at",
+        ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("Europe/Paris".into())),
+        false,
+    )]));
+    let instants: ArrayRef = Arc::new(
+        TimestampNanosecondArray::from(vec![1_709_208_000_000_000_000]).with_timezone("Europe/Paris"),
+    );
+    let batch = RecordBatch::try_new(paris, vec![instants]).unwrap();
+    let denormalized = denormalize_temporal_columns(batch).unwrap();
+    assert_eq!(
+        denormalized.schema().field(0).data_type(),
+        &ArrowDataType::Timestamp(TimeUnit::Nanosecond, Some("UTC".into()))
+    );
+    let received = denormalized.column(0).as_any().downcast_ref::<TimestampNanosecondArray>().unwrap();
+    assert_eq!(received.value(0), 1_709_208_000_000_000_000);
+}
+
+#[test]
+fn test_parse_string_columns_as_dates() {
+    use polars::prelude::df;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Shipment {
+        id: i64,
+        shipped_on: NaiveDate,
+        delivered_at: Option<NaiveDateTime>,
+    }
+
+    // As read from a CSV file without a schema: every temporal value is a string,
+    // and the dates use ordinal and ISO week notation that chrono's serde rejects
+    let df = df! {
+        "id" => [1i64, 2i64],
+        "shipped_on" => ["2024-061", "2024-W10-1"],
+        "delivered_at" => [Some("2024-03-02T16:45:00"), None],
+    }
+    .unwrap();
+    assert!(from_dataframe::<Shipment>(df.clone()).is_err());
+
+    let options = ConversionOptions::new().parse_columns_as(HashMap::from([
+        ("shipped_on".to_string(), ChronoKind::NaiveDate),
+        ("delivered_at".to_string(), ChronoKind::NaiveDateTime),
+    ]));
+    let shipments: Vec<Shipment> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(shipments, vec![
+        Shipment {
+            id: 1,
+            shipped_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            delivered_at: NaiveDate::from_ymd_opt(2024, 3, 2).unwrap().and_hms_opt(16, 45, 0),
+        },
+        Shipment {
+            id: 2,
+            shipped_on: NaiveDate::from_ymd_opt(2024, 3, 4).unwrap(),
+            delivered_at: None,
+        },
+    ]);
+}
+
+#[test]
+fn test_unparseable_temporal_strings_as_nulls() {
+    use polars::prelude::df;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        id: i64,
+        taken_on: Option<NaiveDate>,
+        synced_at: Option<NaiveDateTime>,
+    }
+
+    let df = df! {
+        "id" => [1i64, 2i64, 3i64],
+        "taken_on" => [Some("2024-05-01"), Some("sometime in May"), None],
+        "synced_at" => [Some("2024-05-01T08:30:00"), Some("2024-05-01T08:30:00"), Some("n/a")],
+    }
+    .unwrap();
+    let options = ConversionOptions::new().parse_columns_as(HashMap::from([
+        ("taken_on".to_string(), ChronoKind::NaiveDate),
+        ("synced_at".to_string(), ChronoKind::NaiveDateTime),
+    ]));
+
+    match from_dataframe_with::<Reading>(df.clone(), &options) {
+        Err(PolarsSerdeError::ConversionError { message }) => assert!(message.contains("sometime in May")),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+
+    let options = options.on_temporal_parse_error(OnParseError::Null);
+    let readings: Vec<Reading> = from_dataframe_with(df, &options).unwrap();
+    let synced_at = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap().and_hms_opt(8, 30, 0);
+    assert_eq!(readings, vec![
+        Reading { id: 1, taken_on: NaiveDate::from_ymd_opt(2024, 5, 1), synced_at },
+        Reading { id: 2, taken_on: None, synced_at },
+        Reading { id: 3, taken_on: None, synced_at: None },
+    ]);
+}
+
+#[test]
+fn test_weekday_and_month_roundtrip() {
+    use chrono::{Month, Weekday};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct CalendarRecord {
+        day: Weekday,
+        month: Month,
+        review_day: Option<Weekday>,
+    }
+
+    let records: Vec<CalendarRecord> = (0u8..12)
+        .map(|i| CalendarRecord {
+            day: Weekday::try_from(i % 7).unwrap(),
+            month: Month::try_from(i + 1).unwrap(),
+            review_day: if i % 2 == 0 { Some(Weekday::try_from(6 - i % 7).unwrap()) } else { None },
+        })
+        .collect();
+
+    // Stored as numbers: weekdays from Monday as 0, months from January as 1
+    let df = to_dataframe(&records).expect("Failed to convert calendar records");
+    assert_eq!(df.column("day").unwrap().dtype(), &DataType::UInt8);
+    assert_eq!(df.column("month").unwrap().dtype(), &DataType::UInt8);
+    assert_eq!(df.column("review_day").unwrap().dtype(), &DataType::UInt8);
+    let days: Vec<Option<u8>> = df.column("day").unwrap().u8().unwrap().into_iter().collect();
+    assert_eq!(days[..7], [Some(0), Some(1), Some(2), Some(3), Some(4), Some(5), Some(6)]);
+    let months: Vec<Option<u8>> = df.column("month").unwrap().u8().unwrap().into_iter().collect();
+    assert_eq!(months, (1u8..=12).map(Some).collect::<Vec<_>>());
+
+    let converted: Vec<CalendarRecord> = from_dataframe(df).expect("Failed to convert calendar records back");
+    assert_eq!(converted, records);
+}
+
+#[test]
+fn test_calendar_interval_roundtrip() {
+    use serde_polars::arrow::datatypes::IntervalMonthDayNano;
+    use serde_polars::CalendarInterval;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Subscription {
+        id: i64,
+        billing_period: CalendarInterval,
+        grace_period: Option<CalendarInterval>,
+    }
+
+    let records = vec![
+        Subscription { id: 1, billing_period: CalendarInterval::months(3), grace_period: None },
+        Subscription {
+            id: 2,
+            billing_period: CalendarInterval::new(1, 2, 3_600_000_000_000),
+            grace_period: Some(CalendarInterval::days(14)),
+        },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert intervals");
+    assert!(matches!(df.column("billing_period").unwrap().dtype(), DataType::Struct(_)));
+
+    let converted: Vec<Subscription> = from_dataframe(df).expect("Failed to convert intervals back");
+    assert_eq!(converted, records);
+    assert_eq!(converted[0].billing_period, CalendarInterval { months: 3, days: 0, nanos: 0 });
+
+    // Interchangeable with Arrow's month-day-nano interval and chrono's months
+    let arrow_interval: IntervalMonthDayNano = converted[1].billing_period.into();
+    assert_eq!(arrow_interval, IntervalMonthDayNano::new(1, 2, 3_600_000_000_000));
+    assert_eq!(CalendarInterval::from(arrow_interval), converted[1].billing_period);
+    assert_eq!(CalendarInterval::try_from(chrono::Months::new(3)).unwrap(), CalendarInterval::months(3));
+}
+
+#[test]
+fn test_chrono_duration_roundtrip() {
+    use chrono::Duration;
+    use polars::prelude::TimeUnit;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TimeEntry {
+        id: i64,
+        elapsed: Duration,
+        paused: Option<Duration>,
+    }
+
+    let records = vec![
+        TimeEntry {
+            id: 1,
+            elapsed: Duration::days(3) + Duration::hours(4) + Duration::milliseconds(250),
+            paused: None,
+        },
+        TimeEntry {
+            id: 2,
+            elapsed: -(Duration::days(2) + Duration::nanoseconds(1)),
+            paused: Some(Duration::seconds(90) + Duration::microseconds(500)),
+        },
+        TimeEntry { id: 3, elapsed: Duration::zero(), paused: Some(-Duration::milliseconds(1_500)) },
+    ];
+
+    let df = to_dataframe(&records).expect("Failed to convert durations");
+    assert_eq!(df.column("elapsed").unwrap().dtype(), &DataType::Duration(TimeUnit::Nanoseconds));
+    assert_eq!(df.column("paused").unwrap().dtype(), &DataType::Duration(TimeUnit::Nanoseconds));
+    let paused: Vec<Option<i64>> = df.column("paused").unwrap().cast(&DataType::Int64).unwrap().i64().unwrap().into_iter().collect();
+    assert_eq!(paused, vec![None, Some(90_000_500_000), Some(-1_500_000_000)]);
+    serde_polars::can_deserialize::<TimeEntry>(&df).expect("Duration columns should suit Duration fields");
+
+    let converted: Vec<TimeEntry> = from_dataframe(df).expect("Failed to convert durations back");
+    assert_eq!(converted, records);
+
+    // A batch without any optional duration still gives a Duration column
+    let df = to_dataframe(&records[..1]).expect("Failed to convert a record without pauses");
+    assert_eq!(df.column("paused").unwrap().dtype(), &DataType::Duration(TimeUnit::Nanoseconds));
+    assert_eq!(from_dataframe::<TimeEntry>(df).unwrap(), records[..1]);
+}
+
+#[test]
+fn test_timezone_aware_column_into_naive_datetime_field() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Written {
+        id: i64,
+        taken_at: DateTime<Utc>,
+        checked_at: Option<DateTime<Utc>>,
+        logged_at: DateTime<Utc>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        id: i64,
+        taken_at: NaiveDateTime,
+        checked_at: Option<NaiveDateTime>,
+        logged_at: DateTime<Utc>,
+    }
+
+    // Every Datetime column of the frame is tagged with a timezone
+    let instants: Vec<DateTime<Utc>> = [1_686_839_400_000_000_000i64, 1_700_000_000_123_456_789i64]
+        .iter()
+        .map(|&n| DateTime::from_timestamp_nanos(n))
+        .collect();
+    let written: Vec<Written> = instants
+        .iter()
+        .enumerate()
+        .map(|(i, &instant)| Written { id: i as i64, taken_at: instant, checked_at: Some(instant), logged_at: instant })
+        .collect();
+    let options = ConversionOptions::new()
+        .column_timezones(HashMap::from([("checked_at".to_string(), "America/New_York".to_string())]));
+    let df = to_dataframe_with(&written, &options).unwrap();
+    assert!(matches!(df.column("taken_at").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+
+    // Naive fields receive the UTC wall-clock time of each instant
+    let readings: Vec<Reading> = from_dataframe(df).unwrap();
+    for (reading, expected) in readings.iter().zip(&instants) {
+        assert_eq!(reading.taken_at, expected.naive_utc());
+        assert_eq!(reading.checked_at, Some(expected.naive_utc()));
+        assert_eq!(reading.logged_at, *expected);
+    }
+}
+
+#[test]
+fn test_temporal_columns_as_integers() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Stored {
+        id: i64,
+        calc_min_date: NaiveDate,
+        closed_on: Option<NaiveDate>,
+        updated_at: NaiveDateTime,
+    }
+
+    // Models the temporal columns as raw epoch counts, without chrono
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct RawRecord {
+        id: i64,
+        calc_min_date: i32,
+        closed_on: Option<i32>,
+        updated_at: i64,
+    }
+
+    let stored = vec![
+        Stored {
+            id: 1,
+            calc_min_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            closed_on: None,
+            updated_at: NaiveDate::from_ymd_opt(1970, 1, 2).unwrap().and_hms_opt(0, 0, 1).unwrap(),
+        },
+        Stored {
+            id: 2,
+            calc_min_date: NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            closed_on: Some(NaiveDate::from_ymd_opt(1970, 1, 11).unwrap()),
+            updated_at: NaiveDate::from_ymd_opt(1970, 1, 1).unwrap().and_hms_opt(0, 0, 0).unwrap(),
+        },
+    ];
+    // The same Date/Datetime column types Polars produces when reading Parquet
+    let df = to_dataframe(&stored).unwrap();
+    assert_eq!(df.column("calc_min_date").unwrap().dtype(), &DataType::Date);
+
+    let options = ConversionOptions::new().temporal_as_integers(true);
+    let records: Vec<RawRecord> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(records, vec![
+        RawRecord { id: 1, calc_min_date: 19_723, closed_on: None, updated_at: 86_401_000_000_000 },
+        RawRecord { id: 2, calc_min_date: -1, closed_on: Some(10), updated_at: 0 },
+    ]);
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn test_parquet_date_columns_as_integers() {
+    use serde_polars::{read_parquet_with, write_parquet};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Stored {
+        id: i64,
+        calc_min_date: NaiveDate,
+        closed_on: Option<NaiveDate>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct RawRecord {
+        id: i64,
+        calc_min_date: i32,
+        closed_on: Option<i32>,
+    }
+
+    let stored = vec![
+        Stored { id: 1, calc_min_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(), closed_on: None },
+        Stored {
+            id: 2,
+            calc_min_date: NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            closed_on: Some(NaiveDate::from_ymd_opt(1970, 1, 11).unwrap()),
+        },
+    ];
+    let mut file = Vec::new();
+    write_parquet(&stored, &mut file).unwrap();
+
+    let options = ConversionOptions::new().temporal_as_integers(true);
+    let records: Vec<RawRecord> = read_parquet_with(file.as_slice(), &options).unwrap();
+    assert_eq!(records, vec![
+        RawRecord { id: 1, calc_min_date: 19_723, closed_on: None },
+        RawRecord { id: 2, calc_min_date: -1, closed_on: Some(10) },
+    ]);
+}
+
+#[test]
+fn test_time_columns_as_integers() {
+    use polars::prelude::df;
+
+    // Times of day as nanoseconds since midnight, without chrono
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Shift {
+        id: i64,
+        starts_at: i64,
+        ends_at: Option<i64>,
+    }
+
+    let nine_thirty = (9 * 3600 + 30 * 60) * 1_000_000_000_i64;
+    let last_nanosecond = 86_400 * 1_000_000_000_i64 - 1;
+    let mut df = df! {
+        "id" => [1i64, 2],
+        "starts_at" => [nine_thirty, 0],
+        "ends_at" => [Some(last_nanosecond), None],
+    }
+    .unwrap();
+    for name in ["starts_at", "ends_at"] {
+        let times = df.column(name).unwrap().cast(&DataType::Time).unwrap();
+        df.with_column(times).unwrap();
+    }
+    assert_eq!(df.column("starts_at").unwrap().dtype(), &DataType::Time);
+
+    let options = ConversionOptions::new().temporal_as_integers(true);
+    let shifts: Vec<Shift> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(shifts, vec![
+        Shift { id: 1, starts_at: nine_thirty, ends_at: Some(last_nanosecond) },
+        Shift { id: 2, starts_at: 0, ends_at: None },
+    ]);
+}
+
+#[test]
+fn test_never_convert_columns() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Booking {
+        id: i64,
+        calc_min_date: i32,
+        booked_on: NaiveDate,
+        updated_at: Option<i64>,
+    }
+
+    let bookings = vec![
+        Booking {
+            id: 1,
+            calc_min_date: 19_723,
+            booked_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+            updated_at: Some(86_401),
+        },
+        Booking {
+            id: 2,
+            calc_min_date: -1,
+            booked_on: NaiveDate::from_ymd_opt(1999, 12, 31).unwrap(),
+            updated_at: None,
+        },
+    ];
+
+    // Parsing the integer column as a date is overridden, as is the chrono field
+    let options = ConversionOptions::new()
+        .never_convert_columns(vec!["calc_min_date".to_string(), "booked_on".to_string()])
+        .parse_columns_as(HashMap::from([("calc_min_date".to_string(), ChronoKind::NaiveDate)]))
+        .temporal_as_integers(true);
+
+    let df = to_dataframe_with(&bookings, &options).unwrap();
+    assert_eq!(df.column("calc_min_date").unwrap().dtype(), &DataType::Int32);
+    assert_eq!(df.column("booked_on").unwrap().dtype(), &DataType::String);
+
+    let converted: Vec<Booking> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(converted, bookings);
+
+    // Date columns named in the list keep their dtype even with `temporal_as_integers`
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Dated {
+        id: i64,
+        calc_min_date: NaiveDate,
+    }
+
+    let dated = vec![Dated { id: 1, calc_min_date: NaiveDate::from_ymd_opt(2024, 1, 1).unwrap() }];
+    let df = to_dataframe(&dated).unwrap();
+    let options = ConversionOptions::new()
+        .temporal_as_integers(true)
+        .never_convert_columns(vec!["calc_min_date".to_string()]);
+    let converted: Vec<Dated> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(converted, dated);
+}
+
+/// Day-first timestamps, as a serde `with` module for `NaiveDateTime` fields
+mod day_first {
+    use chrono::NaiveDateTime;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub const FORMAT: &str = "%d/%m/%Y %H:%M";
+
+    pub fn serialize<S: Serializer>(value: &NaiveDateTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&value.format(FORMAT).to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NaiveDateTime, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        NaiveDateTime::parse_from_str(&text, FORMAT).map_err(serde::de::Error::custom)
+    }
+}
+
+#[test]
+fn test_timestamp_output_format_for_custom_with_module() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Stored {
+        id: i64,
+        opened_at: NaiveDateTime,
+        closed_at: NaiveDateTime,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Ticket {
+        id: i64,
+        #[serde(with = "day_first")]
+        opened_at: NaiveDateTime,
+        closed_at: NaiveDateTime,
+    }
+
+    let at = |day: u32, hour: u32| NaiveDate::from_ymd_opt(2024, 3, day).unwrap().and_hms_opt(hour, 15, 0).unwrap();
+    let stored = vec![
+        Stored { id: 1, opened_at: at(1, 9), closed_at: at(2, 17) },
+        Stored { id: 2, opened_at: at(14, 23), closed_at: at(15, 8) },
+    ];
+    let df = to_dataframe(&stored).unwrap();
+    assert!(matches!(df.column("opened_at").unwrap().dtype(), DataType::Datetime(_, None)));
+
+    // The default RFC 3339-style strings do not match the module's format
+    assert!(from_dataframe::<Ticket>(df.clone()).is_err());
+
+    // The chrono field keeps its usual format
+    let options = ConversionOptions::new().timestamp_output_format(day_first::FORMAT.to_string());
+    let tickets: Vec<Ticket> = from_dataframe_with(df, &options).unwrap();
+    assert_eq!(tickets, vec![
+        Ticket { id: 1, opened_at: at(1, 9), closed_at: at(2, 17) },
+        Ticket { id: 2, opened_at: at(14, 23), closed_at: at(15, 8) },
+    ]);
+}
+
+#[test]
+fn test_newtype_wrapped_chrono_fields() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct EventDate(NaiveDate);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Timestamp(DateTime<Utc>);
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Festival {
+        name: String,
+        opens: EventDate,
+        closes: Option<EventDate>,
+        announced_at: Timestamp,
+        rain_dates: Vec<EventDate>,
+    }
+
+    let date = |day| EventDate(NaiveDate::from_ymd_opt(2024, 7, day).unwrap());
+    let festivals = vec![
+        Festival {
+            name: "Jazz in the Park".to_string(),
+            opens: date(12),
+            closes: Some(date(14)),
+            announced_at: Timestamp(DateTime::from_timestamp(1_704_067_200, 0).unwrap()),
+            rain_dates: vec![date(20)],
+        },
+        Festival {
+            name: "Open Air Cinema".to_string(),
+            opens: date(1),
+            closes: None,
+            announced_at: Timestamp(DateTime::from_timestamp(1_706_745_600, 0).unwrap()),
+            rain_dates: vec![],
+        },
+    ];
+
+    let df = to_dataframe(&festivals).unwrap();
+    assert_eq!(df.column("opens").unwrap().dtype(), &DataType::Date);
+    assert_eq!(df.column("closes").unwrap().dtype(), &DataType::Date);
+    assert!(matches!(df.column("announced_at").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+    assert!(matches!(df.column("rain_dates").unwrap().dtype(), DataType::List(_)));
+
+    let converted: Vec<Festival> = from_dataframe(df).unwrap();
+    assert_eq!(converted, festivals);
+}
+
+#[test]
+fn test_chrono_detection_looks_only_at_top_level_fields() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Shipment {
+        date: NaiveDate,
+        carrier: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        // Shares its name with the date of the shipments, but holds free text
+        date: String,
+        shipments: Vec<Shipment>,
+        placed_on: NaiveDate,
+    }
+
+    let orders = vec![Order {
+        date: "next Tuesday".to_string(),
+        shipments: vec![Shipment {
+            date: NaiveDate::from_ymd_opt(2024, 3, 5).unwrap(),
+            carrier: "Rail".to_string(),
+        }],
+        placed_on: NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+    }];
+
+    let detected = serde_polars::detect_chrono_types(&orders[0]).unwrap();
+    assert_eq!(detected, HashMap::from([("placed_on".to_string(), "NaiveDate".to_string())]));
+
+    let df = to_dataframe(&orders).unwrap();
+    assert_eq!(df.column("date").unwrap().dtype(), &DataType::String);
+    let converted: Vec<Order> = from_dataframe(df).unwrap();
+    assert_eq!(converted, orders);
+}
+
+#[test]
+fn test_keyed_map_to_dataframe() {
+    use serde_polars::to_dataframe_keyed;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Member {
+        name: String,
+        joined: NaiveDate,
+    }
+
+    let joined = |day: u32| NaiveDate::from_ymd_opt(2024, 1, day).unwrap();
+    let members: HashMap<u64, Member> = HashMap::from([
+        (7, Member { name: "Alice".to_string(), joined: joined(3) }),
+        (3, Member { name: "Bob".to_string(), joined: joined(9) }),
+        (12, Member { name: "Carol".to_string(), joined: joined(21) }),
+    ]);
+
+    let df = to_dataframe_keyed(&members, "member_id").unwrap();
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["member_id", "name", "joined"]);
+    assert_eq!(df.column("member_id").unwrap().dtype(), &DataType::UInt64);
+    assert_eq!(df.column("joined").unwrap().dtype(), &DataType::Date);
+
+    // Rows of a HashMap come in any order, so compare by key
+    #[derive(Debug, Deserialize)]
+    struct KeyedMember {
+        member_id: u64,
+        name: String,
+        joined: NaiveDate,
+    }
+    let rows: Vec<KeyedMember> = from_dataframe(df).unwrap();
+    assert_eq!(rows.len(), 3);
+    for row in rows {
+        assert_eq!(members[&row.member_id], Member { name: row.name, joined: row.joined });
+    }
+
+    // A BTreeMap gives rows sorted by key
+    let sorted: BTreeMap<u64, Member> = members.clone().into_iter().collect();
+    let df = to_dataframe_keyed(&sorted, "member_id").unwrap();
+    let ids: Vec<Option<u64>> = df.column("member_id").unwrap().u64().unwrap().into_iter().collect();
+    assert_eq!(ids, vec![Some(3), Some(7), Some(12)]);
+
+    // The key column must not shadow a record field
+    assert!(matches!(
+        to_dataframe_keyed(&members, "name"),
+        Err(PolarsSerdeError::ConversionError { .. })
+    ));
+}
+
+#[test]
+fn test_constant_columns() {
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Order {
+        id: i64,
+        placed_on: NaiveDate,
+    }
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct IngestedOrder {
+        id: i64,
+        placed_on: NaiveDate,
+        batch_id: String,
+        ingested_at: DateTime<Utc>,
+        weight: f64,
+    }
+
+    let placed_on = NaiveDate::from_ymd_opt(2024, 5, 30).unwrap();
+    let orders = vec![Order { id: 1, placed_on }, Order { id: 2, placed_on }];
+    let ingested_at = DateTime::parse_from_rfc3339("2024-06-01T08:15:00Z").unwrap().with_timezone(&Utc);
+
+    let options = ConversionOptions::new().add_constant_columns(vec![
+        ("batch_id".to_string(), ScalarValue::from("batch-17")),
+        ("ingested_at".to_string(), ScalarValue::from(ingested_at)),
+        ("weight".to_string(), ScalarValue::Float64(0.5)),
+    ]);
+    let df = to_dataframe_with(&orders, &options).unwrap();
+
+    let names: Vec<String> = df.get_column_names().iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, vec!["id", "placed_on", "batch_id", "ingested_at", "weight"]);
+    assert_eq!(df.column("batch_id").unwrap().dtype(), &DataType::String);
+    assert!(matches!(df.column("ingested_at").unwrap().dtype(), DataType::Datetime(_, Some(_))));
+
+    let ingested: Vec<IngestedOrder> = from_dataframe(df).unwrap();
+    for (order, row) in orders.iter().zip(&ingested) {
+        assert_eq!(row.id, order.id);
+        assert_eq!(row.placed_on, order.placed_on);
+        assert_eq!(row.batch_id, "batch-17");
+        assert_eq!(row.ingested_at, ingested_at);
+        assert_eq!(row.weight, 0.5);
+    }
+
+    // A constant may not shadow a record column
+    let clashing = ConversionOptions::new().add_constant_columns(vec![("id".to_string(), ScalarValue::Int64(0))]);
+    assert!(matches!(
+        to_dataframe_with(&orders, &clashing),
+        Err(PolarsSerdeError::ConversionError { .. })
+    ));
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_crate_types_roundtrip() {
+    use polars::prelude::TimeUnit;
+    use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Flight {
+        departs_on: Date,
+        returns_on: Option<Date>,
+        boarding: PrimitiveDateTime,
+        landing: Option<PrimitiveDateTime>,
+        booked_at: OffsetDateTime,
+        cancelled_at: Option<OffsetDateTime>,
+    }
+
+    let date = |month, day| Date::from_calendar_date(2024, month, day).unwrap();
+    let time = |hour, minute| Time::from_hms_nano(hour, minute, 30, 250_000_000).unwrap();
+    let oslo = UtcOffset::from_hms(2, 0, 0).unwrap();
+    let records = vec![
+        Flight {
+            departs_on: date(Month::February, 29),
+            returns_on: Some(date(Month::March, 4)),
+            boarding: PrimitiveDateTime::new(date(Month::February, 29), time(6, 45)),
+            landing: Some(PrimitiveDateTime::new(date(Month::February, 29), time(9, 5))),
+            booked_at: PrimitiveDateTime::new(date(Month::January, 12), time(23, 10)).assume_offset(oslo),
+            cancelled_at: Some(PrimitiveDateTime::new(date(Month::February, 1), time(0, 30)).assume_utc()),
+        },
+        Flight {
+            departs_on: date(Month::December, 31),
+            returns_on: None,
+            boarding: PrimitiveDateTime::new(date(Month::December, 31), time(22, 0)),
+            landing: None,
+            booked_at: PrimitiveDateTime::new(date(Month::November, 3), time(1, 0)).assume_utc(),
+            cancelled_at: None,
+        },
+    ];
+
+    let df = to_dataframe(&records).unwrap();
+    assert_eq!(df.column("departs_on").unwrap().dtype(), &DataType::Date);
+    assert_eq!(df.column("returns_on").unwrap().dtype(), &DataType::Date);
+    assert!(matches!(df.column("boarding").unwrap().dtype(), DataType::Datetime(TimeUnit::Nanoseconds, None)));
+    assert!(matches!(df.column("landing").unwrap().dtype(), DataType::Datetime(TimeUnit::Nanoseconds, None)));
+    assert!(matches!(df.column("booked_at").unwrap().dtype(), DataType::Datetime(TimeUnit::Nanoseconds, Some(_))));
+    assert!(matches!(df.column("cancelled_at").unwrap().dtype(), DataType::Datetime(TimeUnit::Nanoseconds, Some(_))));
+    assert_eq!(df.column("returns_on").unwrap().null_count(), 1);
+
+    // `OffsetDateTime` compares instants, so values read back at UTC still match
+    let converted: Vec<Flight> = from_dataframe(df).unwrap();
+    assert_eq!(records, converted);
+    assert_eq!(converted[0].booked_at.offset(), UtcOffset::UTC);
+}